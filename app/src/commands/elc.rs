@@ -13,6 +13,28 @@ pub enum ELCCmd {
     CreateClient(ELCOpts),
     #[clap(display_order = 2, about = "Update Light Client")]
     UpdateClient(ELCOpts),
+    #[clap(display_order = 3, about = "Update Light Client with multiple headers")]
+    UpdateClientBatch(ELCOpts),
+    #[clap(
+        display_order = 4,
+        about = "Submit misbehaviour to freeze Light Client"
+    )]
+    SubmitMisbehaviour(ELCOpts),
+    #[clap(
+        display_order = 5,
+        about = "Prune expired consensus states of Light Client"
+    )]
+    Prune(ELCOpts),
+    #[clap(
+        display_order = 6,
+        about = "Recover Light Client with the state of a substitute client"
+    )]
+    RecoverClient(ELCOpts),
+    #[clap(
+        display_order = 7,
+        about = "Upgrade Light Client with the upgraded states of the counterparty chain"
+    )]
+    UpgradeClient(ELCOpts),
 }
 
 impl ELCCmd {
@@ -20,6 +42,11 @@ impl ELCCmd {
         match self {
             ELCCmd::CreateClient(opts) => opts,
             ELCCmd::UpdateClient(opts) => opts,
+            ELCCmd::UpdateClientBatch(opts) => opts,
+            ELCCmd::SubmitMisbehaviour(opts) => opts,
+            ELCCmd::Prune(opts) => opts,
+            ELCCmd::RecoverClient(opts) => opts,
+            ELCCmd::UpgradeClient(opts) => opts,
         }
     }
 }
@@ -60,6 +87,21 @@ impl ELCCmd {
             Self::UpdateClient(_) => {
                 let _ = enclave.proto_update_client(elc_opts.load()?)?;
             }
+            Self::UpdateClientBatch(_) => {
+                let _ = enclave.proto_update_client_batch(elc_opts.load()?)?;
+            }
+            Self::SubmitMisbehaviour(_) => {
+                let _ = enclave.proto_submit_misbehaviour(elc_opts.load()?)?;
+            }
+            Self::Prune(_) => {
+                let _ = enclave.proto_prune_consensus_states(elc_opts.load()?)?;
+            }
+            Self::RecoverClient(_) => {
+                let _ = enclave.proto_recover_client(elc_opts.load()?)?;
+            }
+            Self::UpgradeClient(_) => {
+                let _ = enclave.proto_upgrade_client(elc_opts.load()?)?;
+            }
         }
         Ok(())
     }
@@ -1,9 +1,11 @@
 use crate::opts::Opts;
 use anyhow::{anyhow, Result};
 use clap::Parser;
-use ecall_commands::GenerateEnclaveKeyInput;
+use core::str::FromStr;
+use crypto::Address;
+use ecall_commands::{GenerateEnclaveKeyInput, SignRevokeEnclaveKeyInput};
 use enclave_api::{Enclave, EnclaveCommandAPI, EnclaveProtoAPI};
-use lcp_types::Mrenclave;
+use lcp_types::{ClientId, Mrenclave};
 use log::*;
 use serde_json::json;
 use std::path::PathBuf;
@@ -20,6 +22,11 @@ pub enum EnclaveCmd {
     PruneKeys(PruneKeys),
     #[clap(about = "Print metadata of the enclave", display_order = 4)]
     Metadata(Metadata),
+    #[clap(
+        about = "Sign a revocation of an Enclave Key in the LCP client",
+        display_order = 5
+    )]
+    SignRevokeKey(SignRevokeKey),
 }
 
 impl EnclaveCmd {
@@ -46,6 +53,9 @@ impl EnclaveCmd {
                 run_prune_keys(enclave_loader(opts, cmd.enclave.as_ref())?, cmd)
             }
             Self::Metadata(cmd) => run_print_metadata(opts, cmd),
+            Self::SignRevokeKey(cmd) => {
+                run_sign_revoke_key(enclave_loader(opts, cmd.enclave.as_ref())?, cmd)
+            }
         }
     }
 }
@@ -140,6 +150,47 @@ fn run_prune_keys<E: EnclaveCommandAPI<S>, S: CommitStore>(
     Ok(())
 }
 
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct SignRevokeKey {
+    /// Path to the enclave binary
+    #[clap(long = "enclave", help = "Path to the enclave binary")]
+    pub enclave: Option<PathBuf>,
+    /// Client ID of the LCP client
+    #[clap(long = "client_id", help = "Client ID of the LCP client")]
+    pub client_id: String,
+    /// Revocation sequence of the LCP client
+    #[clap(long = "sequence", help = "Revocation sequence of the LCP client")]
+    pub sequence: u64,
+    /// An enclave key to be revoked
+    #[clap(long = "key", help = "An enclave key to be revoked")]
+    pub key: String,
+    /// An enclave key that signs the revocation
+    #[clap(long = "signer", help = "An enclave key that signs the revocation")]
+    pub signer: String,
+}
+
+fn run_sign_revoke_key<E: EnclaveCommandAPI<S>, S: CommitStore>(
+    enclave: E,
+    input: &SignRevokeKey,
+) -> Result<()> {
+    let res = enclave
+        .sign_revoke_enclave_key(SignRevokeEnclaveKeyInput {
+            client_id: ClientId::from_str(&input.client_id)?,
+            sequence: input.sequence,
+            key: Address::from_hex_string(&input.key)?,
+            signer: Address::from_hex_string(&input.signer)?,
+        })
+        .map_err(|e| anyhow!("failed to sign the revocation: {:?}", e))?;
+    println!(
+        "{}",
+        json! {{
+            "signer": res.signer.to_hex_string(),
+            "signature": hex::encode(res.signature),
+        }}
+    );
+    Ok(())
+}
+
 #[derive(Clone, Debug, Parser, PartialEq)]
 pub struct Metadata {
     /// Path to the enclave binary
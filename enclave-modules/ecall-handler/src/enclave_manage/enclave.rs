@@ -1,7 +1,11 @@
 use crate::enclave_manage::Error;
 use crate::prelude::*;
-use crypto::{EnclaveKey, SealingKey};
-use ecall_commands::{GenerateEnclaveKeyInput, GenerateEnclaveKeyResult};
+use crypto::{EnclaveKey, SealingKey, Signer};
+use ecall_commands::{
+    CommandContext, GenerateEnclaveKeyInput, GenerateEnclaveKeyResult, SignRevokeEnclaveKeyInput,
+    SignRevokeEnclaveKeyResult,
+};
+use light_client::commitments::revoke_enclave_key_sign_bytes;
 
 pub(crate) fn generate_enclave_key(
     _: GenerateEnclaveKeyInput,
@@ -13,3 +17,19 @@ pub(crate) fn generate_enclave_key(
         sealed_ek,
     })
 }
+
+pub(crate) fn sign_revoke_enclave_key(
+    cctx: CommandContext,
+    input: SignRevokeEnclaveKeyInput,
+) -> Result<SignRevokeEnclaveKeyResult, Error> {
+    let ek = EnclaveKey::unseal(&cctx.sealed_ek.ok_or(Error::enclave_key_not_found())?)?;
+    let signature = ek.sign(&revoke_enclave_key_sign_bytes(
+        &input.client_id,
+        input.sequence,
+        input.key,
+    ))?;
+    Ok(SignRevokeEnclaveKeyResult {
+        signer: ek.get_pubkey().as_address(),
+        signature,
+    })
+}
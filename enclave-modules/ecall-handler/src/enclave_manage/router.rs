@@ -1,5 +1,7 @@
 use crate::enclave_manage::{
-    attestation::ias_remote_attestation, enclave::generate_enclave_key, Error,
+    attestation::ias_remote_attestation,
+    enclave::{generate_enclave_key, sign_revoke_enclave_key},
+    Error,
 };
 use crate::prelude::*;
 use ecall_commands::{CommandContext, CommandResult, EnclaveManageCommand, EnclaveManageResult};
@@ -23,6 +25,9 @@ pub fn dispatch(
                 crate::enclave_manage::attestation::simulate_remote_attestation(cctx, input)?,
             ))
         }
+        SignRevokeEnclaveKey(input) => CommandResult::EnclaveManage(
+            EnclaveManageResult::SignRevokeEnclaveKey(sign_revoke_enclave_key(cctx, input)?),
+        ),
     };
     Ok(res)
 }
@@ -7,6 +7,39 @@ define_error! {
         SealedEnclaveKeyNotFound
         |_| { "Sealed EnclaveKey not found" },
 
+        InvalidUpdateClientBatch
+        {
+            descr: String
+        }
+        |e| {
+            format_args!("invalid update client batch: descr={}", e.descr)
+        },
+
+        InvalidVerifyStateBatch
+        {
+            descr: String
+        }
+        |e| {
+            format_args!("invalid verify state batch: descr={}", e.descr)
+        },
+
+        UnexpectedCommand
+        {
+            descr: String
+        }
+        |e| {
+            format_args!("unexpected command: descr={}", e.descr)
+        },
+
+        ClientTypeMismatch
+        {
+            subject_client_type: String,
+            substitute_client_type: String
+        }
+        |e| {
+            format_args!("client type mismatch: subject_client_type={} substitute_client_type={}", e.subject_client_type, e.substitute_client_type)
+        },
+
         LightClient
         [light_client::Error]
         |_| { "LightClient error" },
@@ -6,7 +6,7 @@ use crypto::Signer;
 use ecall_commands::{InitClientInput, InitClientResult, LightClientResult};
 use lcp_types::{Any, ClientId};
 use light_client::commitments::{prove_commitment, CommitmentProof};
-use light_client::{ClientKeeper, ClientReader, LightClientResolver};
+use light_client::{ClientKeeper, ClientReader, LightClientResolver, RegistryError};
 use store::KVStore;
 
 pub fn init_client<R: LightClientResolver, S: KVStore, K: Signer>(
@@ -15,9 +15,16 @@ pub fn init_client<R: LightClientResolver, S: KVStore, K: Signer>(
 ) -> Result<LightClientResult, Error> {
     ctx.set_timestamp(input.current_timestamp);
 
-    let any_client_state: Any = input.any_client_state.into();
+    // the client state is stored with the registered type URL even if it is given with an alias
+    let any_client_state = ctx.normalize_client_state_type_url(input.any_client_state.into());
     let any_consensus_state: Any = input.any_consensus_state.into();
-    let lc = ctx.get_light_client(&any_client_state.type_url).unwrap();
+    let lc = ctx
+        .get_light_client(&any_client_state.type_url)
+        .ok_or_else(|| {
+            Error::light_client_registry(RegistryError::type_url_not_found(
+                any_client_state.type_url.clone(),
+            ))
+        })?;
     let ek = ctx.get_enclave_key();
     let res = lc.create_client(ctx, any_client_state.clone(), any_consensus_state.clone())?;
     let client_type = lc.client_type();
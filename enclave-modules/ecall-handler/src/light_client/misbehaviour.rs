@@ -0,0 +1,25 @@
+use super::registry::get_light_client_by_client_id;
+use crate::light_client::Error;
+use context::Context;
+use crypto::Signer;
+use ecall_commands::{LightClientResult, SubmitMisbehaviourInput, SubmitMisbehaviourResult};
+use light_client::commitments::prove_commitment;
+use light_client::{ClientKeeper, LightClientResolver};
+use store::KVStore;
+
+pub fn submit_misbehaviour<R: LightClientResolver, S: KVStore, K: Signer>(
+    ctx: &mut Context<R, S, K>,
+    input: SubmitMisbehaviourInput,
+) -> Result<LightClientResult, Error> {
+    ctx.set_timestamp(input.current_timestamp);
+
+    let lc = get_light_client_by_client_id(ctx, &input.client_id)?;
+    let ek = ctx.get_enclave_key();
+    let res = lc.submit_misbehaviour(ctx, input.client_id.clone(), input.any_misbehaviour)?;
+
+    ctx.store_any_client_state(input.client_id, res.new_any_client_state)?;
+
+    Ok(LightClientResult::SubmitMisbehaviour(
+        SubmitMisbehaviourResult(prove_commitment(ek, input.signer, res.commitment)?),
+    ))
+}
@@ -1,14 +1,25 @@
 pub use errors::Error;
 pub use init_client::init_client;
-pub use query::query_client;
+pub use misbehaviour::submit_misbehaviour;
+pub use prune::prune_consensus_states;
+pub use query::{
+    query_client, query_client_status, query_clients, query_consensus_state,
+    query_consensus_state_heights, query_light_clients,
+};
+pub use recover_client::recover_client;
 pub use router::dispatch;
-pub use update_client::update_client;
-pub use verify_state::{verify_membership, verify_non_membership};
+pub use update_client::{update_client, update_client_batch};
+pub use upgrade_client::upgrade_client;
+pub use verify_state::{verify_membership, verify_non_membership, verify_state_batch};
 
 mod errors;
 mod init_client;
+mod misbehaviour;
+mod prune;
 mod query;
+mod recover_client;
 mod registry;
 mod router;
 mod update_client;
+mod upgrade_client;
 mod verify_state;
@@ -0,0 +1,60 @@
+use super::registry::get_light_client_by_client_id;
+use crate::light_client::Error;
+use crate::prelude::*;
+use context::Context;
+use core::time::Duration;
+use crypto::Signer;
+use ecall_commands::{LightClientResult, PruneConsensusStatesInput, PruneConsensusStatesResult};
+use lcp_types::{ClientId, Height};
+use light_client::{
+    prunable_consensus_state_heights, prunable_consensus_state_heights_from_keys, ClientKeeper,
+    LightClientResolver,
+};
+use store::KVStore;
+
+/// The maximum number of consensus states pruned on each update of the client.
+/// This bounds the extra work of `update_client`, and the remaining expired states are pruned on later updates.
+pub(crate) const MAX_PRUNE_CONSENSUS_STATES_ON_UPDATE: usize = 8;
+
+pub fn prune_consensus_states<R: LightClientResolver, S: KVStore, K: Signer>(
+    ctx: &mut Context<R, S, K>,
+    input: PruneConsensusStatesInput,
+) -> Result<LightClientResult, Error> {
+    ctx.set_timestamp(input.current_timestamp);
+
+    // this reads all the consensus state keys of the client instead of the height index,
+    // so that the states stored before the height index was introduced are also pruned
+    let pruned_heights = {
+        let lc = get_light_client_by_client_id(ctx, &input.client_id)?;
+        prunable_consensus_state_heights_from_keys(
+            ctx,
+            lc.as_ref(),
+            &input.client_id,
+            input.retention_period,
+            input.limit.try_into().unwrap_or(usize::MAX),
+        )?
+    };
+    ctx.delete_consensus_states(&input.client_id, &pruned_heights)?;
+    Ok(LightClientResult::PruneConsensusStates(
+        PruneConsensusStatesResult { pruned_heights },
+    ))
+}
+
+/// prune_expired_consensus_states deletes at most `limit` consensus states of the client
+/// which are older than `retention_period`, and returns their heights.
+///
+/// This is called on each update of the client, so the prunable states are found by walking the height index
+/// from the lowest height, which stops at the first state that is not prunable.
+pub(crate) fn prune_expired_consensus_states<R: LightClientResolver, S: KVStore, K: Signer>(
+    ctx: &mut Context<R, S, K>,
+    client_id: &ClientId,
+    retention_period: Option<Duration>,
+    limit: usize,
+) -> Result<Vec<Height>, Error> {
+    let heights = {
+        let lc = get_light_client_by_client_id(ctx, client_id)?;
+        prunable_consensus_state_heights(ctx, lc.as_ref(), client_id, retention_period, limit)?
+    };
+    ctx.delete_consensus_states(client_id, &heights)?;
+    Ok(heights)
+}
@@ -1,8 +1,15 @@
 use super::registry::get_light_client_by_client_id;
 use crate::light_client::Error;
+use crate::prelude::*;
 use context::Context;
 use crypto::Signer;
-use ecall_commands::{LightClientResult, QueryClientInput, QueryClientResult};
+use ecall_commands::{
+    ClientInfo, ClientSummary, LightClientResult, QueryClientInput, QueryClientResult,
+    QueryClientStatusInput, QueryClientStatusResult, QueryClientsInput, QueryClientsResult,
+    QueryConsensusStateHeightsInput, QueryConsensusStateHeightsResult, QueryConsensusStateInput,
+    QueryConsensusStateResult, QueryLightClientsInput, QueryLightClientsResult,
+};
+use lcp_types::ClientId;
 use light_client::{ClientReader, LightClientResolver};
 use store::KVStore;
 
@@ -12,11 +19,116 @@ pub fn query_client<R: LightClientResolver, S: KVStore, K: Signer>(
 ) -> Result<LightClientResult, Error> {
     let lc = get_light_client_by_client_id(ctx, &input.client_id)?;
     let any_client_state = ctx.client_state(&input.client_id)?;
-    let any_consensus_state =
-        ctx.consensus_state(&input.client_id, &lc.latest_height(ctx, &input.client_id)?)?;
+    let height = match input.height {
+        Some(height) => height,
+        None => lc.latest_height(ctx, &input.client_id)?,
+    };
+    let any_consensus_state = ctx.consensus_state(&input.client_id, &height)?;
 
     Ok(LightClientResult::QueryClient(QueryClientResult {
         any_client_state,
         any_consensus_state,
     }))
 }
+
+pub fn query_consensus_state<R: LightClientResolver, S: KVStore, K: Signer>(
+    ctx: &mut Context<R, S, K>,
+    input: QueryConsensusStateInput,
+) -> Result<LightClientResult, Error> {
+    // ensure the client exists
+    let _ = ctx.client_state(&input.client_id)?;
+    let any_consensus_state = ctx.find_consensus_state(&input.client_id, &input.height)?;
+
+    Ok(LightClientResult::QueryConsensusState(
+        QueryConsensusStateResult {
+            any_consensus_state,
+        },
+    ))
+}
+
+pub fn query_consensus_state_heights<R: LightClientResolver, S: KVStore, K: Signer>(
+    ctx: &mut Context<R, S, K>,
+    input: QueryConsensusStateHeightsInput,
+) -> Result<LightClientResult, Error> {
+    // ensure the client exists
+    let _ = ctx.client_state(&input.client_id)?;
+    let heights = ctx.consensus_state_heights(&input.client_id, &input.pagination)?;
+
+    Ok(LightClientResult::QueryConsensusStateHeights(
+        QueryConsensusStateHeightsResult { heights },
+    ))
+}
+
+pub fn query_clients<R: LightClientResolver, S: KVStore, K: Signer>(
+    ctx: &mut Context<R, S, K>,
+    input: QueryClientsInput,
+) -> Result<LightClientResult, Error> {
+    ctx.set_timestamp(input.current_timestamp);
+
+    let clients = ctx
+        .client_ids(
+            input.pagination.start_after.as_ref(),
+            input.pagination.page_size(),
+        )?
+        .into_iter()
+        .map(|client_id| ClientInfo {
+            // an error of a client is reported in its entry so that it does not fail the whole listing
+            summary: client_summary(ctx, &client_id).map_err(|e| e.to_string()),
+            client_id,
+        })
+        .collect();
+    Ok(LightClientResult::QueryClients(QueryClientsResult {
+        clients,
+    }))
+}
+
+fn client_summary<R: LightClientResolver, S: KVStore, K: Signer>(
+    ctx: &Context<R, S, K>,
+    client_id: &ClientId,
+) -> Result<ClientSummary, Error> {
+    let lc = get_light_client_by_client_id(ctx, client_id)?;
+    Ok(ClientSummary {
+        client_type: ctx.client_type(client_id)?,
+        latest_height: lc.latest_height(ctx, client_id)?,
+        status: lc.status(ctx, client_id)?,
+    })
+}
+
+pub fn query_client_status<R: LightClientResolver, S: KVStore, K: Signer>(
+    ctx: &mut Context<R, S, K>,
+    input: QueryClientStatusInput,
+) -> Result<LightClientResult, Error> {
+    ctx.set_timestamp(input.current_timestamp);
+
+    let lc = get_light_client_by_client_id(ctx, &input.client_id)?;
+    let status = lc.status(ctx, &input.client_id)?;
+    let expires_at = match lc.trusting_period(ctx, &input.client_id)? {
+        Some(trusting_period) => {
+            let latest_height = lc.latest_height(ctx, &input.client_id)?;
+            let timestamp = lc.consensus_state_timestamp(ctx, &input.client_id, &latest_height)?;
+            // the overflow means that the client never expires in practice
+            (timestamp + trusting_period).ok()
+        }
+        None => None,
+    };
+    Ok(LightClientResult::QueryClientStatus(
+        QueryClientStatusResult { status, expires_at },
+    ))
+}
+
+pub fn query_light_clients<R: LightClientResolver, S: KVStore, K: Signer>(
+    ctx: &mut Context<R, S, K>,
+    _: QueryLightClientsInput,
+) -> Result<LightClientResult, Error> {
+    let light_clients = ctx
+        .list_light_clients()
+        .into_iter()
+        .map(|info| ecall_commands::LightClientInfo {
+            client_type: info.client_type,
+            client_state_type_urls: info.client_state_type_urls,
+        })
+        .collect();
+    Ok(LightClientResult::QueryLightClients(
+        QueryLightClientsResult { light_clients },
+    ))
+}
@@ -0,0 +1,43 @@
+use super::registry::get_light_client_by_client_id;
+use crate::light_client::Error;
+use context::Context;
+use crypto::Signer;
+use ecall_commands::{LightClientResult, RecoverClientInput, RecoverClientResult};
+use light_client::commitments::prove_commitment;
+use light_client::{ClientKeeper, ClientReader, LightClientResolver};
+use store::KVStore;
+
+pub fn recover_client<R: LightClientResolver, S: KVStore, K: Signer>(
+    ctx: &mut Context<R, S, K>,
+    input: RecoverClientInput,
+) -> Result<LightClientResult, Error> {
+    ctx.set_timestamp(input.current_timestamp);
+
+    let subject_client_type = ctx.client_type(&input.subject_client_id)?;
+    let substitute_client_type = ctx.client_type(&input.substitute_client_id)?;
+    if subject_client_type != substitute_client_type {
+        return Err(Error::client_type_mismatch(
+            subject_client_type,
+            substitute_client_type,
+        ));
+    }
+
+    let lc = get_light_client_by_client_id(ctx, &input.subject_client_id)?;
+    let ek = ctx.get_enclave_key();
+    let res = lc.recover_client(
+        ctx,
+        input.subject_client_id.clone(),
+        input.substitute_client_id,
+    )?;
+
+    ctx.store_any_client_state(input.subject_client_id.clone(), res.new_any_client_state)?;
+    ctx.store_any_consensus_state(
+        input.subject_client_id,
+        res.height,
+        res.new_any_consensus_state,
+    )?;
+
+    Ok(LightClientResult::RecoverClient(RecoverClientResult(
+        prove_commitment(ek, input.signer, res.commitment)?,
+    )))
+}
@@ -6,12 +6,20 @@ use lcp_types::ClientId;
 use light_client::{ClientReader, LightClient, LightClientResolver, RegistryError};
 use store::KVStore;
 
+/// get_light_client_by_client_id returns the light client that handles the client
+///
+/// If no light client is registered with the type URL of the client state,
+/// the light client is looked up by the client type of the client instead.
 pub fn get_light_client_by_client_id<'a, R: LightClientResolver, S: KVStore, K: Signer>(
     ctx: &'a Context<R, S, K>,
     client_id: &ClientId,
 ) -> Result<&'a Box<dyn LightClient>, Error> {
     let any_client_state = ctx.client_state(client_id)?.to_proto();
-    ctx.get_light_client(any_client_state.type_url.as_ref())
+    if let Some(lc) = ctx.get_light_client(any_client_state.type_url.as_ref()) {
+        return Ok(lc);
+    }
+    let client_type = ctx.client_type(client_id)?;
+    ctx.get_light_client_by_client_type(&client_type)
         .ok_or(Error::light_client_registry(
             RegistryError::type_url_not_found(any_client_state.type_url),
         ))
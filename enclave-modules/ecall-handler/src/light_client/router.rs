@@ -1,5 +1,8 @@
 use crate::light_client::{
-    init_client, query_client, update_client, verify_membership, verify_non_membership, Error,
+    init_client, prune_consensus_states, query_client, query_client_status, query_clients,
+    query_consensus_state, query_consensus_state_heights, query_light_clients, recover_client,
+    submit_misbehaviour, update_client, update_client_batch, upgrade_client, verify_membership,
+    verify_non_membership, verify_state_batch, Error,
 };
 use context::Context;
 use crypto::NopSigner;
@@ -15,6 +18,12 @@ pub fn dispatch<E: Env>(
     command: LightClientCommand,
 ) -> Result<CommandResult, Error> {
     let res = match command {
+        LightClientCommand::Execute(LightClientExecuteCommand::PruneConsensusStates(input)) => {
+            // pruning does not require the enclave key because it produces no commitment
+            let mut ctx =
+                Context::new(env.get_lc_registry(), env.new_store(cctx.tx_id), &NopSigner);
+            prune_consensus_states(&mut ctx, input)?
+        }
         LightClientCommand::Execute(cmd) => {
             use LightClientExecuteCommand::*;
             let sealed_ek = cctx
@@ -25,8 +34,18 @@ pub fn dispatch<E: Env>(
             match cmd {
                 InitClient(input) => init_client(&mut ctx, input)?,
                 UpdateClient(input) => update_client(&mut ctx, input)?,
+                UpdateClientBatch(input) => update_client_batch(&mut ctx, input)?,
+                SubmitMisbehaviour(input) => submit_misbehaviour(&mut ctx, input)?,
+                PruneConsensusStates(_) => {
+                    return Err(Error::unexpected_command(
+                        "PruneConsensusStates must be dispatched without the enclave key".into(),
+                    ))
+                }
+                RecoverClient(input) => recover_client(&mut ctx, input)?,
+                UpgradeClient(input) => upgrade_client(&mut ctx, input)?,
                 VerifyMembership(input) => verify_membership(&mut ctx, input)?,
                 VerifyNonMembership(input) => verify_non_membership(&mut ctx, input)?,
+                VerifyStateBatch(input) => verify_state_batch(&mut ctx, input)?,
             }
         }
         LightClientCommand::Query(cmd) => {
@@ -35,6 +54,13 @@ pub fn dispatch<E: Env>(
                 Context::new(env.get_lc_registry(), env.new_store(cctx.tx_id), &NopSigner);
             match cmd {
                 QueryClient(input) => query_client(&mut ctx, input)?,
+                QueryConsensusState(input) => query_consensus_state(&mut ctx, input)?,
+                QueryConsensusStateHeights(input) => {
+                    query_consensus_state_heights(&mut ctx, input)?
+                }
+                QueryClients(input) => query_clients(&mut ctx, input)?,
+                QueryClientStatus(input) => query_client_status(&mut ctx, input)?,
+                QueryLightClients(input) => query_light_clients(&mut ctx, input)?,
             }
         }
     };
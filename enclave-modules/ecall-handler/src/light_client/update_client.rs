@@ -1,8 +1,13 @@
+use super::prune::{prune_expired_consensus_states, MAX_PRUNE_CONSENSUS_STATES_ON_UPDATE};
 use super::registry::get_light_client_by_client_id;
 use crate::light_client::Error;
+use crate::prelude::*;
 use context::Context;
 use crypto::Signer;
-use ecall_commands::{LightClientResult, UpdateClientInput, UpdateClientResult};
+use ecall_commands::{
+    LightClientResult, UpdateClientBatchInput, UpdateClientBatchResult, UpdateClientInput,
+    UpdateClientResult,
+};
 use light_client::commitments::{
     prove_commitment, Commitment, CommitmentProof, UpdateClientCommitment,
 };
@@ -28,7 +33,17 @@ pub fn update_client<R: LightClientResolver, S: KVStore, K: Signer>(
     };
 
     ctx.store_any_client_state(input.client_id.clone(), res.new_any_client_state)?;
-    ctx.store_any_consensus_state(input.client_id, res.height, res.new_any_consensus_state)?;
+    ctx.store_any_consensus_state(
+        input.client_id.clone(),
+        res.height,
+        res.new_any_consensus_state,
+    )?;
+    prune_expired_consensus_states(
+        ctx,
+        &input.client_id,
+        None,
+        MAX_PRUNE_CONSENSUS_STATES_ON_UPDATE,
+    )?;
 
     let proof = if res.prove {
         prove_commitment(ek, input.signer, commitment)?
@@ -37,3 +52,77 @@ pub fn update_client<R: LightClientResolver, S: KVStore, K: Signer>(
     };
     Ok(LightClientResult::UpdateClient(UpdateClientResult(proof)))
 }
+
+/// update_client_batch applies the given headers to the client in order and
+/// returns a single commitment aggregating all the state transitions.
+///
+/// The aggregated commitment has the previous state of the first step and the new state of the last step.
+/// Each step must be based on the state produced by the preceding step.
+pub fn update_client_batch<R: LightClientResolver, S: KVStore, K: Signer>(
+    ctx: &mut Context<R, S, K>,
+    input: UpdateClientBatchInput,
+) -> Result<LightClientResult, Error> {
+    if input.any_headers.is_empty() {
+        return Err(Error::invalid_update_client_batch(
+            "headers must be non-empty".into(),
+        ));
+    }
+    ctx.set_timestamp(input.current_timestamp);
+
+    let ek = ctx.get_enclave_key();
+    let mut aggregated: Option<UpdateClientCommitment> = None;
+    let mut prove = true;
+    for (i, any_header) in input.any_headers.into_iter().enumerate() {
+        let lc = get_light_client_by_client_id(ctx, &input.client_id)?;
+        let res = lc.update_client(ctx, input.client_id.clone(), any_header.into())?;
+        let mut commitment = UpdateClientCommitment::try_from(res.commitment)?;
+        if input.include_state && commitment.new_state.is_none() {
+            commitment.new_state = Some(res.new_any_client_state.clone());
+        }
+        aggregated = Some(match aggregated {
+            None => commitment,
+            Some(prev) => {
+                if commitment.prev_height != Some(prev.new_height)
+                    || commitment.prev_state_id != Some(prev.new_state_id)
+                {
+                    return Err(Error::invalid_update_client_batch(format!(
+                        "header[{}] is not based on the state of the previous step: expected_prev_height={} actual_prev_height={:?}",
+                        i, prev.new_height, commitment.prev_height
+                    )));
+                }
+                UpdateClientCommitment {
+                    prev_state_id: prev.prev_state_id,
+                    prev_height: prev.prev_height,
+                    context: prev.context.aggregate(commitment.context),
+                    ..commitment
+                }
+            }
+        });
+        prove &= res.prove;
+
+        ctx.store_any_client_state(input.client_id.clone(), res.new_any_client_state)?;
+        ctx.store_any_consensus_state(
+            input.client_id.clone(),
+            res.height,
+            res.new_any_consensus_state,
+        )?;
+    }
+    prune_expired_consensus_states(
+        ctx,
+        &input.client_id,
+        None,
+        MAX_PRUNE_CONSENSUS_STATES_ON_UPDATE,
+    )?;
+
+    // the length of headers is checked above
+    let commitment: Commitment = aggregated.unwrap().into();
+
+    let proof = if prove {
+        prove_commitment(ek, input.signer, commitment)?
+    } else {
+        CommitmentProof::new_with_no_signature(commitment.to_commitment_bytes())
+    };
+    Ok(LightClientResult::UpdateClientBatch(
+        UpdateClientBatchResult(proof),
+    ))
+}
@@ -0,0 +1,33 @@
+use super::registry::get_light_client_by_client_id;
+use crate::light_client::Error;
+use context::Context;
+use crypto::Signer;
+use ecall_commands::{LightClientResult, UpgradeClientInput, UpgradeClientResult};
+use light_client::commitments::prove_commitment;
+use light_client::{ClientKeeper, LightClientResolver};
+use store::KVStore;
+
+pub fn upgrade_client<R: LightClientResolver, S: KVStore, K: Signer>(
+    ctx: &mut Context<R, S, K>,
+    input: UpgradeClientInput,
+) -> Result<LightClientResult, Error> {
+    ctx.set_timestamp(input.current_timestamp);
+
+    let lc = get_light_client_by_client_id(ctx, &input.client_id)?;
+    let ek = ctx.get_enclave_key();
+    let res = lc.upgrade_client(
+        ctx,
+        input.client_id.clone(),
+        input.upgraded_any_client_state,
+        input.upgraded_any_consensus_state,
+        input.proof_upgrade_client,
+        input.proof_upgrade_consensus_state,
+    )?;
+
+    ctx.store_any_client_state(input.client_id.clone(), res.new_any_client_state)?;
+    ctx.store_any_consensus_state(input.client_id, res.height, res.new_any_consensus_state)?;
+
+    Ok(LightClientResult::UpgradeClient(UpgradeClientResult(
+        prove_commitment(ek, input.signer, res.commitment)?,
+    )))
+}
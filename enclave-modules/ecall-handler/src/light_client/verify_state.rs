@@ -1,12 +1,13 @@
 use super::registry::get_light_client_by_client_id;
 use crate::light_client::Error;
+use crate::prelude::*;
 use context::Context;
 use crypto::Signer;
 use ecall_commands::{
     LightClientResult, VerifyMembershipInput, VerifyMembershipResult, VerifyNonMembershipInput,
-    VerifyNonMembershipResult,
+    VerifyNonMembershipResult, VerifyStateBatchInput, VerifyStateBatchResult,
 };
-use light_client::commitments::prove_commitment;
+use light_client::commitments::{prove_commitment, BatchStateCommitment, StateCommitmentEntry};
 use light_client::LightClientResolver;
 use store::KVStore;
 
@@ -52,3 +53,64 @@ pub fn verify_non_membership<R: LightClientResolver, S: KVStore, K: Signer>(
         VerifyNonMembershipResult(prove_commitment(ek, input.signer, res.state_commitment)?),
     ))
 }
+
+pub fn verify_state_batch<R: LightClientResolver, S: KVStore, K: Signer>(
+    ctx: &mut Context<R, S, K>,
+    input: VerifyStateBatchInput,
+) -> Result<LightClientResult, Error> {
+    let ek = ctx.get_enclave_key();
+    let lc = get_light_client_by_client_id(ctx, &input.client_id)?;
+
+    let mut state_id = None;
+    let mut entries = Vec::with_capacity(input.entries.len());
+    for entry in input.entries.into_iter() {
+        let res = match entry.value {
+            Some(value) => lc.verify_membership(
+                ctx,
+                input.client_id.clone(),
+                entry.prefix,
+                entry.path,
+                value,
+                input.proof_height,
+                entry.proof,
+            )?,
+            None => lc.verify_non_membership(
+                ctx,
+                input.client_id.clone(),
+                entry.prefix,
+                entry.path,
+                input.proof_height,
+                entry.proof,
+            )?,
+        };
+        let commitment = res.state_commitment;
+        if commitment.height != input.proof_height {
+            return Err(Error::invalid_verify_state_batch(format!(
+                "height mismatch: expected={} actual={}",
+                input.proof_height, commitment.height
+            )));
+        }
+        match state_id {
+            None => state_id = Some(commitment.state_id),
+            Some(id) if id != commitment.state_id => {
+                return Err(Error::invalid_verify_state_batch(format!(
+                    "state_id mismatch: expected={} actual={}",
+                    id, commitment.state_id
+                )));
+            }
+            Some(_) => {}
+        }
+        entries.push(StateCommitmentEntry::from(commitment));
+    }
+    let state_id = state_id.ok_or_else(|| {
+        Error::invalid_verify_state_batch("entries must be non-empty".to_string())
+    })?;
+
+    Ok(LightClientResult::VerifyStateBatch(VerifyStateBatchResult(
+        prove_commitment(
+            ek,
+            input.signer,
+            BatchStateCommitment::new(entries, input.proof_height, state_id).into(),
+        )?,
+    )))
+}
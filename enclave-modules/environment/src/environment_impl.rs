@@ -1,7 +1,7 @@
 use crate::{prelude::*, Env};
 use alloc::sync::Arc;
 use enclave_store::EnclaveStore;
-use light_client::{LightClient, LightClientResolver, MapLightClientRegistry};
+use light_client::{LightClient, LightClientInfo, LightClientResolver, MapLightClientRegistry};
 use store::{KVStore, TxId};
 
 pub struct Environment {
@@ -20,6 +20,22 @@ impl LightClientResolver for Environment {
     fn get_light_client(&self, type_url: &str) -> Option<&alloc::boxed::Box<dyn LightClient>> {
         self.lc_registry.get_light_client(type_url)
     }
+
+    fn get_light_client_by_client_type(
+        &self,
+        client_type: &str,
+    ) -> Option<&alloc::boxed::Box<dyn LightClient>> {
+        self.lc_registry
+            .get_light_client_by_client_type(client_type)
+    }
+
+    fn resolve_type_url(&self, type_url: &str) -> Option<&str> {
+        self.lc_registry.resolve_type_url(type_url)
+    }
+
+    fn list_light_clients(&self) -> Vec<LightClientInfo> {
+        self.lc_registry.list_light_clients()
+    }
 }
 
 impl Env for Environment {
@@ -1,6 +1,6 @@
 use crate::prelude::*;
 use crate::{errors::Error, ffi};
-use ocall_commands::{Command, CommandResult};
+use ocall_commands::{Command, CommandResult, OCALL_OUTPUT_BUFFER_SIZE};
 use sgx_types::*;
 
 pub fn execute_command(cmd: Command) -> Result<CommandResult, Error> {
@@ -8,7 +8,7 @@ pub fn execute_command(cmd: Command) -> Result<CommandResult, Error> {
         .map_err(Error::bincode_encode)?;
     let mut ret: sgx_status_t = sgx_status_t::SGX_ERROR_UNEXPECTED;
     let mut output_len = 0;
-    let output_maxlen = 65536;
+    let output_maxlen = OCALL_OUTPUT_BUFFER_SIZE;
     let mut output_buf = Vec::with_capacity(output_maxlen);
     let output_ptr = output_buf.as_mut_ptr();
 
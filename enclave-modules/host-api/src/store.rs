@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use crate::{api::execute_command, Error};
 use ocall_commands::{Command, CommandResult, StoreCommand, StoreResult};
-use store::TxId;
+use store::{TxId, KV};
 
 pub fn get(tx_id: TxId, key: Vec<u8>) -> Result<Option<Vec<u8>>, Error> {
     let cmd = Command::Store(StoreCommand::Get(tx_id, key));
@@ -29,3 +29,17 @@ pub fn remove(tx_id: TxId, key: Vec<u8>) -> Result<(), Error> {
         unreachable!()
     }
 }
+
+pub fn scan_prefix(
+    tx_id: TxId,
+    prefix: Vec<u8>,
+    start_after: Option<Vec<u8>>,
+    limit: u32,
+) -> Result<Vec<KV>, Error> {
+    let cmd = Command::Store(StoreCommand::ScanPrefix(tx_id, prefix, start_after, limit));
+    if let CommandResult::Store(StoreResult::ScanPrefix(v)) = execute_command(cmd)? {
+        Ok(v)
+    } else {
+        unreachable!()
+    }
+}
@@ -1,5 +1,6 @@
 use crate::prelude::*;
-use store::{KVStore, TxId};
+use ocall_commands::MAX_SCAN_PREFIX_LIMIT;
+use store::{KVStore, TxId, KV};
 
 pub struct EnclaveStore {
     tx_id: TxId,
@@ -21,4 +22,22 @@ impl KVStore for EnclaveStore {
     fn remove(&mut self, key: &[u8]) {
         host_api::store::remove(self.tx_id, key.to_vec()).unwrap();
     }
+    fn scan_prefix(&self, prefix: &[u8], start_after: Option<&[u8]>, limit: usize) -> Vec<KV> {
+        // the host may return a page smaller than the requested limit to fit in the ocall output buffer,
+        // so this keeps requesting pages until `limit` pairs are collected or the iteration reaches the end
+        let mut kvs: Vec<KV> = Vec::new();
+        let mut start_after = start_after.map(|k| k.to_vec());
+        while kvs.len() < limit {
+            let page_limit = (limit - kvs.len()).min(MAX_SCAN_PREFIX_LIMIT as usize) as u32;
+            let page =
+                host_api::store::scan_prefix(self.tx_id, prefix.to_vec(), start_after, page_limit)
+                    .unwrap();
+            match page.last() {
+                Some((k, _)) => start_after = Some(k.clone()),
+                None => break,
+            }
+            kvs.extend(page);
+        }
+        kvs
+    }
 }
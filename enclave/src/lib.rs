@@ -13,5 +13,6 @@ setup_runtime!({
 fn build_lc_registry() -> MapLightClientRegistry {
     let mut registry = MapLightClientRegistry::new();
     tendermint_lc::register_implementations(&mut registry);
+    solomachine_lc::register_implementations(&mut registry);
     registry
 }
@@ -2,19 +2,26 @@ use crate::context::CommitmentContext;
 use crate::prelude::*;
 use crate::{Error, StateID};
 use core::fmt::Display;
-use lcp_types::{Any, Height, Time};
+use core::str::FromStr;
+use lcp_types::{Any, ClientId, Height, Time};
 use prost::Message;
 use serde::{Deserialize, Serialize};
 
 pub const COMMITMENT_SCHEMA_VERSION: u16 = 1;
 pub const COMMITMENT_TYPE_UPDATE_CLIENT: u16 = 1;
 pub const COMMITMENT_TYPE_STATE: u16 = 2;
+pub const COMMITMENT_TYPE_BATCH_STATE: u16 = 3;
+pub const COMMITMENT_TYPE_MISBEHAVIOUR: u16 = 4;
+pub const COMMITMENT_TYPE_RECOVER_CLIENT: u16 = 5;
 pub const COMMITMENT_HEADER_SIZE: usize = 32;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Commitment {
     UpdateClient(UpdateClientCommitment),
     State(StateCommitment),
+    BatchState(BatchStateCommitment),
+    Misbehaviour(MisbehaviourCommitment),
+    RecoverClient(RecoverClientCommitment),
 }
 
 pub trait EthABIEncoder {
@@ -29,6 +36,9 @@ impl Display for Commitment {
         match self {
             Commitment::UpdateClient(c) => write!(f, "UpdateClient({})", c),
             Commitment::State(c) => write!(f, "State({})", c),
+            Commitment::BatchState(c) => write!(f, "BatchState({})", c),
+            Commitment::Misbehaviour(c) => write!(f, "Misbehaviour({})", c),
+            Commitment::RecoverClient(c) => write!(f, "RecoverClient({})", c),
         }
     }
 }
@@ -59,6 +69,45 @@ impl TryFrom<Commitment> for StateCommitment {
     }
 }
 
+impl TryFrom<Commitment> for BatchStateCommitment {
+    type Error = Error;
+    fn try_from(value: Commitment) -> Result<Self, Self::Error> {
+        match value {
+            Commitment::BatchState(c) => Ok(c),
+            _ => Err(Error::unexpected_commitment_type(
+                COMMITMENT_TYPE_BATCH_STATE,
+                value.commitment_type(),
+            )),
+        }
+    }
+}
+
+impl TryFrom<Commitment> for MisbehaviourCommitment {
+    type Error = Error;
+    fn try_from(value: Commitment) -> Result<Self, Self::Error> {
+        match value {
+            Commitment::Misbehaviour(c) => Ok(c),
+            _ => Err(Error::unexpected_commitment_type(
+                COMMITMENT_TYPE_MISBEHAVIOUR,
+                value.commitment_type(),
+            )),
+        }
+    }
+}
+
+impl TryFrom<Commitment> for RecoverClientCommitment {
+    type Error = Error;
+    fn try_from(value: Commitment) -> Result<Self, Self::Error> {
+        match value {
+            Commitment::RecoverClient(c) => Ok(c),
+            _ => Err(Error::unexpected_commitment_type(
+                COMMITMENT_TYPE_RECOVER_CLIENT,
+                value.commitment_type(),
+            )),
+        }
+    }
+}
+
 impl Commitment {
     pub fn to_commitment_bytes(self) -> Vec<u8> {
         self.ethabi_encode()
@@ -83,6 +132,9 @@ impl Commitment {
         match self {
             Commitment::UpdateClient(_) => COMMITMENT_TYPE_UPDATE_CLIENT,
             Commitment::State(_) => COMMITMENT_TYPE_STATE,
+            Commitment::BatchState(_) => COMMITMENT_TYPE_BATCH_STATE,
+            Commitment::Misbehaviour(_) => COMMITMENT_TYPE_MISBEHAVIOUR,
+            Commitment::RecoverClient(_) => COMMITMENT_TYPE_RECOVER_CLIENT,
         }
     }
 }
@@ -94,6 +146,9 @@ impl EthABIEncoder for Commitment {
             commitment: match self {
                 Commitment::UpdateClient(c) => c.ethabi_encode(),
                 Commitment::State(c) => c.ethabi_encode(),
+                Commitment::BatchState(c) => c.ethabi_encode(),
+                Commitment::Misbehaviour(c) => c.ethabi_encode(),
+                Commitment::RecoverClient(c) => c.ethabi_encode(),
             },
         }
         .encode()
@@ -131,6 +186,15 @@ impl EthABIEncoder for Commitment {
                 Ok(UpdateClientCommitment::ethabi_decode(&commitment)?.into())
             }
             COMMITMENT_TYPE_STATE => Ok(StateCommitment::ethabi_decode(&commitment)?.into()),
+            COMMITMENT_TYPE_BATCH_STATE => {
+                Ok(BatchStateCommitment::ethabi_decode(&commitment)?.into())
+            }
+            COMMITMENT_TYPE_MISBEHAVIOUR => {
+                Ok(MisbehaviourCommitment::ethabi_decode(&commitment)?.into())
+            }
+            COMMITMENT_TYPE_RECOVER_CLIENT => {
+                Ok(RecoverClientCommitment::ethabi_decode(&commitment)?.into())
+            }
             _ => Err(Error::invalid_abi(format!(
                 "invalid commitment type: {}",
                 commitment_type
@@ -520,6 +584,575 @@ impl EthABIEncoder for StateCommitment {
     }
 }
 
+/// BatchStateCommitment is a commitment to multiple states that are verified against the same consensus state
+///
+/// All the entries share the proof height `height` and the `state_id` of the client at the height.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchStateCommitment {
+    pub entries: Vec<StateCommitmentEntry>,
+    pub height: Height,
+    pub state_id: StateID,
+}
+
+/// StateCommitmentEntry is an entry of BatchStateCommitment
+///
+/// If `value` is None, the entry indicates the non-existence of the state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateCommitmentEntry {
+    pub prefix: CommitmentPrefix,
+    pub path: String,
+    pub value: Option<[u8; 32]>,
+}
+
+impl From<BatchStateCommitment> for Commitment {
+    fn from(value: BatchStateCommitment) -> Self {
+        Self::BatchState(value)
+    }
+}
+
+impl BatchStateCommitment {
+    pub fn new(entries: Vec<StateCommitmentEntry>, height: Height, state_id: StateID) -> Self {
+        Self {
+            entries,
+            height,
+            state_id,
+        }
+    }
+}
+
+impl StateCommitmentEntry {
+    pub fn new(prefix: CommitmentPrefix, path: String, value: Option<[u8; 32]>) -> Self {
+        Self {
+            prefix,
+            path,
+            value,
+        }
+    }
+}
+
+impl From<StateCommitment> for StateCommitmentEntry {
+    fn from(value: StateCommitment) -> Self {
+        Self {
+            prefix: value.prefix,
+            path: value.path,
+            value: value.value,
+        }
+    }
+}
+
+// the struct is encoded as a tuple of 3 elements
+pub(crate) struct EthABIBatchStateCommitment {
+    entries: Vec<EthABIStateCommitmentEntry>, // ((bytes, bytes, bytes32))[]
+    height: EthABIHeight,                     // (uint64, uint64)
+    state_id: ethabi::FixedBytes,             // bytes32
+}
+
+// the struct is encoded as a tuple of 3 elements
+pub(crate) struct EthABIStateCommitmentEntry {
+    prefix: ethabi::Bytes,     // bytes
+    path: ethabi::Bytes,       // bytes
+    value: ethabi::FixedBytes, // bytes32
+}
+
+impl EthABIStateCommitmentEntry {
+    fn param_type() -> ethabi::ParamType {
+        use ethabi::ParamType;
+        ParamType::Tuple(vec![
+            ParamType::Bytes,
+            ParamType::Bytes,
+            ParamType::FixedBytes(32),
+        ])
+    }
+
+    fn into_token(self) -> ethabi::Token {
+        use ethabi::Token;
+        Token::Tuple(vec![
+            Token::Bytes(self.prefix),
+            Token::Bytes(self.path),
+            Token::FixedBytes(self.value),
+        ])
+    }
+
+    fn from_token(token: ethabi::Token) -> Result<Self, Error> {
+        let mut values = expect_tuple(token, 3)?.into_iter();
+        Ok(Self {
+            prefix: expect_bytes(values.next())?,
+            path: expect_bytes(values.next())?,
+            value: expect_fixed_bytes(values.next())?,
+        })
+    }
+}
+
+impl EthABIBatchStateCommitment {
+    pub fn encode(self) -> Vec<u8> {
+        use ethabi::Token;
+        ethabi::encode(&[Token::Tuple(vec![
+            Token::Array(
+                self.entries
+                    .into_iter()
+                    .map(EthABIStateCommitmentEntry::into_token)
+                    .collect(),
+            ),
+            Token::Tuple(self.height.into()),
+            Token::FixedBytes(self.state_id),
+        ])])
+    }
+
+    pub fn decode(bz: &[u8]) -> Result<Self, Error> {
+        use ethabi::ParamType;
+        let token = ethabi::decode(
+            &[ParamType::Tuple(vec![
+                ParamType::Array(Box::new(EthABIStateCommitmentEntry::param_type())),
+                ParamType::Tuple(vec![ParamType::Uint(64), ParamType::Uint(64)]),
+                ParamType::FixedBytes(32),
+            ])],
+            bz,
+        )?
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::invalid_abi("batch state commitment must not be empty".into()))?;
+
+        let mut values = expect_tuple(token, 3)?.into_iter();
+        let entries = match values.next() {
+            Some(ethabi::Token::Array(entries)) => entries
+                .into_iter()
+                .map(EthABIStateCommitmentEntry::from_token)
+                .collect::<Result<_, _>>()?,
+            _ => return Err(Error::invalid_abi("entries must be an array".into())),
+        };
+        Ok(Self {
+            entries,
+            height: match values.next() {
+                Some(token) => expect_tuple(token, 2)?.try_into()?,
+                None => return Err(Error::invalid_abi("height must be a tuple".into())),
+            },
+            state_id: expect_fixed_bytes(values.next())?,
+        })
+    }
+}
+
+/// expect_tuple returns the elements of the tuple token if it has `len` elements
+fn expect_tuple(token: ethabi::Token, len: usize) -> Result<Vec<ethabi::Token>, Error> {
+    match token.into_tuple() {
+        Some(tuple) if tuple.len() == len => Ok(tuple),
+        _ => Err(Error::invalid_abi(format!(
+            "expected a tuple of {} elements",
+            len
+        ))),
+    }
+}
+
+fn expect_bytes(token: Option<ethabi::Token>) -> Result<ethabi::Bytes, Error> {
+    token
+        .and_then(|t| t.into_bytes())
+        .ok_or_else(|| Error::invalid_abi("expected bytes".into()))
+}
+
+fn expect_fixed_bytes(token: Option<ethabi::Token>) -> Result<ethabi::FixedBytes, Error> {
+    token
+        .and_then(|t| t.into_fixed_bytes())
+        .ok_or_else(|| Error::invalid_abi("expected fixed bytes".into()))
+}
+
+impl From<StateCommitmentEntry> for EthABIStateCommitmentEntry {
+    fn from(value: StateCommitmentEntry) -> Self {
+        use ethabi::*;
+        Self {
+            prefix: value.prefix,
+            path: Bytes::from(value.path),
+            value: FixedBytes::from(value.value.unwrap_or_default()),
+        }
+    }
+}
+
+impl TryFrom<EthABIStateCommitmentEntry> for StateCommitmentEntry {
+    type Error = Error;
+    fn try_from(value: EthABIStateCommitmentEntry) -> Result<Self, Self::Error> {
+        Ok(Self {
+            prefix: value.prefix,
+            path: String::from_utf8(value.path)?,
+            value: bytes_to_bytes32(value.value)?,
+        })
+    }
+}
+
+impl From<BatchStateCommitment> for EthABIBatchStateCommitment {
+    fn from(value: BatchStateCommitment) -> Self {
+        Self {
+            entries: value.entries.into_iter().map(Into::into).collect(),
+            height: value.height.into(),
+            state_id: value.state_id.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<EthABIBatchStateCommitment> for BatchStateCommitment {
+    type Error = Error;
+    fn try_from(value: EthABIBatchStateCommitment) -> Result<Self, Self::Error> {
+        Ok(Self {
+            entries: value
+                .entries
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            height: value.height.into(),
+            state_id: value.state_id.as_slice().try_into()?,
+        })
+    }
+}
+
+impl Display for BatchStateCommitment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "entries=[")?;
+        for (i, entry) in self.entries.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{{{}}}", entry)?;
+        }
+        write!(f, "] height={} state_id={}", self.height, self.state_id)
+    }
+}
+
+impl Display for StateCommitmentEntry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "prefix={:?} path={} value={:?}",
+            self.prefix, self.path, self.value
+        )
+    }
+}
+
+impl EthABIEncoder for BatchStateCommitment {
+    fn ethabi_encode(self) -> Vec<u8> {
+        Into::<EthABIBatchStateCommitment>::into(self).encode()
+    }
+
+    fn ethabi_decode(bz: &[u8]) -> Result<Self, Error> {
+        EthABIBatchStateCommitment::decode(bz).and_then(|v| v.try_into())
+    }
+}
+
+/// MisbehaviourCommitment is a commitment that attests the client is frozen at the height due to a misbehaviour
+///
+/// `prev_states` are the trusted states that the misbehaviour was verified against.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MisbehaviourCommitment {
+    pub client_id: ClientId,
+    pub frozen_height: Height,
+    pub prev_states: Vec<PrevState>,
+    pub context: CommitmentContext,
+}
+
+/// PrevState is a pair of the height and state id of a trusted state
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrevState {
+    pub height: Height,
+    pub state_id: StateID,
+}
+
+impl From<MisbehaviourCommitment> for Commitment {
+    fn from(value: MisbehaviourCommitment) -> Self {
+        Self::Misbehaviour(value)
+    }
+}
+
+impl PrevState {
+    pub fn new(height: Height, state_id: StateID) -> Self {
+        Self { height, state_id }
+    }
+}
+
+// the struct is encoded as a tuple of 4 elements
+pub(crate) struct EthABIMisbehaviourCommitment {
+    client_id: ethabi::Bytes,          // bytes
+    frozen_height: EthABIHeight,       // (uint64, uint64)
+    prev_states: Vec<EthABIPrevState>, // ((uint64, uint64), bytes32)[]
+    context: ethabi::Bytes,            // bytes
+}
+
+// the struct is encoded as a tuple of 2 elements
+pub(crate) struct EthABIPrevState {
+    height: EthABIHeight,         // (uint64, uint64)
+    state_id: ethabi::FixedBytes, // bytes32
+}
+
+impl EthABIPrevState {
+    fn param_type() -> ethabi::ParamType {
+        use ethabi::ParamType;
+        ParamType::Tuple(vec![
+            ParamType::Tuple(vec![ParamType::Uint(64), ParamType::Uint(64)]),
+            ParamType::FixedBytes(32),
+        ])
+    }
+
+    fn into_token(self) -> ethabi::Token {
+        use ethabi::Token;
+        Token::Tuple(vec![
+            Token::Tuple(self.height.into()),
+            Token::FixedBytes(self.state_id),
+        ])
+    }
+
+    fn from_token(token: ethabi::Token) -> Result<Self, Error> {
+        let tuple = token
+            .into_tuple()
+            .ok_or_else(|| Error::invalid_abi("prev state must be a tuple".into()))?;
+        // if the decoding is successful, the length of the tuple should be 2
+        assert!(tuple.len() == 2);
+        let mut values = tuple.into_iter();
+        Ok(Self {
+            height: values.next().unwrap().into_tuple().unwrap().try_into()?,
+            state_id: values.next().unwrap().into_fixed_bytes().unwrap(),
+        })
+    }
+}
+
+impl EthABIMisbehaviourCommitment {
+    pub fn encode(self) -> Vec<u8> {
+        use ethabi::Token;
+        ethabi::encode(&[Token::Tuple(vec![
+            Token::Bytes(self.client_id),
+            Token::Tuple(self.frozen_height.into()),
+            Token::Array(
+                self.prev_states
+                    .into_iter()
+                    .map(EthABIPrevState::into_token)
+                    .collect(),
+            ),
+            Token::Bytes(self.context),
+        ])])
+    }
+
+    pub fn decode(bz: &[u8]) -> Result<Self, Error> {
+        use ethabi::ParamType;
+        let tuple = ethabi::decode(
+            &[ParamType::Tuple(vec![
+                ParamType::Bytes,
+                ParamType::Tuple(vec![ParamType::Uint(64), ParamType::Uint(64)]),
+                ParamType::Array(Box::new(EthABIPrevState::param_type())),
+                ParamType::Bytes,
+            ])],
+            bz,
+        )?
+        .into_iter()
+        .next()
+        .unwrap()
+        .into_tuple()
+        .unwrap();
+
+        // if the decoding is successful, the length of the tuple should be 4
+        assert!(tuple.len() == 4);
+        let mut values = tuple.into_iter();
+        Ok(Self {
+            client_id: values.next().unwrap().into_bytes().unwrap(),
+            frozen_height: values.next().unwrap().into_tuple().unwrap().try_into()?,
+            prev_states: values
+                .next()
+                .unwrap()
+                .into_array()
+                .unwrap()
+                .into_iter()
+                .map(EthABIPrevState::from_token)
+                .collect::<Result<_, _>>()?,
+            context: values.next().unwrap().into_bytes().unwrap(),
+        })
+    }
+}
+
+impl From<MisbehaviourCommitment> for EthABIMisbehaviourCommitment {
+    fn from(value: MisbehaviourCommitment) -> Self {
+        Self {
+            client_id: value.client_id.to_string().into_bytes(),
+            frozen_height: value.frozen_height.into(),
+            prev_states: value
+                .prev_states
+                .into_iter()
+                .map(|s| EthABIPrevState {
+                    height: s.height.into(),
+                    state_id: s.state_id.to_vec(),
+                })
+                .collect(),
+            context: value.context.ethabi_encode(),
+        }
+    }
+}
+
+impl TryFrom<EthABIMisbehaviourCommitment> for MisbehaviourCommitment {
+    type Error = Error;
+    fn try_from(value: EthABIMisbehaviourCommitment) -> Result<Self, Self::Error> {
+        Ok(Self {
+            client_id: ClientId::from_str(&String::from_utf8(value.client_id)?)?,
+            frozen_height: value.frozen_height.into(),
+            prev_states: value
+                .prev_states
+                .into_iter()
+                .map(|s| {
+                    Ok(PrevState {
+                        height: s.height.into(),
+                        state_id: s.state_id.as_slice().try_into()?,
+                    })
+                })
+                .collect::<Result<_, Error>>()?,
+            context: CommitmentContext::ethabi_decode(value.context.as_slice())?,
+        })
+    }
+}
+
+impl Display for MisbehaviourCommitment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "client_id={} frozen_height={} prev_states=[",
+            self.client_id, self.frozen_height
+        )?;
+        for (i, state) in self.prev_states.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{{height={} state_id={}}}", state.height, state.state_id)?;
+        }
+        write!(f, "] context={{{}}}", self.context)
+    }
+}
+
+impl EthABIEncoder for MisbehaviourCommitment {
+    fn ethabi_encode(self) -> Vec<u8> {
+        Into::<EthABIMisbehaviourCommitment>::into(self).encode()
+    }
+
+    fn ethabi_decode(bz: &[u8]) -> Result<Self, Error> {
+        EthABIMisbehaviourCommitment::decode(bz).and_then(|v| v.try_into())
+    }
+}
+
+/// RecoverClientCommitment is a commitment that attests the subject client is recovered with the state of the substitute client
+///
+/// Unlike `UpdateClientCommitment`, the new state is not derived from the previous state by a verified header,
+/// so the LCP client must accept it only with the approval of an authority.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecoverClientCommitment {
+    pub prev_state_id: StateID,
+    pub new_state_id: StateID,
+    pub new_state: Any,
+    pub prev_height: Height,
+    pub new_height: Height,
+    pub timestamp: Time,
+}
+
+impl From<RecoverClientCommitment> for Commitment {
+    fn from(value: RecoverClientCommitment) -> Self {
+        Self::RecoverClient(value)
+    }
+}
+
+// the struct is encoded as a tuple of 6 elements
+pub(crate) struct EthABIRecoverClientCommitment {
+    prev_state_id: ethabi::FixedBytes, // bytes32
+    new_state_id: ethabi::FixedBytes,  // bytes32
+    new_state: ethabi::Bytes,          // bytes
+    prev_height: EthABIHeight,         // (u64, u64)
+    new_height: EthABIHeight,          // (u64, u64)
+    timestamp: ethabi::Uint,           // u128
+}
+
+impl EthABIRecoverClientCommitment {
+    pub fn encode(self) -> Vec<u8> {
+        use ethabi::Token;
+        ethabi::encode(&[Token::Tuple(vec![
+            Token::FixedBytes(self.prev_state_id),
+            Token::FixedBytes(self.new_state_id),
+            Token::Bytes(self.new_state),
+            Token::Tuple(self.prev_height.into()),
+            Token::Tuple(self.new_height.into()),
+            Token::Uint(self.timestamp),
+        ])])
+    }
+
+    pub fn decode(bz: &[u8]) -> Result<Self, Error> {
+        use ethabi::ParamType;
+        let token = ethabi::decode(
+            &[ParamType::Tuple(vec![
+                ParamType::FixedBytes(32),
+                ParamType::FixedBytes(32),
+                ParamType::Bytes,
+                ParamType::Tuple(vec![ParamType::Uint(64), ParamType::Uint(64)]),
+                ParamType::Tuple(vec![ParamType::Uint(64), ParamType::Uint(64)]),
+                ParamType::Uint(64),
+            ])],
+            bz,
+        )?
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::invalid_abi("empty recover client commitment".into()))?;
+        let mut values = expect_tuple(token, 6)?.into_iter();
+        Ok(Self {
+            prev_state_id: expect_fixed_bytes(values.next())?,
+            new_state_id: expect_fixed_bytes(values.next())?,
+            new_state: expect_bytes(values.next())?,
+            prev_height: expect_tuple(values.next().unwrap(), 2)?.try_into()?,
+            new_height: expect_tuple(values.next().unwrap(), 2)?.try_into()?,
+            timestamp: values
+                .next()
+                .and_then(|t| t.into_uint())
+                .ok_or_else(|| Error::invalid_abi("expected uint".into()))?,
+        })
+    }
+}
+
+impl From<RecoverClientCommitment> for EthABIRecoverClientCommitment {
+    fn from(value: RecoverClientCommitment) -> Self {
+        use ethabi::*;
+        Self {
+            prev_state_id: FixedBytes::from(value.prev_state_id.to_vec().as_slice()),
+            new_state_id: FixedBytes::from(value.new_state_id.to_vec().as_slice()),
+            new_state: value.new_state.encode_to_vec(),
+            prev_height: value.prev_height.into(),
+            new_height: value.new_height.into(),
+            timestamp: Uint::from(value.timestamp.as_unix_timestamp_nanos()),
+        }
+    }
+}
+
+impl TryFrom<EthABIRecoverClientCommitment> for RecoverClientCommitment {
+    type Error = Error;
+    fn try_from(value: EthABIRecoverClientCommitment) -> Result<Self, Self::Error> {
+        Ok(Self {
+            prev_state_id: value.prev_state_id.as_slice().try_into()?,
+            new_state_id: value.new_state_id.as_slice().try_into()?,
+            new_state: Any::try_from(value.new_state)?,
+            prev_height: value.prev_height.into(),
+            new_height: value.new_height.into(),
+            timestamp: Time::from_unix_timestamp_nanos(value.timestamp.as_u128())?,
+        })
+    }
+}
+
+impl Display for RecoverClientCommitment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "prev_state_id={} new_state_id={} prev_height={} new_height={} timestamp={}",
+            self.prev_state_id,
+            self.new_state_id,
+            self.prev_height,
+            self.new_height,
+            self.timestamp
+        )
+    }
+}
+
+impl EthABIEncoder for RecoverClientCommitment {
+    fn ethabi_encode(self) -> Vec<u8> {
+        Into::<EthABIRecoverClientCommitment>::into(self).encode()
+    }
+
+    fn ethabi_decode(bz: &[u8]) -> Result<Self, Error> {
+        EthABIRecoverClientCommitment::decode(bz).and_then(|v| v.try_into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -641,5 +1274,110 @@ mod tests {
             let p2 = CommitmentProof::ethabi_decode(&p1.clone().ethabi_encode()).unwrap();
             assert_eq!(p1, p2);
         }
+
+        #[test]
+        fn pt_batch_state_commitment(
+            entries in any::<Vec<(CommitmentPrefix, String, Option<[u8; 32]>)>>(),
+            height in any::<(u64, u64)>().prop_map(height_from_tuple),
+            state_id in any::<[u8; 32]>().prop_map(StateID::from),
+            proof_signer in any::<[u8; 20]>(),
+            proof_signature in any::<[u8; 65]>()
+        ) {
+            let c1 = BatchStateCommitment {
+                entries: entries
+                    .into_iter()
+                    .map(|(prefix, path, value)| StateCommitmentEntry::new(prefix, path, value))
+                    .collect(),
+                height,
+                state_id,
+            };
+            let v = c1.clone().ethabi_encode();
+            let c2 = BatchStateCommitment::ethabi_decode(&v).unwrap();
+            assert_eq!(c1, c2);
+
+            let p1 = CommitmentProof {
+                commitment_bytes: Commitment::from(c1).to_commitment_bytes(),
+                signer: Address(proof_signer),
+                signature: proof_signature.to_vec(),
+            };
+            let p2 = CommitmentProof::ethabi_decode(&p1.clone().ethabi_encode()).unwrap();
+            assert_eq!(p1, p2);
+        }
+
+        #[test]
+        fn pt_batch_state_commitment_decode_arbitrary_bytes(bz in any::<Vec<u8>>()) {
+            // decoding an untrusted input must return an error instead of panicking
+            let _ = BatchStateCommitment::ethabi_decode(&bz);
+        }
+
+        #[test]
+        fn pt_recover_client_commitment(
+            prev_state_id in any::<[u8; 32]>().prop_map(StateID::from),
+            new_state_id in any::<[u8; 32]>().prop_map(StateID::from),
+            new_state in any::<(String, Vec<u8>)>().prop_filter("type_url length must be greater than 0", |t| !t.0.is_empty()),
+            prev_height in any::<(u64, u64)>().prop_map(height_from_tuple),
+            new_height in any::<(u64, u64)>().prop_map(height_from_tuple),
+            timestamp in ..=MAX_UNIX_TIMESTAMP_NANOS,
+            proof_signer in any::<[u8; 20]>(),
+            proof_signature in any::<[u8; 65]>()
+        ) {
+            let c1 = RecoverClientCommitment {
+                prev_state_id,
+                new_state_id,
+                new_state: Any::new(new_state.0, new_state.1),
+                prev_height,
+                new_height,
+                timestamp: Time::from_unix_timestamp_nanos(timestamp).unwrap(),
+            };
+            let v = c1.clone().ethabi_encode();
+            let c2 = RecoverClientCommitment::ethabi_decode(&v).unwrap();
+            assert_eq!(c1, c2);
+
+            let p1 = CommitmentProof {
+                commitment_bytes: Commitment::from(c1).to_commitment_bytes(),
+                signer: Address(proof_signer),
+                signature: proof_signature.to_vec(),
+            };
+            let p2 = CommitmentProof::ethabi_decode(&p1.clone().ethabi_encode()).unwrap();
+            assert_eq!(p1, p2);
+        }
+
+        #[test]
+        fn pt_recover_client_commitment_decode_arbitrary_bytes(bz in any::<Vec<u8>>()) {
+            // decoding an untrusted input must return an error instead of panicking
+            let _ = RecoverClientCommitment::ethabi_decode(&bz);
+        }
+
+        #[test]
+        fn pt_misbehaviour_commitment(
+            client_counter in any::<u64>(),
+            frozen_height in any::<(u64, u64)>().prop_map(height_from_tuple),
+            prev_states in any::<Vec<((u64, u64), [u8; 32])>>(),
+            proof_signer in any::<[u8; 20]>(),
+            proof_signature in any::<[u8; 65]>()
+        ) {
+            let c1 = MisbehaviourCommitment {
+                client_id: ClientId::new("07-tendermint", client_counter).unwrap(),
+                frozen_height,
+                prev_states: prev_states
+                    .into_iter()
+                    .map(|(height, state_id)| {
+                        PrevState::new(height_from_tuple(height), state_id.into())
+                    })
+                    .collect(),
+                context: Default::default(),
+            };
+            let v = c1.clone().ethabi_encode();
+            let c2 = MisbehaviourCommitment::ethabi_decode(&v).unwrap();
+            assert_eq!(c1, c2);
+
+            let p1 = CommitmentProof {
+                commitment_bytes: Commitment::from(c1).to_commitment_bytes(),
+                signer: Address(proof_signer),
+                signature: proof_signature.to_vec(),
+            };
+            let p2 = CommitmentProof::ethabi_decode(&p1.clone().ethabi_encode()).unwrap();
+            assert_eq!(p1, p2);
+        }
     }
 }
@@ -22,6 +22,16 @@ impl CommitmentContext {
         }
     }
 
+    /// aggregate returns a context that is valid only if both `self` and `other` are valid
+    pub fn aggregate(self, other: Self) -> Self {
+        match (self, other) {
+            (CommitmentContext::Empty, ctx) | (ctx, CommitmentContext::Empty) => ctx,
+            (CommitmentContext::TrustingPeriod(c1), CommitmentContext::TrustingPeriod(c2)) => {
+                CommitmentContext::TrustingPeriod(c1.aggregate(c2))
+            }
+        }
+    }
+
     // MSB first
     // 0-1:  type
     // 2-31: reserved
@@ -203,6 +213,20 @@ impl TrustingPeriodContext {
         Ok(())
     }
 
+    /// aggregate returns the most restrictive context of `self` and `other`
+    pub fn aggregate(self, other: Self) -> Self {
+        Self {
+            trusting_period: self.trusting_period.min(other.trusting_period),
+            clock_drift: self.clock_drift.min(other.clock_drift),
+            untrusted_header_timestamp: self
+                .untrusted_header_timestamp
+                .max(other.untrusted_header_timestamp),
+            trusted_state_timestamp: self
+                .trusted_state_timestamp
+                .min(other.trusted_state_timestamp),
+        }
+    }
+
     fn ensure_within_trust_period(
         now: Time,
         trusted_state_time: Time,
@@ -536,4 +560,41 @@ mod tests {
             validate_and_assert_no_error(ctx, current_timestamp);
         }
     }
+
+    #[test]
+    fn test_trusting_period_context_aggregation() {
+        let current_timestamp = datetime!(2023-08-20 0:00 UTC);
+        let ctx1 = build_trusting_period_context(
+            3,
+            2,
+            current_timestamp - Duration::new(0, 1),
+            current_timestamp - Duration::new(0, 2),
+        );
+        let ctx2 = build_trusting_period_context(
+            2,
+            1,
+            current_timestamp + Duration::new(0, 1),
+            current_timestamp - Duration::new(0, 1),
+        );
+        validate_and_assert_no_error(ctx1.clone(), current_timestamp);
+        validate_and_assert_clock_drift_error(ctx2.clone(), current_timestamp);
+
+        let ctx = ctx1.clone().aggregate(ctx2.clone());
+        assert_eq!(ctx, ctx2.clone().aggregate(ctx1.clone()));
+        assert_eq!(
+            ctx,
+            build_trusting_period_context(
+                2,
+                1,
+                current_timestamp + Duration::new(0, 1),
+                current_timestamp - Duration::new(0, 2),
+            )
+        );
+        validate_and_assert_trusting_period_error(ctx, current_timestamp);
+
+        let ctx = CommitmentContext::Empty.aggregate(ctx1.clone().into());
+        assert_eq!(ctx, CommitmentContext::TrustingPeriod(ctx1));
+        let ctx = CommitmentContext::Empty.aggregate(CommitmentContext::Empty);
+        assert_eq!(ctx, CommitmentContext::Empty);
+    }
 }
@@ -20,12 +20,14 @@ mod prelude {
 }
 
 pub use commitment::{
-    Commitment, CommitmentPrefix, EthABIEncoder, StateCommitment, UpdateClientCommitment,
+    BatchStateCommitment, Commitment, CommitmentPrefix, EthABIEncoder, MisbehaviourCommitment,
+    PrevState, RecoverClientCommitment, StateCommitment, StateCommitmentEntry,
+    UpdateClientCommitment,
 };
 pub use context::{CommitmentContext, TrustingPeriodContext};
 pub use errors::Error;
 pub use proof::CommitmentProof;
-pub use prover::prove_commitment;
+pub use prover::{prove_commitment, revoke_enclave_key_sign_bytes};
 pub use state::{gen_state_id_from_any, gen_state_id_from_bytes, StateID, STATE_ID_SIZE};
 
 mod commitment;
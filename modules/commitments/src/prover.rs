@@ -1,6 +1,7 @@
 use crate::errors::Error;
 use crate::{prelude::*, Commitment, CommitmentProof};
 use crypto::{Address, Signer};
+use lcp_types::ClientId;
 
 pub fn prove_commitment(
     signer: &dyn Signer,
@@ -15,3 +16,10 @@ pub fn prove_commitment(
         signature,
     ))
 }
+
+/// revoke_enclave_key_sign_bytes returns the bytes to be signed to revoke the key of the client
+///
+/// `sequence` is the client's revocation sequence, so that the signature can be used only once.
+pub fn revoke_enclave_key_sign_bytes(client_id: &ClientId, sequence: u64, key: Address) -> Vec<u8> {
+    format!("revoke_enclave_key/{}/{}/{}", client_id, sequence, key).into_bytes()
+}
@@ -1,11 +1,11 @@
 use crate::prelude::*;
 use crypto::Signer;
-use lcp_types::Time;
+use lcp_types::{Any, Time};
 use light_client::{
-    ClientKeeper, ClientReader, HostClientKeeper, HostClientReader, HostContext,
+    ClientKeeper, ClientReader, HostClientKeeper, HostClientReader, HostContext, LightClientInfo,
     LightClientResolver,
 };
-use store::KVStore;
+use store::{KVStore, KV};
 
 pub struct Context<'k, R: LightClientResolver, S: KVStore, K: Signer> {
     lc_registry: R,
@@ -45,6 +45,10 @@ impl<'k, R: LightClientResolver, S: KVStore, K: Signer> KVStore for Context<'k,
     fn remove(&mut self, key: &[u8]) {
         self.store.remove(key)
     }
+
+    fn scan_prefix(&self, prefix: &[u8], start_after: Option<&[u8]>, limit: usize) -> Vec<KV> {
+        self.store.scan_prefix(prefix, start_after, limit)
+    }
 }
 
 impl<'k, R: LightClientResolver, S: KVStore, K: Signer> HostContext for Context<'k, R, S, K> {
@@ -53,7 +57,19 @@ impl<'k, R: LightClientResolver, S: KVStore, K: Signer> HostContext for Context<
     }
 }
 
-impl<'k, R: LightClientResolver, S: KVStore, K: Signer> ClientReader for Context<'k, R, S, K> {}
+impl<'k, R: LightClientResolver, S: KVStore, K: Signer> ClientReader for Context<'k, R, S, K> {
+    fn normalize_client_state_type_url(&self, any_client_state: Any) -> Any {
+        match self
+            .lc_registry
+            .resolve_type_url(&any_client_state.type_url)
+        {
+            Some(type_url) if type_url != any_client_state.type_url => {
+                Any::new(type_url.to_string(), any_client_state.to_proto().value)
+            }
+            _ => any_client_state,
+        }
+    }
+}
 
 impl<'k, R: LightClientResolver, S: KVStore, K: Signer> ClientKeeper for Context<'k, R, S, K> {}
 
@@ -70,4 +86,20 @@ impl<'k, R: LightClientResolver, S: KVStore, K: Signer> LightClientResolver
     ) -> Option<&alloc::boxed::Box<dyn light_client::LightClient>> {
         self.lc_registry.get_light_client(type_url)
     }
+
+    fn get_light_client_by_client_type(
+        &self,
+        client_type: &str,
+    ) -> Option<&alloc::boxed::Box<dyn light_client::LightClient>> {
+        self.lc_registry
+            .get_light_client_by_client_type(client_type)
+    }
+
+    fn resolve_type_url(&self, type_url: &str) -> Option<&str> {
+        self.lc_registry.resolve_type_url(type_url)
+    }
+
+    fn list_light_clients(&self) -> Vec<LightClientInfo> {
+        self.lc_registry.list_light_clients()
+    }
 }
@@ -1,6 +1,7 @@
 use crate::{prelude::*, EnclaveKeySelector, InputValidationError as Error};
 use attestation_report::EndorsedAttestationVerificationReport;
 use crypto::{Address, EnclavePublicKey, SealedEnclaveKey};
+use lcp_types::ClientId;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -9,6 +10,7 @@ pub enum EnclaveManageCommand {
     IASRemoteAttestation(IASRemoteAttestationInput),
     #[cfg(feature = "sgx-sw")]
     SimulateRemoteAttestation(SimulateRemoteAttestationInput),
+    SignRevokeEnclaveKey(SignRevokeEnclaveKeyInput),
 }
 
 impl EnclaveKeySelector for EnclaveManageCommand {
@@ -18,6 +20,7 @@ impl EnclaveKeySelector for EnclaveManageCommand {
             Self::IASRemoteAttestation(input) => Some(input.target_enclave_key),
             #[cfg(feature = "sgx-sw")]
             Self::SimulateRemoteAttestation(input) => Some(input.target_enclave_key),
+            Self::SignRevokeEnclaveKey(input) => Some(input.signer),
         }
     }
 }
@@ -59,12 +62,23 @@ impl SimulateRemoteAttestationInput {
     }
 }
 
+/// `SignRevokeEnclaveKeyInput` is an input to sign the revocation of `key` in the LCP client `client_id` with the enclave key `signer`
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SignRevokeEnclaveKeyInput {
+    pub client_id: ClientId,
+    /// the current revocation sequence of the LCP client
+    pub sequence: u64,
+    pub key: Address,
+    pub signer: Address,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub enum EnclaveManageResult {
     GenerateEnclaveKey(GenerateEnclaveKeyResult),
     IASRemoteAttestation(IASRemoteAttestationResult),
     #[cfg(feature = "sgx-sw")]
     SimulateRemoteAttestation(SimulateRemoteAttestationResult),
+    SignRevokeEnclaveKey(SignRevokeEnclaveKeyResult),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -83,3 +97,9 @@ pub struct IASRemoteAttestationResult {
 pub struct SimulateRemoteAttestationResult {
     pub avr: attestation_report::AttestationVerificationReport,
 }
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SignRevokeEnclaveKeyResult {
+    pub signer: Address,
+    pub signature: Vec<u8>,
+}
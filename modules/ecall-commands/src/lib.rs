@@ -24,16 +24,24 @@ pub use commands::{Command, CommandContext, CommandResult, ECallCommand};
 use crypto::Address;
 pub use enclave_manage::{
     EnclaveManageCommand, EnclaveManageResult, GenerateEnclaveKeyInput, GenerateEnclaveKeyResult,
-    IASRemoteAttestationInput, IASRemoteAttestationResult,
+    IASRemoteAttestationInput, IASRemoteAttestationResult, SignRevokeEnclaveKeyInput,
+    SignRevokeEnclaveKeyResult,
 };
 #[cfg(feature = "sgx-sw")]
 pub use enclave_manage::{SimulateRemoteAttestationInput, SimulateRemoteAttestationResult};
 pub use errors::InputValidationError;
 pub use light_client::{
-    CommitmentProofPair, InitClientInput, InitClientResult, LightClientCommand,
-    LightClientExecuteCommand, LightClientQueryCommand, LightClientResult, QueryClientInput,
-    QueryClientResult, UpdateClientInput, UpdateClientResult, VerifyMembershipInput,
+    ClientInfo, ClientSummary, CommitmentProofPair, InitClientInput, InitClientResult,
+    LightClientCommand, LightClientExecuteCommand, LightClientInfo, LightClientQueryCommand,
+    LightClientResult, PruneConsensusStatesInput, PruneConsensusStatesResult, QueryClientInput,
+    QueryClientResult, QueryClientStatusInput, QueryClientStatusResult, QueryClientsInput,
+    QueryClientsResult, QueryConsensusStateHeightsInput, QueryConsensusStateHeightsResult,
+    QueryConsensusStateInput, QueryConsensusStateResult, QueryLightClientsInput,
+    QueryLightClientsResult, RecoverClientInput, RecoverClientResult, SubmitMisbehaviourInput,
+    SubmitMisbehaviourResult, UpdateClientBatchInput, UpdateClientBatchResult, UpdateClientInput,
+    UpdateClientResult, UpgradeClientInput, UpgradeClientResult, VerifyMembershipInput,
     VerifyMembershipResult, VerifyNonMembershipInput, VerifyNonMembershipResult,
+    VerifyStateBatchInput, VerifyStateBatchResult, VerifyStateEntry,
 };
 
 mod commands;
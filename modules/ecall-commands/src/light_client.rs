@@ -1,7 +1,8 @@
 use crate::{prelude::*, EnclaveKeySelector};
 use commitments::CommitmentProof;
+use core::time::Duration;
 use crypto::Address;
-use lcp_types::{Any, ClientId, Height, Time};
+use lcp_types::{Any, ClientId, ClientStatus, Height, Pagination, Time};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -14,13 +15,24 @@ pub enum LightClientCommand {
 pub enum LightClientExecuteCommand {
     InitClient(InitClientInput),
     UpdateClient(UpdateClientInput),
+    UpdateClientBatch(UpdateClientBatchInput),
+    SubmitMisbehaviour(SubmitMisbehaviourInput),
+    PruneConsensusStates(PruneConsensusStatesInput),
+    RecoverClient(RecoverClientInput),
+    UpgradeClient(UpgradeClientInput),
     VerifyMembership(VerifyMembershipInput),
     VerifyNonMembership(VerifyNonMembershipInput),
+    VerifyStateBatch(VerifyStateBatchInput),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub enum LightClientQueryCommand {
     QueryClient(QueryClientInput),
+    QueryConsensusState(QueryConsensusStateInput),
+    QueryConsensusStateHeights(QueryConsensusStateHeightsInput),
+    QueryClients(QueryClientsInput),
+    QueryClientStatus(QueryClientStatusInput),
+    QueryLightClients(QueryLightClientsInput),
 }
 
 impl EnclaveKeySelector for LightClientCommand {
@@ -29,8 +41,14 @@ impl EnclaveKeySelector for LightClientCommand {
             Self::Execute(cmd) => match cmd {
                 LightClientExecuteCommand::InitClient(input) => Some(input.signer),
                 LightClientExecuteCommand::UpdateClient(input) => Some(input.signer),
+                LightClientExecuteCommand::UpdateClientBatch(input) => Some(input.signer),
+                LightClientExecuteCommand::SubmitMisbehaviour(input) => Some(input.signer),
+                LightClientExecuteCommand::PruneConsensusStates(_) => None,
+                LightClientExecuteCommand::RecoverClient(input) => Some(input.signer),
+                LightClientExecuteCommand::UpgradeClient(input) => Some(input.signer),
                 LightClientExecuteCommand::VerifyMembership(input) => Some(input.signer),
                 LightClientExecuteCommand::VerifyNonMembership(input) => Some(input.signer),
+                LightClientExecuteCommand::VerifyStateBatch(input) => Some(input.signer),
             },
             Self::Query(_) => None,
         }
@@ -54,6 +72,60 @@ pub struct UpdateClientInput {
     pub signer: Address,
 }
 
+/// `UpdateClientBatchInput` is an input to apply a sequence of headers to the client atomically
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateClientBatchInput {
+    pub client_id: ClientId,
+    pub any_headers: Vec<Any>,
+    pub include_state: bool,
+    pub current_timestamp: Time,
+    pub signer: Address,
+}
+
+/// `SubmitMisbehaviourInput` is an input to freeze the client with the evidence of a misbehaviour
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SubmitMisbehaviourInput {
+    pub client_id: ClientId,
+    pub any_misbehaviour: Any,
+    pub current_timestamp: Time,
+    pub signer: Address,
+}
+
+/// `PruneConsensusStatesInput` is an input to delete the expired consensus states of the client
+///
+/// If `retention_period` is None, the trusting period of the client is used.
+/// `limit` is the maximum number of consensus states to delete.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PruneConsensusStatesInput {
+    pub client_id: ClientId,
+    pub retention_period: Option<Duration>,
+    pub limit: u64,
+    pub current_timestamp: Time,
+}
+
+/// `RecoverClientInput` is an input to replace the state of the expired or frozen subject client with the state of the substitute client
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecoverClientInput {
+    pub subject_client_id: ClientId,
+    pub substitute_client_id: ClientId,
+    pub current_timestamp: Time,
+    pub signer: Address,
+}
+
+/// `UpgradeClientInput` is an input to upgrade the client with the upgraded states committed by the counterparty chain
+///
+/// The proofs must be the proofs of the upgraded states against the root of the latest consensus state of the client.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpgradeClientInput {
+    pub client_id: ClientId,
+    pub upgraded_any_client_state: Any,
+    pub upgraded_any_consensus_state: Any,
+    pub proof_upgrade_client: Vec<u8>,
+    pub proof_upgrade_consensus_state: Vec<u8>,
+    pub current_timestamp: Time,
+    pub signer: Address,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct VerifyMembershipInput {
     pub client_id: ClientId,
@@ -73,23 +145,87 @@ pub struct VerifyNonMembershipInput {
     pub signer: Address,
 }
 
+/// `VerifyStateBatchInput` is an input to verify multiple states against the consensus state at `proof_height`
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VerifyStateBatchInput {
+    pub client_id: ClientId,
+    pub proof_height: Height,
+    pub entries: Vec<VerifyStateEntry>,
+    pub signer: Address,
+}
+
+/// `VerifyStateEntry` is an entry of `VerifyStateBatchInput`
+///
+/// If `value` is None, the entry is verified as the non-existence of the state at the path.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VerifyStateEntry {
+    pub prefix: Vec<u8>,
+    pub path: String,
+    pub value: Option<Vec<u8>>,
+    pub proof: Vec<u8>,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct CommitmentProofPair(pub Height, pub Vec<u8>);
 
+/// `QueryClientInput` is an input to query the client state and the consensus state
+///
+/// If `height` is None, the consensus state at the latest height is returned.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct QueryClientInput {
     pub client_id: ClientId,
+    pub height: Option<Height>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QueryConsensusStateInput {
+    pub client_id: ClientId,
+    pub height: Height,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QueryConsensusStateHeightsInput {
+    pub client_id: ClientId,
+    pub pagination: Pagination<Height>,
 }
 
+/// `QueryClientsInput` is an input to list a page of the clients in ascending order of the client identifier
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QueryClientsInput {
+    pub pagination: Pagination<ClientId>,
+    pub current_timestamp: Time,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QueryClientStatusInput {
+    pub client_id: ClientId,
+    pub current_timestamp: Time,
+}
+
+/// `QueryLightClientsInput` is an input to list the ELCs that the enclave supports
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct QueryLightClientsInput;
+
 #[derive(Serialize, Deserialize, Debug)]
 pub enum LightClientResult {
     InitClient(InitClientResult),
     UpdateClient(UpdateClientResult),
+    UpdateClientBatch(UpdateClientBatchResult),
+    SubmitMisbehaviour(SubmitMisbehaviourResult),
+    PruneConsensusStates(PruneConsensusStatesResult),
+    RecoverClient(RecoverClientResult),
+    UpgradeClient(UpgradeClientResult),
 
     VerifyMembership(VerifyMembershipResult),
     VerifyNonMembership(VerifyNonMembershipResult),
+    VerifyStateBatch(VerifyStateBatchResult),
 
     QueryClient(QueryClientResult),
+    QueryConsensusState(QueryConsensusStateResult),
+    QueryConsensusStateHeights(QueryConsensusStateHeightsResult),
+    QueryClients(QueryClientsResult),
+    QueryClientStatus(QueryClientStatusResult),
+    QueryLightClients(QueryLightClientsResult),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -102,14 +238,94 @@ pub struct InitClientResult {
 #[serde(transparent)]
 pub struct UpdateClientResult(pub CommitmentProof);
 
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(transparent)]
+pub struct UpdateClientBatchResult(pub CommitmentProof);
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(transparent)]
+pub struct SubmitMisbehaviourResult(pub CommitmentProof);
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PruneConsensusStatesResult {
+    pub pruned_heights: Vec<Height>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(transparent)]
+pub struct RecoverClientResult(pub CommitmentProof);
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(transparent)]
+pub struct UpgradeClientResult(pub CommitmentProof);
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct VerifyMembershipResult(pub CommitmentProof);
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct VerifyNonMembershipResult(pub CommitmentProof);
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VerifyStateBatchResult(pub CommitmentProof);
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct QueryClientResult {
     pub any_client_state: Any,
     pub any_consensus_state: Any,
 }
+
+/// `QueryConsensusStateResult` is a result of the consensus state query
+///
+/// `any_consensus_state` is None if the client has no consensus state at the given height.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QueryConsensusStateResult {
+    pub any_consensus_state: Option<Any>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QueryConsensusStateHeightsResult {
+    pub heights: Vec<Height>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QueryClientsResult {
+    pub clients: Vec<ClientInfo>,
+}
+
+/// `ClientInfo` is a summary of a client
+///
+/// If the client cannot be read, `summary` holds the error message so that the other clients are still listed.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ClientInfo {
+    pub client_id: ClientId,
+    pub summary: Result<ClientSummary, String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ClientSummary {
+    pub client_type: String,
+    pub latest_height: Height,
+    pub status: ClientStatus,
+}
+
+/// `QueryClientStatusResult` is a result of the client status query
+///
+/// `expires_at` is the time when the latest consensus state leaves the trusting period.
+/// It is None if the client has no trusting period.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QueryClientStatusResult {
+    pub status: ClientStatus,
+    pub expires_at: Option<Time>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QueryLightClientsResult {
+    pub light_clients: Vec<LightClientInfo>,
+}
+
+/// `LightClientInfo` is a summary of an ELC implementation registered in the enclave
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LightClientInfo {
+    pub client_type: String,
+    pub client_state_type_urls: Vec<String>,
+}
@@ -2,13 +2,24 @@ use crate::errors::InputValidationError as Error;
 use crate::light_client::*;
 use crate::prelude::*;
 use core::str::FromStr;
+use core::time::Duration;
 use crypto::Address;
 use lcp_types::proto::lcp::service::elc::v1::{
-    MsgCreateClient, MsgCreateClientResponse, MsgUpdateClient, MsgUpdateClientResponse,
-    MsgVerifyMembership, MsgVerifyMembershipResponse, MsgVerifyNonMembership,
-    MsgVerifyNonMembershipResponse, QueryClientRequest, QueryClientResponse,
+    IdentifiedClient, MsgCreateClient, MsgCreateClientResponse, MsgPruneConsensusStates,
+    MsgPruneConsensusStatesResponse, MsgRecoverClient, MsgRecoverClientResponse,
+    MsgSubmitMisbehaviour, MsgSubmitMisbehaviourResponse, MsgUpdateClient, MsgUpdateClientBatch,
+    MsgUpdateClientBatchResponse, MsgUpdateClientResponse, MsgUpgradeClient,
+    MsgUpgradeClientResponse, MsgVerifyMembership, MsgVerifyMembershipResponse,
+    MsgVerifyNonMembership, MsgVerifyNonMembershipResponse, MsgVerifyStateBatch,
+    MsgVerifyStateBatchResponse, QueryClientRequest, QueryClientResponse, QueryClientStatusRequest,
+    QueryClientStatusResponse, QueryClientsRequest, QueryClientsResponse,
+    QueryConsensusStateHeightsRequest, QueryConsensusStateHeightsResponse,
+    QueryConsensusStateRequest,
 };
-use lcp_types::{ClientId, Time};
+use lcp_types::proto::lcp::service::enclave::v1::{
+    LightClientInfo as ProtoLightClientInfo, QueryLightClientsRequest, QueryLightClientsResponse,
+};
+use lcp_types::{ClientId, Pagination, Time};
 
 impl TryFrom<MsgCreateClient> for InitClientInput {
     type Error = Error;
@@ -48,6 +59,101 @@ impl TryFrom<MsgUpdateClient> for UpdateClientInput {
     }
 }
 
+impl TryFrom<MsgUpdateClientBatch> for UpdateClientBatchInput {
+    type Error = Error;
+    fn try_from(msg: MsgUpdateClientBatch) -> Result<Self, Error> {
+        if msg.headers.is_empty() {
+            return Err(Error::invalid_argument("headers must be non-empty".into()));
+        }
+        let any_headers = msg.headers.into_iter().map(Into::into).collect();
+        let client_id = ClientId::from_str(&msg.client_id)?;
+        Ok(Self {
+            client_id,
+            any_headers,
+            include_state: msg.include_state,
+            current_timestamp: Time::now(),
+            signer: Address::try_from(msg.signer.as_slice())?,
+        })
+    }
+}
+
+impl TryFrom<MsgSubmitMisbehaviour> for SubmitMisbehaviourInput {
+    type Error = Error;
+    fn try_from(msg: MsgSubmitMisbehaviour) -> Result<Self, Error> {
+        let any_misbehaviour = msg
+            .misbehaviour
+            .ok_or_else(|| Error::invalid_argument("misbehaviour must be non-nil".into()))?
+            .into();
+        let client_id = ClientId::from_str(&msg.client_id)?;
+        Ok(Self {
+            client_id,
+            any_misbehaviour,
+            current_timestamp: Time::now(),
+            signer: Address::try_from(msg.signer.as_slice())?,
+        })
+    }
+}
+
+impl TryFrom<MsgPruneConsensusStates> for PruneConsensusStatesInput {
+    type Error = Error;
+    fn try_from(msg: MsgPruneConsensusStates) -> Result<Self, Error> {
+        let client_id = ClientId::from_str(&msg.client_id)?;
+        Ok(Self {
+            client_id,
+            retention_period: if msg.retention_period == 0 {
+                None
+            } else {
+                Some(Duration::from_secs(msg.retention_period))
+            },
+            limit: if msg.limit == 0 { u64::MAX } else { msg.limit },
+            current_timestamp: Time::now(),
+        })
+    }
+}
+
+impl TryFrom<MsgRecoverClient> for RecoverClientInput {
+    type Error = Error;
+    fn try_from(msg: MsgRecoverClient) -> Result<Self, Error> {
+        let subject_client_id = ClientId::from_str(&msg.subject_client_id)?;
+        let substitute_client_id = ClientId::from_str(&msg.substitute_client_id)?;
+        if subject_client_id == substitute_client_id {
+            return Err(Error::invalid_argument(
+                "subject_client_id and substitute_client_id must be different".into(),
+            ));
+        }
+        Ok(Self {
+            subject_client_id,
+            substitute_client_id,
+            current_timestamp: Time::now(),
+            signer: Address::try_from(msg.signer.as_slice())?,
+        })
+    }
+}
+
+impl TryFrom<MsgUpgradeClient> for UpgradeClientInput {
+    type Error = Error;
+    fn try_from(msg: MsgUpgradeClient) -> Result<Self, Error> {
+        let upgraded_any_client_state = msg
+            .client_state
+            .ok_or_else(|| Error::invalid_argument("client_state must be non-nil".into()))?
+            .into();
+        let upgraded_any_consensus_state = msg
+            .consensus_state
+            .ok_or_else(|| Error::invalid_argument("consensus_state must be non-nil".into()))?
+            .into();
+        let client_id = ClientId::from_str(&msg.client_id)?;
+        Ok(Self {
+            client_id,
+            upgraded_any_client_state,
+            upgraded_any_consensus_state,
+            proof_upgrade_client: msg.proof_upgrade_client,
+            proof_upgrade_consensus_state: msg.proof_upgrade_consensus_state,
+            current_timestamp: Time::now(),
+            signer: Address::try_from(msg.signer.as_slice())?,
+        })
+    }
+}
+
 impl TryFrom<MsgVerifyMembership> for VerifyMembershipInput {
     type Error = Error;
 
@@ -91,11 +197,105 @@ impl TryFrom<MsgVerifyNonMembership> for VerifyNonMembershipInput {
     }
 }
 
+impl TryFrom<MsgVerifyStateBatch> for VerifyStateBatchInput {
+    type Error = Error;
+
+    fn try_from(msg: MsgVerifyStateBatch) -> Result<Self, Self::Error> {
+        let client_id = ClientId::from_str(&msg.client_id)?;
+        let proof_height = msg
+            .proof_height
+            .ok_or_else(|| Error::invalid_argument("proof_height must be non-nil".into()))?
+            .into();
+        if msg.entries.is_empty() {
+            return Err(Error::invalid_argument("entries must be non-empty".into()));
+        }
+        let entries = msg
+            .entries
+            .into_iter()
+            .map(|entry| VerifyStateEntry {
+                prefix: entry.prefix,
+                path: entry.path,
+                value: if entry.value.is_empty() {
+                    None
+                } else {
+                    Some(entry.value)
+                },
+                proof: entry.proof,
+            })
+            .collect();
+        Ok(Self {
+            client_id,
+            proof_height,
+            entries,
+            signer: Address::try_from(msg.signer.as_slice())?,
+        })
+    }
+}
+
 impl TryFrom<QueryClientRequest> for QueryClientInput {
     type Error = Error;
     fn try_from(query: QueryClientRequest) -> Result<Self, Error> {
         let client_id = ClientId::from_str(&query.client_id)?;
-        Ok(Self { client_id })
+        Ok(Self {
+            client_id,
+            height: query.height.map(Into::into),
+        })
+    }
+}
+
+impl TryFrom<QueryConsensusStateRequest> for QueryConsensusStateInput {
+    type Error = Error;
+    fn try_from(query: QueryConsensusStateRequest) -> Result<Self, Error> {
+        let client_id = ClientId::from_str(&query.client_id)?;
+        let height = query
+            .height
+            .ok_or_else(|| Error::invalid_argument("height must be non-nil".into()))?
+            .into();
+        Ok(Self { client_id, height })
+    }
+}
+
+impl TryFrom<QueryClientStatusRequest> for QueryClientStatusInput {
+    type Error = Error;
+    fn try_from(query: QueryClientStatusRequest) -> Result<Self, Error> {
+        let client_id = ClientId::from_str(&query.client_id)?;
+        Ok(Self {
+            client_id,
+            current_timestamp: Time::now(),
+        })
+    }
+}
+
+impl TryFrom<QueryClientsRequest> for QueryClientsInput {
+    type Error = Error;
+    fn try_from(query: QueryClientsRequest) -> Result<Self, Error> {
+        let start_after = if query.start_after.is_empty() {
+            None
+        } else {
+            Some(ClientId::from_str(&query.start_after)?)
+        };
+        Ok(Self {
+            pagination: Pagination::new(start_after, query.limit),
+            current_timestamp: Time::now(),
+        })
+    }
+}
+
+impl TryFrom<QueryLightClientsRequest> for QueryLightClientsInput {
+    type Error = Error;
+    fn try_from(_: QueryLightClientsRequest) -> Result<Self, Error> {
+        Ok(Self)
+    }
+}
+
+impl TryFrom<QueryConsensusStateHeightsRequest> for QueryConsensusStateHeightsInput {
+    type Error = Error;
+    fn try_from(query: QueryConsensusStateHeightsRequest) -> Result<Self, Error> {
+        let client_id = ClientId::from_str(&query.client_id)?;
+        Ok(Self {
+            client_id,
+            pagination: Pagination::new(query.start_after.map(Into::into), query.limit),
+        })
     }
 }
 
@@ -120,6 +320,54 @@ impl From<UpdateClientResult> for MsgUpdateClientResponse {
     }
 }
 
+impl From<UpdateClientBatchResult> for MsgUpdateClientBatchResponse {
+    fn from(res: UpdateClientBatchResult) -> Self {
+        Self {
+            commitment: res.0.commitment_bytes,
+            signer: res.0.signer.into(),
+            signature: res.0.signature,
+        }
+    }
+}
+
+impl From<SubmitMisbehaviourResult> for MsgSubmitMisbehaviourResponse {
+    fn from(res: SubmitMisbehaviourResult) -> Self {
+        Self {
+            commitment: res.0.commitment_bytes,
+            signer: res.0.signer.into(),
+            signature: res.0.signature,
+        }
+    }
+}
+
+impl From<PruneConsensusStatesResult> for MsgPruneConsensusStatesResponse {
+    fn from(res: PruneConsensusStatesResult) -> Self {
+        Self {
+            pruned_heights: res.pruned_heights.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<RecoverClientResult> for MsgRecoverClientResponse {
+    fn from(res: RecoverClientResult) -> Self {
+        Self {
+            commitment: res.0.commitment_bytes,
+            signer: res.0.signer.into(),
+            signature: res.0.signature,
+        }
+    }
+}
+
+impl From<UpgradeClientResult> for MsgUpgradeClientResponse {
+    fn from(res: UpgradeClientResult) -> Self {
+        Self {
+            commitment: res.0.commitment_bytes,
+            signer: res.0.signer.into(),
+            signature: res.0.signature,
+        }
+    }
+}
+
 impl From<VerifyMembershipResult> for MsgVerifyMembershipResponse {
     fn from(res: VerifyMembershipResult) -> Self {
         Self {
@@ -140,6 +388,16 @@ impl From<VerifyNonMembershipResult> for MsgVerifyNonMembershipResponse {
     }
 }
 
+impl From<VerifyStateBatchResult> for MsgVerifyStateBatchResponse {
+    fn from(res: VerifyStateBatchResult) -> Self {
+        Self {
+            commitment: res.0.commitment_bytes,
+            signer: res.0.signer.to_vec(),
+            signature: res.0.signature,
+        }
+    }
+}
+
 impl From<QueryClientResult> for QueryClientResponse {
     fn from(res: QueryClientResult) -> Self {
         Self {
@@ -148,3 +406,67 @@ impl From<QueryClientResult> for QueryClientResponse {
         }
     }
 }
+
+impl From<QueryConsensusStateHeightsResult> for QueryConsensusStateHeightsResponse {
+    fn from(res: QueryConsensusStateHeightsResult) -> Self {
+        Self {
+            heights: res.heights.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<QueryClientsResult> for QueryClientsResponse {
+    fn from(res: QueryClientsResult) -> Self {
+        Self {
+            clients: res.clients.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<ClientInfo> for IdentifiedClient {
+    fn from(info: ClientInfo) -> Self {
+        match info.summary {
+            Ok(summary) => Self {
+                client_id: info.client_id.to_string(),
+                client_type: summary.client_type,
+                latest_height: Some(summary.latest_height.into()),
+                status: summary.status.to_string(),
+                error: Default::default(),
+            },
+            Err(error) => Self {
+                client_id: info.client_id.to_string(),
+                error,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl From<QueryClientStatusResult> for QueryClientStatusResponse {
+    fn from(res: QueryClientStatusResult) -> Self {
+        Self {
+            status: res.status.to_string(),
+            expires_at: res
+                .expires_at
+                .map(|t| t.as_unix_timestamp_secs())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl From<QueryLightClientsResult> for QueryLightClientsResponse {
+    fn from(res: QueryLightClientsResult) -> Self {
+        Self {
+            light_clients: res.light_clients.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<LightClientInfo> for ProtoLightClientInfo {
+    fn from(info: LightClientInfo) -> Self {
+        Self {
+            client_type: info.client_type,
+            client_state_type_urls: info.client_state_type_urls,
+        }
+    }
+}
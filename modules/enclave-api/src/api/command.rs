@@ -1,12 +1,20 @@
-use crate::{EnclavePrimitiveAPI, Result};
+use crate::{EnclavePrimitiveAPI, Error, Result};
 use ecall_commands::{
     Command, CommandResult, EnclaveManageCommand, EnclaveManageResult, GenerateEnclaveKeyInput,
     GenerateEnclaveKeyResult, IASRemoteAttestationInput, IASRemoteAttestationResult,
     InitClientInput, InitClientResult, LightClientCommand, LightClientExecuteCommand,
-    LightClientQueryCommand, LightClientResult, QueryClientInput, QueryClientResult,
-    UpdateClientInput, UpdateClientResult, VerifyMembershipInput, VerifyMembershipResult,
-    VerifyNonMembershipInput, VerifyNonMembershipResult,
+    LightClientQueryCommand, LightClientResult, PruneConsensusStatesInput,
+    PruneConsensusStatesResult, QueryClientInput, QueryClientResult, QueryClientStatusInput,
+    QueryClientStatusResult, QueryClientsInput, QueryClientsResult,
+    QueryConsensusStateHeightsInput, QueryConsensusStateHeightsResult, QueryConsensusStateInput,
+    QueryLightClientsInput, QueryLightClientsResult, RecoverClientInput, RecoverClientResult,
+    SignRevokeEnclaveKeyInput, SignRevokeEnclaveKeyResult, SubmitMisbehaviourInput,
+    SubmitMisbehaviourResult, UpdateClientBatchInput, UpdateClientBatchResult, UpdateClientInput,
+    UpdateClientResult, UpgradeClientInput, UpgradeClientResult, VerifyMembershipInput,
+    VerifyMembershipResult, VerifyNonMembershipInput, VerifyNonMembershipResult,
+    VerifyStateBatchInput, VerifyStateBatchResult,
 };
+use lcp_types::Any;
 use store::transaction::CommitStore;
 
 pub trait EnclaveCommandAPI<S: CommitStore>: EnclavePrimitiveAPI<S> {
@@ -49,6 +57,20 @@ pub trait EnclaveCommandAPI<S: CommitStore>: EnclavePrimitiveAPI<S> {
         Ok(res)
     }
 
+    /// sign_revoke_enclave_key signs the revocation of the key in the LCP client with the enclave key
+    fn sign_revoke_enclave_key(
+        &self,
+        input: SignRevokeEnclaveKeyInput,
+    ) -> Result<SignRevokeEnclaveKeyResult> {
+        match self.execute_command(
+            Command::EnclaveManage(EnclaveManageCommand::SignRevokeEnclaveKey(input)),
+            None,
+        )? {
+            CommandResult::EnclaveManage(EnclaveManageResult::SignRevokeEnclaveKey(res)) => Ok(res),
+            _ => unreachable!(),
+        }
+    }
+
     /// simulate_remote_attestation simulates Remote Attestation
     #[cfg(feature = "sgx-sw")]
     fn simulate_remote_attestation(
@@ -109,6 +131,85 @@ pub trait EnclaveCommandAPI<S: CommitStore>: EnclavePrimitiveAPI<S> {
         }
     }
 
+    /// update_client_batch updates the ELC instance corresponding to client_id with the given headers in order
+    fn update_client_batch(
+        &self,
+        input: UpdateClientBatchInput,
+    ) -> Result<UpdateClientBatchResult> {
+        let update_key = Some(input.client_id.to_string());
+        match self.execute_command(
+            Command::LightClient(LightClientCommand::Execute(
+                LightClientExecuteCommand::UpdateClientBatch(input),
+            )),
+            update_key,
+        )? {
+            CommandResult::LightClient(LightClientResult::UpdateClientBatch(res)) => Ok(res),
+            _ => unreachable!(),
+        }
+    }
+
+    /// submit_misbehaviour freezes the ELC instance corresponding to client_id with the given misbehaviour
+    fn submit_misbehaviour(
+        &self,
+        input: SubmitMisbehaviourInput,
+    ) -> Result<SubmitMisbehaviourResult> {
+        let update_key = Some(input.client_id.to_string());
+        match self.execute_command(
+            Command::LightClient(LightClientCommand::Execute(
+                LightClientExecuteCommand::SubmitMisbehaviour(input),
+            )),
+            update_key,
+        )? {
+            CommandResult::LightClient(LightClientResult::SubmitMisbehaviour(res)) => Ok(res),
+            _ => unreachable!(),
+        }
+    }
+
+    /// prune_consensus_states deletes the expired consensus states of the ELC instance corresponding to client_id
+    fn prune_consensus_states(
+        &self,
+        input: PruneConsensusStatesInput,
+    ) -> Result<PruneConsensusStatesResult> {
+        let update_key = Some(input.client_id.to_string());
+        match self.execute_command(
+            Command::LightClient(LightClientCommand::Execute(
+                LightClientExecuteCommand::PruneConsensusStates(input),
+            )),
+            update_key,
+        )? {
+            CommandResult::LightClient(LightClientResult::PruneConsensusStates(res)) => Ok(res),
+            _ => unreachable!(),
+        }
+    }
+
+    /// recover_client replaces the state of the ELC instance corresponding to subject_client_id with the state of the substitute client
+    fn recover_client(&self, input: RecoverClientInput) -> Result<RecoverClientResult> {
+        let update_key = Some(input.subject_client_id.to_string());
+        match self.execute_command(
+            Command::LightClient(LightClientCommand::Execute(
+                LightClientExecuteCommand::RecoverClient(input),
+            )),
+            update_key,
+        )? {
+            CommandResult::LightClient(LightClientResult::RecoverClient(res)) => Ok(res),
+            _ => unreachable!(),
+        }
+    }
+
+    /// upgrade_client upgrades the ELC instance corresponding to client_id with the upgraded states committed by the counterparty chain
+    fn upgrade_client(&self, input: UpgradeClientInput) -> Result<UpgradeClientResult> {
+        let update_key = Some(input.client_id.to_string());
+        match self.execute_command(
+            Command::LightClient(LightClientCommand::Execute(
+                LightClientExecuteCommand::UpgradeClient(input),
+            )),
+            update_key,
+        )? {
+            CommandResult::LightClient(LightClientResult::UpgradeClient(res)) => Ok(res),
+            _ => unreachable!(),
+        }
+    }
+
     /// verify_membership verifies the existence of the state in the upstream chain and generates the state commitment of its result
     fn verify_membership(&self, input: VerifyMembershipInput) -> Result<VerifyMembershipResult> {
         match self.execute_command(
@@ -138,6 +239,19 @@ pub trait EnclaveCommandAPI<S: CommitStore>: EnclavePrimitiveAPI<S> {
         }
     }
 
+    /// verify_state_batch verifies multiple states at the same height in the upstream chain and generates a batch state commitment of its result
+    fn verify_state_batch(&self, input: VerifyStateBatchInput) -> Result<VerifyStateBatchResult> {
+        match self.execute_command(
+            Command::LightClient(LightClientCommand::Execute(
+                LightClientExecuteCommand::VerifyStateBatch(input),
+            )),
+            None,
+        )? {
+            CommandResult::LightClient(LightClientResult::VerifyStateBatch(res)) => Ok(res),
+            _ => unreachable!(),
+        }
+    }
+
     /// query_client queries the client state and consensus state
     fn query_client(&self, input: QueryClientInput) -> Result<QueryClientResult> {
         match self.execute_command(
@@ -150,4 +264,84 @@ pub trait EnclaveCommandAPI<S: CommitStore>: EnclavePrimitiveAPI<S> {
             _ => unreachable!(),
         }
     }
+
+    /// query_consensus_state queries the consensus state at the given height
+    /// If the client has no consensus state at the height, it returns `ConsensusStateNotFound` error
+    fn query_consensus_state(&self, input: QueryConsensusStateInput) -> Result<Any> {
+        let (client_id, height) = (input.client_id.clone(), input.height);
+        match self.execute_command(
+            Command::LightClient(LightClientCommand::Query(
+                LightClientQueryCommand::QueryConsensusState(input),
+            )),
+            None,
+        )? {
+            CommandResult::LightClient(LightClientResult::QueryConsensusState(res)) => res
+                .any_consensus_state
+                .ok_or_else(|| Error::consensus_state_not_found(client_id, height)),
+            _ => unreachable!(),
+        }
+    }
+
+    /// query_consensus_state_heights queries the heights of the consensus states that the client holds
+    fn query_consensus_state_heights(
+        &self,
+        input: QueryConsensusStateHeightsInput,
+    ) -> Result<QueryConsensusStateHeightsResult> {
+        match self.execute_command(
+            Command::LightClient(LightClientCommand::Query(
+                LightClientQueryCommand::QueryConsensusStateHeights(input),
+            )),
+            None,
+        )? {
+            CommandResult::LightClient(LightClientResult::QueryConsensusStateHeights(res)) => {
+                Ok(res)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// query_clients queries the summaries of the clients
+    fn query_clients(&self, input: QueryClientsInput) -> Result<QueryClientsResult> {
+        match self.execute_command(
+            Command::LightClient(LightClientCommand::Query(
+                LightClientQueryCommand::QueryClients(input),
+            )),
+            None,
+        )? {
+            CommandResult::LightClient(LightClientResult::QueryClients(res)) => Ok(res),
+            _ => unreachable!(),
+        }
+    }
+
+    /// query_client_status queries the status of the client
+    fn query_client_status(
+        &self,
+        input: QueryClientStatusInput,
+    ) -> Result<QueryClientStatusResult> {
+        match self.execute_command(
+            Command::LightClient(LightClientCommand::Query(
+                LightClientQueryCommand::QueryClientStatus(input),
+            )),
+            None,
+        )? {
+            CommandResult::LightClient(LightClientResult::QueryClientStatus(res)) => Ok(res),
+            _ => unreachable!(),
+        }
+    }
+
+    /// query_light_clients queries the ELCs that the enclave supports
+    fn query_light_clients(
+        &self,
+        input: QueryLightClientsInput,
+    ) -> Result<QueryLightClientsResult> {
+        match self.execute_command(
+            Command::LightClient(LightClientCommand::Query(
+                LightClientQueryCommand::QueryLightClients(input),
+            )),
+            None,
+        )? {
+            CommandResult::LightClient(LightClientResult::QueryLightClients(res)) => Ok(res),
+            _ => unreachable!(),
+        }
+    }
 }
@@ -1,10 +1,18 @@
 use super::command::EnclaveCommandAPI;
 use crate::Result;
 use lcp_proto::lcp::service::elc::v1::{
-    MsgCreateClient, MsgCreateClientResponse, MsgUpdateClient, MsgUpdateClientResponse,
-    MsgVerifyMembership, MsgVerifyMembershipResponse, MsgVerifyNonMembership,
-    MsgVerifyNonMembershipResponse, QueryClientRequest, QueryClientResponse,
+    MsgCreateClient, MsgCreateClientResponse, MsgPruneConsensusStates,
+    MsgPruneConsensusStatesResponse, MsgRecoverClient, MsgRecoverClientResponse,
+    MsgSubmitMisbehaviour, MsgSubmitMisbehaviourResponse, MsgUpdateClient, MsgUpdateClientBatch,
+    MsgUpdateClientBatchResponse, MsgUpdateClientResponse, MsgUpgradeClient,
+    MsgUpgradeClientResponse, MsgVerifyMembership, MsgVerifyMembershipResponse,
+    MsgVerifyNonMembership, MsgVerifyNonMembershipResponse, MsgVerifyStateBatch,
+    MsgVerifyStateBatchResponse, QueryClientRequest, QueryClientResponse, QueryClientStatusRequest,
+    QueryClientStatusResponse, QueryClientsRequest, QueryClientsResponse,
+    QueryConsensusStateHeightsRequest, QueryConsensusStateHeightsResponse,
+    QueryConsensusStateRequest, QueryConsensusStateResponse,
 };
+use lcp_proto::lcp::service::enclave::v1::{QueryLightClientsRequest, QueryLightClientsResponse};
 use log::*;
 use store::transaction::CommitStore;
 
@@ -30,6 +38,73 @@ pub trait EnclaveProtoAPI<S: CommitStore>: EnclaveCommandAPI<S> {
         Ok(res.into())
     }
 
+    fn proto_update_client_batch(
+        &self,
+        msg: MsgUpdateClientBatch,
+    ) -> Result<MsgUpdateClientBatchResponse> {
+        let client_id = msg.client_id.clone();
+        let num_headers = msg.headers.len();
+        let res = self.update_client_batch(msg.try_into()?)?;
+        info!(
+            "update_client_batch: client_id={} num_headers={} commitment={{{}}}",
+            client_id,
+            num_headers,
+            res.0.commitment()?
+        );
+        Ok(res.into())
+    }
+
+    fn proto_submit_misbehaviour(
+        &self,
+        msg: MsgSubmitMisbehaviour,
+    ) -> Result<MsgSubmitMisbehaviourResponse> {
+        let client_id = msg.client_id.clone();
+        let res = self.submit_misbehaviour(msg.try_into()?)?;
+        info!(
+            "submit_misbehaviour: client_id={} commitment={{{}}}",
+            client_id,
+            res.0.commitment()?
+        );
+        Ok(res.into())
+    }
+
+    fn proto_prune_consensus_states(
+        &self,
+        msg: MsgPruneConsensusStates,
+    ) -> Result<MsgPruneConsensusStatesResponse> {
+        let client_id = msg.client_id.clone();
+        let res = self.prune_consensus_states(msg.try_into()?)?;
+        info!(
+            "prune_consensus_states: client_id={} pruned_heights={:?}",
+            client_id, res.pruned_heights
+        );
+        Ok(res.into())
+    }
+
+    fn proto_recover_client(&self, msg: MsgRecoverClient) -> Result<MsgRecoverClientResponse> {
+        let subject_client_id = msg.subject_client_id.clone();
+        let substitute_client_id = msg.substitute_client_id.clone();
+        let res = self.recover_client(msg.try_into()?)?;
+        info!(
+            "recover_client: subject_client_id={} substitute_client_id={} commitment={{{}}}",
+            subject_client_id,
+            substitute_client_id,
+            res.0.commitment()?
+        );
+        Ok(res.into())
+    }
+
+    fn proto_upgrade_client(&self, msg: MsgUpgradeClient) -> Result<MsgUpgradeClientResponse> {
+        let client_id = msg.client_id.clone();
+        let res = self.upgrade_client(msg.try_into()?)?;
+        info!(
+            "upgrade_client: client_id={} commitment={{{}}}",
+            client_id,
+            res.0.commitment()?
+        );
+        Ok(res.into())
+    }
+
     fn proto_verify_membership(
         &self,
         msg: MsgVerifyMembership,
@@ -58,7 +133,58 @@ pub trait EnclaveProtoAPI<S: CommitStore>: EnclaveCommandAPI<S> {
         Ok(res.into())
     }
 
+    fn proto_verify_state_batch(
+        &self,
+        msg: MsgVerifyStateBatch,
+    ) -> Result<MsgVerifyStateBatchResponse> {
+        let client_id = msg.client_id.clone();
+        let res = self.verify_state_batch(msg.try_into()?)?;
+        info!(
+            "verify_state_batch: client_id={} commitment={{{}}}",
+            client_id,
+            res.0.commitment()?
+        );
+        Ok(res.into())
+    }
+
     fn proto_query_client(&self, query: QueryClientRequest) -> Result<QueryClientResponse> {
         Ok(self.query_client(query.try_into()?)?.into())
     }
+
+    fn proto_query_consensus_state(
+        &self,
+        query: QueryConsensusStateRequest,
+    ) -> Result<QueryConsensusStateResponse> {
+        let any_consensus_state = self.query_consensus_state(query.try_into()?)?;
+        Ok(QueryConsensusStateResponse {
+            consensus_state: Some(any_consensus_state.into()),
+        })
+    }
+
+    fn proto_query_consensus_state_heights(
+        &self,
+        query: QueryConsensusStateHeightsRequest,
+    ) -> Result<QueryConsensusStateHeightsResponse> {
+        Ok(self
+            .query_consensus_state_heights(query.try_into()?)?
+            .into())
+    }
+
+    fn proto_query_clients(&self, query: QueryClientsRequest) -> Result<QueryClientsResponse> {
+        Ok(self.query_clients(query.try_into()?)?.into())
+    }
+
+    fn proto_query_client_status(
+        &self,
+        query: QueryClientStatusRequest,
+    ) -> Result<QueryClientStatusResponse> {
+        Ok(self.query_client_status(query.try_into()?)?.into())
+    }
+
+    fn proto_query_light_clients(
+        &self,
+        query: QueryLightClientsRequest,
+    ) -> Result<QueryLightClientsResponse> {
+        Ok(self.query_light_clients(query.try_into()?)?.into())
+    }
 }
@@ -1,4 +1,5 @@
 use flex_error::*;
+use lcp_types::{ClientId, Height};
 use sgx_types::sgx_status_t;
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -37,6 +38,14 @@ define_error! {
             format_args!("Command error: status={:?} descr={}", e.status, e.descr)
         },
 
+        ConsensusStateNotFound {
+            client_id: ClientId,
+            height: Height
+        }
+        |e| {
+            format_args!("consensus_state not found: client_id={} height={}", e.client_id, e.height)
+        },
+
         EcallCommand
         [ecall_commands::InputValidationError]
         |_| { "ECallCommand input validation error" },
@@ -1,6 +1,7 @@
 pub use api::{EnclaveCommandAPI, EnclavePrimitiveAPI, EnclaveProtoAPI};
 pub use enclave::{Enclave, EnclaveInfo};
-use errors::{Error, Result};
+use errors::Result;
+pub use errors::{Error, ErrorDetail};
 #[cfg(feature = "sgx-sw")]
 pub use rsa;
 #[cfg(feature = "sgx-sw")]
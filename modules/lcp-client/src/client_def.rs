@@ -1,18 +1,23 @@
-use crate::client_state::ClientState;
+use crate::client_state::{ClientState, UpgradeOptions};
 use crate::consensus_state::ConsensusState;
 use crate::errors::Error;
 use crate::message::{
-    ClientMessage, CommitmentReader, RegisterEnclaveKeyMessage, UpdateClientMessage,
+    hex_encode, recover_client_sign_bytes, revoke_enclave_key_sign_bytes,
+    upgrade_client_sign_bytes, ClientMessage, CommitmentReader, ELCMisbehaviourMessage,
+    MisbehaviourMessage, RegisterEnclaveKeyMessage, RevokeEnclaveKeyMessage, UpdateClientMessage,
 };
 use attestation_report::EndorsedAttestationVerificationReport;
 use crypto::{verify_signature_address, Address, Keccak256};
 use light_client::commitments::{
-    CommitmentPrefix, CommitmentProof, EthABIEncoder, StateCommitment,
+    BatchStateCommitment, CommitmentPrefix, CommitmentProof, EthABIEncoder,
+    RecoverClientCommitment, StateCommitment, StateID,
 };
 use light_client::types::{ClientId, Height, Time};
-use light_client::{ClientKeeper, ClientReader, HostClientKeeper, HostClientReader};
+use light_client::{ClientKeeper, ClientReader, HostClientKeeper, HostClientReader, HostContext};
 
 pub const LCP_CLIENT_TYPE: &str = "0000-lcp";
+/// QUOTE_STATUS_OK is the quote status that is always allowed
+pub const QUOTE_STATUS_OK: &str = "OK";
 
 /// LCPClient is a PoC implementation of LCP Client
 /// This is aimed to testing purposes only for now
@@ -30,13 +35,25 @@ impl LCPClient {
         consensus_state: ConsensusState,
     ) -> Result<(), Error> {
         // key_expiration must not be 0
-        assert!(!client_state.key_expiration.is_zero());
+        if client_state.key_expiration.is_zero() {
+            return Err(Error::invalid_key_expiration());
+        }
         // An initial client state's latest height must be empty
-        assert!(client_state.latest_height.is_zero());
+        if !client_state.latest_height.is_zero() {
+            return Err(Error::invalid_initial_latest_height(
+                client_state.latest_height,
+            ));
+        }
         // mr_enclave length must be 32
-        assert!(client_state.mr_enclave.len() == 32);
+        if client_state.mr_enclave.len() != 32 {
+            return Err(Error::invalid_mrenclave_length(
+                client_state.mr_enclave.len(),
+            ));
+        }
         // An initial consensus state must be empty
-        assert!(consensus_state.is_empty());
+        if !consensus_state.is_empty() {
+            return Err(Error::invalid_initial_consensus_state());
+        }
 
         ctx.store_any_client_state(client_id.clone(), client_state.clone().into())?;
         ctx.store_any_consensus_state(
@@ -54,7 +71,10 @@ impl LCPClient {
         client_id: ClientId,
         message: ClientMessage,
     ) -> Result<(), Error> {
-        let client_state = ctx.client_state(&client_id)?.try_into()?;
+        let client_state: ClientState = ctx.client_state(&client_id)?.try_into()?;
+        if client_state.frozen {
+            return Err(Error::client_frozen(client_id));
+        }
         match message {
             ClientMessage::UpdateClient(header) => {
                 self.update_client(ctx, client_id, client_state, header)
@@ -62,6 +82,15 @@ impl LCPClient {
             ClientMessage::RegisterEnclaveKey(header) => {
                 self.register_enclave_key(ctx, client_id, client_state, header)
             }
+            ClientMessage::Misbehaviour(header) => {
+                self.submit_misbehaviour(ctx, client_id, client_state, header)
+            }
+            ClientMessage::ELCMisbehaviour(header) => {
+                self.submit_elc_misbehaviour(ctx, client_id, client_state, header)
+            }
+            ClientMessage::RevokeEnclaveKey(header) => {
+                self.revoke_enclave_key(ctx, client_id, client_state, header)
+            }
         }
     }
 
@@ -72,28 +101,20 @@ impl LCPClient {
         client_state: ClientState,
         message: UpdateClientMessage,
     ) -> Result<(), Error> {
-        // TODO return an error instead of assertion
-
         if client_state.latest_height.is_zero() {
             // if the client state's latest height is zero, the commitment's new_state must be non-nil
-            assert!(message.commitment.new_state.is_some());
+            if message.commitment.new_state.is_none() {
+                return Err(Error::missing_new_state());
+            }
         } else {
             // if the client state's latest height is non-zero, the commitment's prev_* must be non-nil
-            assert!(message.prev_height().is_some() && message.prev_state_id().is_some());
-            // check if the previous consensus state exists in the store
-            let prev_consensus_state: ConsensusState = ctx
-                .consensus_state(&client_id, &message.prev_height().unwrap())?
-                .try_into()?;
-            assert!(prev_consensus_state.state_id == message.prev_state_id().unwrap());
+            // and match the stored consensus state
+            self.ensure_prev_state(ctx, &client_id, &message)?;
         }
 
         // check if the specified signer exists in the client state
-        assert!(self.contains_enclave_key(ctx, &client_id, message.signer()));
-
-        // check if the `header.signer` matches the commitment prover
-        let signer =
-            verify_signature_address(&message.commitment_bytes, &message.signature).unwrap();
-        assert!(message.signer() == signer);
+        // and the `header.signer` matches the commitment prover
+        self.ensure_update_client_message_signer(ctx, &client_id, &client_state, &message)?;
 
         // check if proxy's validation context matches our's context
         message.context().validate(ctx.host_timestamp())?;
@@ -117,20 +138,256 @@ impl LCPClient {
         client_state: ClientState,
         message: RegisterEnclaveKeyMessage,
     ) -> Result<(), Error> {
-        // TODO return an error instead of assertion
-
         let eavr = message.0;
         let (key, attestation_time) = verify_report(ctx.host_timestamp(), &client_state, &eavr)?;
+        if self.is_revoked_enclave_key(ctx, &client_id, key) {
+            return Err(Error::revoked_enclave_key(key));
+        }
 
         self.add_enclave_key(
             ctx,
             &client_id,
+            &client_state,
             key,
             (attestation_time + client_state.key_expiration)?.as_unix_timestamp_secs(),
         );
         Ok(())
     }
 
+    /// revoke_enclave_key removes the enclave key and prevents it from being registered again
+    ///
+    /// The message must be signed by an active enclave key or one of the authorities in the client state.
+    /// The client's `revocation_sequence` is incremented so that the signature cannot be replayed.
+    fn revoke_enclave_key(
+        &self,
+        ctx: &mut dyn HostClientKeeper,
+        client_id: ClientId,
+        client_state: ClientState,
+        message: RevokeEnclaveKeyMessage,
+    ) -> Result<(), Error> {
+        let signer = verify_signature_address(
+            &revoke_enclave_key_sign_bytes(
+                &client_id,
+                client_state.revocation_sequence,
+                message.key,
+            ),
+            &message.signature,
+        )?;
+        if message.signer != signer {
+            return Err(Error::signer_mismatch(message.signer, signer));
+        }
+        if !client_state.authorities.contains(&signer) {
+            self.ensure_active_enclave_key(ctx, &client_id, &client_state, signer)?;
+        }
+
+        let revoked_at = ctx.host_timestamp().as_unix_timestamp_secs();
+        ctx.remove(enclave_key_path(&client_id, &client_state.mr_enclave, message.key).as_slice());
+        ctx.remove(legacy_enclave_key_path(&client_id, message.key).as_slice());
+        ctx.set(
+            revoked_enclave_key_path(&client_id, message.key),
+            revoked_at.to_be_bytes().to_vec(),
+        );
+        let revocation_sequence = client_state.revocation_sequence + 1;
+        ctx.store_any_client_state(
+            client_id,
+            ClientState {
+                revocation_sequence,
+                ..client_state
+            }
+            .into(),
+        )?;
+        Ok(())
+    }
+
+    /// submit_misbehaviour freezes the client if the given misbehaviour is valid
+    ///
+    /// A misbehaviour is valid if the two commitments are signed by the registered enclave keys
+    /// and they have the same new height, but different new states.
+    /// Each commitment must be derived from a previous state stored in the client, which may differ from the other's.
+    fn submit_misbehaviour(
+        &self,
+        ctx: &mut dyn HostClientKeeper,
+        client_id: ClientId,
+        client_state: ClientState,
+        message: MisbehaviourMessage,
+    ) -> Result<(), Error> {
+        let (c1, c2) = (message.update_1.commitment(), message.update_2.commitment());
+        if c1.new_height != c2.new_height {
+            return Err(Error::invalid_misbehaviour(format!(
+                "new_height mismatch: update_1={} update_2={}",
+                c1.new_height, c2.new_height
+            )));
+        }
+        if c1.new_state_id == c2.new_state_id {
+            return Err(Error::invalid_misbehaviour(format!(
+                "new_state_id must be different: state_id={}",
+                c1.new_state_id
+            )));
+        }
+
+        for update in [&message.update_1, &message.update_2] {
+            self.ensure_prev_state(ctx, &client_id, update)?;
+            self.ensure_update_client_message_signer(ctx, &client_id, &client_state, update)?;
+        }
+
+        ctx.store_any_client_state(client_id, client_state.freeze().into())?;
+        Ok(())
+    }
+
+    /// submit_elc_misbehaviour freezes the client if the ELC in the enclave detected a misbehaviour
+    ///
+    /// The commitment must be signed by an active enclave key,
+    /// and every previous state that the misbehaviour was verified against must be stored in the client.
+    /// The commitment's `client_id` is the ELC's one, so it is not compared with the client's.
+    fn submit_elc_misbehaviour(
+        &self,
+        ctx: &mut dyn HostClientKeeper,
+        client_id: ClientId,
+        client_state: ClientState,
+        message: ELCMisbehaviourMessage,
+    ) -> Result<(), Error> {
+        let commitment = message.commitment;
+        if commitment.prev_states.is_empty() {
+            return Err(Error::invalid_misbehaviour(
+                "prev_states must not be empty".into(),
+            ));
+        }
+        // check if the misbehaviour is verified against the states stored in the client
+        for prev_state in commitment.prev_states.iter() {
+            self.ensure_state_id(ctx, &client_id, &prev_state.height, prev_state.state_id)?;
+        }
+
+        // check if the `message.signer` matches the commitment prover
+        // and the signer is not expired and exists in the client state
+        self.ensure_commitment_prover(
+            ctx,
+            &client_id,
+            &client_state,
+            &CommitmentProof::new(message.commitment_bytes, message.signer, message.signature),
+        )?;
+
+        // check if proxy's validation context matches our's context
+        commitment.context.validate(ctx.host_timestamp())?;
+
+        ctx.store_any_client_state(client_id, client_state.freeze().into())?;
+        Ok(())
+    }
+
+    /// upgrade_client upgrades the client state with the given options
+    ///
+    /// This rotates `mr_enclave` and optionally `key_expiration` while keeping the consensus states.
+    /// Enclave keys registered for the previous `mr_enclave` are no longer available after the upgrade.
+    /// The options must be signed over `upgrade_client_sign_bytes` by one of the authorities in the client state.
+    pub fn upgrade_client(
+        &self,
+        ctx: &mut dyn HostClientKeeper,
+        client_id: ClientId,
+        options: UpgradeOptions,
+        signer: Address,
+        signature: Vec<u8>,
+    ) -> Result<(), Error> {
+        let client_state: ClientState = ctx.client_state(&client_id)?.try_into()?;
+        if client_state.frozen {
+            return Err(Error::client_frozen(client_id));
+        }
+
+        // check if the upgrade is signed by an authority
+        let actual_signer = verify_signature_address(
+            &upgrade_client_sign_bytes(
+                &client_id,
+                &client_state.mr_enclave,
+                client_state.upgrade_sequence,
+                &options,
+            ),
+            &signature,
+        )?;
+        if signer != actual_signer {
+            return Err(Error::signer_mismatch(signer, actual_signer));
+        }
+        if !client_state.authorities.contains(&signer) {
+            return Err(Error::not_authority(signer));
+        }
+
+        // mr_enclave length must be 32
+        if options.mr_enclave.len() != 32 {
+            return Err(Error::invalid_mrenclave_length(options.mr_enclave.len()));
+        }
+        // key_expiration must not be 0
+        if options.key_expiration.map_or(false, |d| d.is_zero()) {
+            return Err(Error::invalid_key_expiration());
+        }
+
+        let upgraded_at = ctx.host_timestamp().as_unix_timestamp_secs();
+        ctx.set(
+            upgraded_path(&client_id),
+            upgraded_at.to_be_bytes().to_vec(),
+        );
+        ctx.store_any_client_state(client_id, client_state.upgrade(options).into())?;
+        Ok(())
+    }
+
+    /// recover_client replaces the latest state of the client with the state of a recovered ELC
+    ///
+    /// `proof` is a `CommitmentProof` of a `RecoverClientCommitment` signed by an active enclave key.
+    /// Because the recovery is not verified by a header, the commitment must also be approved by one of the authorities
+    /// in the client state with a signature over `recover_client_sign_bytes`.
+    /// The recovery also unfreezes the client.
+    pub fn recover_client(
+        &self,
+        ctx: &mut dyn HostClientKeeper,
+        client_id: ClientId,
+        proof: Vec<u8>,
+        signer: Address,
+        signature: Vec<u8>,
+    ) -> Result<(), Error> {
+        let client_state: ClientState = ctx.client_state(&client_id)?.try_into()?;
+
+        // convert `proof` to CommitmentProof
+        let commitment_proof = CommitmentProof::ethabi_decode(proof.as_slice())?;
+        let commitment: RecoverClientCommitment = commitment_proof.commitment()?.try_into()?;
+
+        // check if the recovery is approved by an authority
+        let actual_signer = verify_signature_address(
+            &recover_client_sign_bytes(&client_id, &commitment_proof.commitment_bytes),
+            &signature,
+        )?;
+        if signer != actual_signer {
+            return Err(Error::signer_mismatch(signer, actual_signer));
+        }
+        if !client_state.authorities.contains(&signer) {
+            return Err(Error::not_authority(signer));
+        }
+
+        // check if the recovery is derived from the state stored in the client
+        self.ensure_state_id(
+            ctx,
+            &client_id,
+            &commitment.prev_height,
+            commitment.prev_state_id,
+        )?;
+
+        // check if the `commitment_proof.signer` matches the commitment prover
+        // and the signer is not expired and exists in the client state
+        self.ensure_commitment_prover(ctx, &client_id, &client_state, &commitment_proof)?;
+
+        let mut new_client_state = client_state;
+        new_client_state.frozen = false;
+        if new_client_state.latest_height < commitment.new_height {
+            new_client_state.latest_height = commitment.new_height;
+        }
+        let new_consensus_state = ConsensusState {
+            state_id: commitment.new_state_id,
+            timestamp: commitment.timestamp,
+        };
+        ctx.store_any_client_state(client_id.clone(), new_client_state.into())?;
+        ctx.store_any_consensus_state(
+            client_id,
+            commitment.new_height,
+            new_consensus_state.into(),
+        )?;
+        Ok(())
+    }
+
     /// verify_membership is a generic proof verification method which verifies a proof of the existence of a value at a given path at the specified height.
     pub fn verify_membership(
         &self,
@@ -142,38 +399,143 @@ impl LCPClient {
         proof_height: Height,
         proof: Vec<u8>,
     ) -> Result<(), Error> {
-        // TODO return an error instead of assertion
+        let client_state = ClientState::try_from(ctx.client_state(&client_id)?)?;
+        if client_state.frozen {
+            return Err(Error::client_frozen(client_id));
+        }
 
         // convert `proof` to CommitmentProof
-        let commitment_proof = CommitmentProof::ethabi_decode(proof.as_slice()).unwrap();
+        let commitment_proof = CommitmentProof::ethabi_decode(proof.as_slice())?;
         let commitment: StateCommitment = commitment_proof.commitment()?.try_into()?;
 
         // check if `.prefix` matches the counterparty connection's prefix
-        assert!(commitment.prefix == prefix);
+        if commitment.prefix != prefix {
+            return Err(Error::prefix_mismatch(prefix, commitment.prefix));
+        }
         // check if `.path` matches expected the commitment path
-        assert!(commitment.path == path);
+        if commitment.path != path {
+            return Err(Error::path_mismatch(path, commitment.path));
+        }
         // check if `.height` matches proof height
-        assert!(commitment.height == proof_height);
+        if commitment.height != proof_height {
+            return Err(Error::height_mismatch(proof_height, commitment.height));
+        }
 
         // check if `.value` matches expected state
-        assert!(commitment.value == Some(value.keccak256()));
+        let value = Some(value.keccak256());
+        if commitment.value != value {
+            return Err(Error::value_mismatch(value, commitment.value));
+        }
 
         // check if `.state_id` matches the corresponding stored consensus state's state_id
-        let consensus_state =
-            ConsensusState::try_from(ctx.consensus_state(&client_id, &proof_height)?)?;
-        assert!(consensus_state.state_id == commitment.state_id);
+        self.ensure_state_id(ctx, &client_id, &proof_height, commitment.state_id)?;
 
         // check if the `commitment_proof.signer` matches the commitment prover
-        let signer = verify_signature_address(
-            &commitment_proof.commitment_bytes,
-            &commitment_proof.signature,
-        )?;
-        assert!(commitment_proof.signer == signer);
+        // and the signer is not expired and exists in the client state
+        self.ensure_commitment_prover(ctx, &client_id, &client_state, &commitment_proof)
+    }
 
-        // check if the specified signer is not expired and exists in the client state
-        assert!(self.is_active_enclave_key(ctx, &client_id, signer));
+    /// verify_non_membership is a generic proof verification method which verifies the absence of a given commitment.
+    pub fn verify_non_membership(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: ClientId,
+        prefix: CommitmentPrefix,
+        path: String,
+        proof_height: Height,
+        proof: Vec<u8>,
+    ) -> Result<(), Error> {
+        let client_state = ClientState::try_from(ctx.client_state(&client_id)?)?;
+        if client_state.frozen {
+            return Err(Error::client_frozen(client_id));
+        }
 
-        Ok(())
+        // convert `proof` to CommitmentProof
+        let commitment_proof = CommitmentProof::ethabi_decode(proof.as_slice())?;
+        let commitment: StateCommitment = commitment_proof.commitment()?.try_into()?;
+
+        // check if `.prefix` matches the counterparty connection's prefix
+        if commitment.prefix != prefix {
+            return Err(Error::prefix_mismatch(prefix, commitment.prefix));
+        }
+        // check if `.path` matches expected the commitment path
+        if commitment.path != path {
+            return Err(Error::path_mismatch(path, commitment.path));
+        }
+        // check if `.height` matches proof height
+        if commitment.height != proof_height {
+            return Err(Error::height_mismatch(proof_height, commitment.height));
+        }
+
+        // check if `.value` is empty
+        if commitment.value.is_some() {
+            return Err(Error::value_mismatch(None, commitment.value));
+        }
+
+        // check if `.state_id` matches the corresponding stored consensus state's state_id
+        self.ensure_state_id(ctx, &client_id, &proof_height, commitment.state_id)?;
+
+        // check if the `commitment_proof.signer` matches the commitment prover
+        // and the signer is not expired and exists in the client state
+        self.ensure_commitment_prover(ctx, &client_id, &client_state, &commitment_proof)
+    }
+
+    /// verify_batch_state verifies a proof of the existence or non-existence of multiple states
+    ///
+    /// Each element of `states` is a tuple of (prefix, path, value), and all of them are proven at `proof_height`.
+    /// If the value is None, the element indicates the non-existence of the state at the path.
+    pub fn verify_batch_state(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: ClientId,
+        states: Vec<(CommitmentPrefix, String, Option<Vec<u8>>)>,
+        proof_height: Height,
+        proof: Vec<u8>,
+    ) -> Result<(), Error> {
+        let client_state = ClientState::try_from(ctx.client_state(&client_id)?)?;
+        if client_state.frozen {
+            return Err(Error::client_frozen(client_id));
+        }
+
+        // convert `proof` to CommitmentProof
+        let commitment_proof = CommitmentProof::ethabi_decode(proof.as_slice())?;
+        let commitment: BatchStateCommitment = commitment_proof.commitment()?.try_into()?;
+
+        // check if the number of entries matches the number of states
+        if commitment.entries.len() != states.len() {
+            return Err(Error::entries_length_mismatch(
+                states.len(),
+                commitment.entries.len(),
+            ));
+        }
+
+        // check if `.height` matches proof height
+        if commitment.height != proof_height {
+            return Err(Error::height_mismatch(proof_height, commitment.height));
+        }
+
+        for (entry, (prefix, path, value)) in commitment.entries.iter().zip(states.into_iter()) {
+            // check if `.prefix` matches the counterparty connection's prefix
+            if entry.prefix != prefix {
+                return Err(Error::prefix_mismatch(prefix, entry.prefix.clone()));
+            }
+            // check if `.path` matches expected the commitment path
+            if entry.path != path {
+                return Err(Error::path_mismatch(path, entry.path.clone()));
+            }
+            // check if `.value` matches expected state
+            let value = value.map(|v| v.keccak256());
+            if entry.value != value {
+                return Err(Error::value_mismatch(value, entry.value));
+            }
+        }
+
+        // check if `.state_id` matches the corresponding stored consensus state's state_id
+        self.ensure_state_id(ctx, &client_id, &proof_height, commitment.state_id)?;
+
+        // check if the `commitment_proof.signer` matches the commitment prover
+        // and the signer is not expired and exists in the client state
+        self.ensure_commitment_prover(ctx, &client_id, &client_state, &commitment_proof)
     }
 
     pub fn client_type(&self) -> String {
@@ -184,34 +546,162 @@ impl LCPClient {
         &self,
         ctx: &T,
         client_id: &ClientId,
+        client_state: &ClientState,
         key: Address,
     ) -> bool {
-        ctx.get(enclave_key_path(client_id, key).as_slice())
+        self.get_enclave_key_expiration(ctx, client_id, client_state, key)
             .is_some()
     }
 
-    fn is_active_enclave_key<T: HostClientReader + ?Sized>(
+    /// get_enclave_key_expiration returns the encoded expiration of the key bound to the client's `mr_enclave`
+    ///
+    /// Keys registered under the legacy path are bound to the initial `mr_enclave`,
+    /// so they are available until the client is upgraded.
+    fn get_enclave_key_expiration<T: ClientReader + ?Sized>(
+        &self,
+        ctx: &T,
+        client_id: &ClientId,
+        client_state: &ClientState,
+        key: Address,
+    ) -> Option<Vec<u8>> {
+        ctx.get(enclave_key_path(client_id, &client_state.mr_enclave, key).as_slice())
+            .or_else(|| match ctx.get(upgraded_path(client_id).as_slice()) {
+                Some(_) => None,
+                None => ctx.get(legacy_enclave_key_path(client_id, key).as_slice()),
+            })
+    }
+
+    fn is_revoked_enclave_key<T: ClientReader + ?Sized>(
         &self,
         ctx: &T,
         client_id: &ClientId,
         key: Address,
     ) -> bool {
-        let expired_at = match ctx.get(enclave_key_path(client_id, key).as_slice()) {
-            Some(bz) => u64::from_be_bytes(bz.as_slice().try_into().unwrap()),
-            None => return false,
+        ctx.get(revoked_enclave_key_path(client_id, key).as_slice())
+            .is_some()
+    }
+
+    /// ensure_active_enclave_key returns an error if the key is not registered, revoked or expired
+    fn ensure_active_enclave_key<T: HostContext + ClientReader + ?Sized>(
+        &self,
+        ctx: &T,
+        client_id: &ClientId,
+        client_state: &ClientState,
+        key: Address,
+    ) -> Result<(), Error> {
+        if self.is_revoked_enclave_key(ctx, client_id, key) {
+            return Err(Error::revoked_enclave_key(key));
+        }
+        let expired_at = match self.get_enclave_key_expiration(ctx, client_id, client_state, key) {
+            Some(bz) => u64::from_be_bytes(
+                bz.as_slice()
+                    .try_into()
+                    .map_err(|_| Error::invalid_enclave_key_expiration(key, bz.clone()))?,
+            ),
+            None => return Err(Error::enclave_key_not_found(key)),
+        };
+        let current_timestamp = ctx.host_timestamp();
+        if current_timestamp.as_unix_timestamp_secs() < expired_at {
+            Ok(())
+        } else {
+            Err(Error::expired_enclave_key(
+                key,
+                expired_at,
+                current_timestamp,
+            ))
+        }
+    }
+
+    /// ensure_update_client_message_signer returns an error if the message's signer is not registered or is not the actual prover
+    fn ensure_update_client_message_signer<T: ClientReader + ?Sized>(
+        &self,
+        ctx: &T,
+        client_id: &ClientId,
+        client_state: &ClientState,
+        message: &UpdateClientMessage,
+    ) -> Result<(), Error> {
+        // check if the specified signer exists in the client state
+        if !self.contains_enclave_key(ctx, client_id, client_state, message.signer()) {
+            return Err(Error::enclave_key_not_found(message.signer()));
+        }
+
+        // check if the `header.signer` matches the commitment prover
+        let signer = verify_signature_address(&message.commitment_bytes, &message.signature)?;
+        if message.signer() != signer {
+            return Err(Error::signer_mismatch(message.signer(), signer));
+        }
+        Ok(())
+    }
+
+    /// ensure_prev_state returns an error if the message's previous state doesn't match the stored consensus state
+    fn ensure_prev_state<T: ClientReader + ?Sized>(
+        &self,
+        ctx: &T,
+        client_id: &ClientId,
+        message: &UpdateClientMessage,
+    ) -> Result<(), Error> {
+        let (prev_height, prev_state_id) = match (message.prev_height(), message.prev_state_id()) {
+            (Some(prev_height), Some(prev_state_id)) => (prev_height, prev_state_id),
+            _ => return Err(Error::missing_prev_state()),
         };
-        ctx.host_timestamp().as_unix_timestamp_secs() < expired_at
+        // check if the previous consensus state exists in the store
+        let prev_consensus_state: ConsensusState =
+            ctx.consensus_state(client_id, &prev_height)?.try_into()?;
+        if prev_consensus_state.state_id != prev_state_id {
+            return Err(Error::state_id_mismatch(
+                prev_consensus_state.state_id,
+                prev_state_id,
+            ));
+        }
+        Ok(())
+    }
+
+    /// ensure_state_id returns an error if the state_id doesn't match the consensus state's one at the given height
+    fn ensure_state_id<T: ClientReader + ?Sized>(
+        &self,
+        ctx: &T,
+        client_id: &ClientId,
+        height: &Height,
+        state_id: StateID,
+    ) -> Result<(), Error> {
+        let consensus_state = ConsensusState::try_from(ctx.consensus_state(client_id, height)?)?;
+        if consensus_state.state_id != state_id {
+            return Err(Error::state_id_mismatch(consensus_state.state_id, state_id));
+        }
+        Ok(())
+    }
+
+    /// ensure_commitment_prover returns an error if the proof's signer is not the actual prover or is not an active enclave key
+    fn ensure_commitment_prover<T: HostContext + ClientReader + ?Sized>(
+        &self,
+        ctx: &T,
+        client_id: &ClientId,
+        client_state: &ClientState,
+        commitment_proof: &CommitmentProof,
+    ) -> Result<(), Error> {
+        // check if the `commitment_proof.signer` matches the commitment prover
+        let signer = verify_signature_address(
+            &commitment_proof.commitment_bytes,
+            &commitment_proof.signature,
+        )?;
+        if commitment_proof.signer != signer {
+            return Err(Error::signer_mismatch(commitment_proof.signer, signer));
+        }
+
+        // check if the specified signer is not expired and exists in the client state
+        self.ensure_active_enclave_key(ctx, client_id, client_state, signer)
     }
 
     fn add_enclave_key<T: ClientKeeper + ?Sized>(
         &self,
         ctx: &mut T,
         client_id: &ClientId,
+        client_state: &ClientState,
         key: Address,
         expired_at: u64,
     ) {
         ctx.set(
-            enclave_key_path(client_id, key),
+            enclave_key_path(client_id, &client_state.mr_enclave, key),
             expired_at.to_be_bytes().to_vec(),
         );
     }
@@ -230,7 +720,25 @@ fn verify_report(
     #[cfg(not(test))]
     attestation_report::verify_report(eavr, current_timestamp)?;
 
-    let quote = eavr.get_avr()?.parse_quote()?;
+    let avr = eavr.get_avr()?;
+    let quote = avr.parse_quote()?;
+
+    // check if the quote status is "OK" or allowed by the client state
+    if quote.status != QUOTE_STATUS_OK
+        && !client_state.allowed_quote_statuses.contains(&quote.status)
+    {
+        return Err(Error::disallowed_quote_status(quote.status));
+    }
+
+    // check if all advisory IDs in the report are allowed by the client state
+    let disallowed_advisory_ids: Vec<String> = avr
+        .advisory_ids
+        .into_iter()
+        .filter(|id| !client_state.allowed_advisory_ids.contains(id))
+        .collect();
+    if !disallowed_advisory_ids.is_empty() {
+        return Err(Error::disallowed_advisory_ids(disallowed_advisory_ids));
+    }
 
     // check if attestation report's timestamp is not expired
     let key_expiration = (quote.attestation_time + client_state.key_expiration)?;
@@ -253,15 +761,46 @@ fn verify_report(
     Ok((quote.get_enclave_key_address()?, quote.attestation_time))
 }
 
-fn enclave_key_path(client_id: &ClientId, key: Address) -> Vec<u8> {
+// enclave_key_path returns a path to the key's expiration
+// NOTE: the path includes `mr_enclave` so that keys bound to other measurements are not available
+fn enclave_key_path(client_id: &ClientId, mr_enclave: &[u8], key: Address) -> Vec<u8> {
+    format!(
+        "clients/{}/aux/enclave_keys/{}/{}",
+        client_id,
+        hex_encode(mr_enclave),
+        key
+    )
+    .as_bytes()
+    .to_vec()
+}
+
+// legacy_enclave_key_path returns a path to the key's expiration registered before the path included `mr_enclave`
+fn legacy_enclave_key_path(client_id: &ClientId, key: Address) -> Vec<u8> {
     format!("clients/{}/aux/enclave_keys/{}", client_id, key)
         .as_bytes()
         .to_vec()
 }
 
+// upgraded_path returns a path to the time when the client was last upgraded
+fn upgraded_path(client_id: &ClientId) -> Vec<u8> {
+    format!("clients/{}/aux/upgraded", client_id)
+        .as_bytes()
+        .to_vec()
+}
+
+// revoked_enclave_key_path returns a path to the key's revocation time
+// NOTE: the path doesn't include `mr_enclave` so that the revocation survives upgrades
+fn revoked_enclave_key_path(client_id: &ClientId, key: Address) -> Vec<u8> {
+    format!("clients/{}/aux/revoked_enclave_keys/{}", client_id, key)
+        .as_bytes()
+        .to_vec()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client_state::UpgradeOptions;
+    use crate::errors::ErrorDetail;
     use alloc::rc::Rc;
     use alloc::sync::Arc;
     use attestation_report::AttestationVerificationReport;
@@ -269,18 +808,29 @@ mod tests {
     use core::cell::RefCell;
     use core::str::FromStr;
     use core::time::Duration;
-    use crypto::{EnclaveKey, EnclavePublicKey};
+    use crypto::{EnclaveKey, EnclavePublicKey, Signer};
     use ibc::{
         mock::{
-            client_state::MockClientState, consensus_state::MockConsensusState, header::MockHeader,
+            client_state::MockClientState,
+            consensus_state::MockConsensusState,
+            header::MockHeader,
+            misbehaviour::{Misbehaviour as MockMisbehaviour, MOCK_MISBEHAVIOUR_TYPE_URL},
         },
+        timestamp::Timestamp,
         Height as ICS02Height,
     };
-    use light_client::commitments::prove_commitment;
+    use light_client::commitments::{
+        prove_commitment, CommitmentContext, MisbehaviourCommitment, PrevState,
+        StateCommitmentEntry, UpdateClientCommitment,
+    };
+    use light_client::types::proto::ibc::lightclients::lcp::v1::UpdateClientMessage as RawUpdateClientMessage;
+    use light_client::types::proto::protobuf::Protobuf;
+    use light_client::types::Any;
     use light_client::{LightClient, LightClientResolver, MapLightClientRegistry};
     use mock_lc::MockLightClient;
-    use sgx_types::{sgx_quote_t, sgx_report_body_t};
+    use sgx_types::{sgx_measurement_t, sgx_quote_t, sgx_report_body_t};
     use store::memory::MemStore;
+    use store::KVStore;
 
     #[test]
     fn test_client() {
@@ -302,6 +852,12 @@ mod tests {
                 latest_height: Height::zero(),
                 mr_enclave: [0u8; 32].to_vec(),
                 key_expiration: Duration::from_secs(60 * 60 * 24 * 7),
+                allowed_quote_statuses: vec![],
+                allowed_advisory_ids: vec![],
+                frozen: false,
+                authorities: vec![],
+                upgrade_sequence: 0,
+                revocation_sequence: 0,
             };
             let initial_consensus_state = ConsensusState {
                 state_id: Default::default(),
@@ -399,6 +955,9 @@ mod tests {
             res.unwrap()
         };
 
+        let update_commitment: UpdateClientCommitment =
+            proof1.commitment().unwrap().try_into().unwrap();
+
         // 5. on the downstream side, updates LCP Light Client's state with the commitment from the LCP
         {
             let header = ClientMessage::UpdateClient(UpdateClientMessage {
@@ -407,12 +966,2105 @@ mod tests {
                 signer: proof1.signer,
                 signature: proof1.signature,
             });
+            let mut ctx = Context::new(registry.clone(), ibc_store.clone(), &ek);
+            ctx.set_timestamp((Time::now() + Duration::from_secs(60)).unwrap());
+
+            let res = lcp_client.update_state(&mut ctx, lcp_client_id.clone(), header);
+            assert!(res.is_ok(), "res={:?}", res);
+        }
+
+        // 6. on the downstream side, verifies multiple states with a batch state commitment
+        {
+            let states = vec![
+                (
+                    b"ibc".to_vec(),
+                    "connections/connection-0".to_string(),
+                    Some(b"connection".to_vec()),
+                ),
+                (
+                    b"ibc".to_vec(),
+                    "channelEnds/ports/transfer/channels/channel-0".to_string(),
+                    None,
+                ),
+            ];
+            let commitment = BatchStateCommitment::new(
+                states
+                    .iter()
+                    .map(|(prefix, path, value)| {
+                        StateCommitmentEntry::new(
+                            prefix.clone(),
+                            path.clone(),
+                            value.as_ref().map(|v| v.keccak256()),
+                        )
+                    })
+                    .collect(),
+                update_commitment.new_height,
+                update_commitment.new_state_id,
+            );
+            let proof =
+                prove_commitment(&ek, ek.get_pubkey().as_address(), commitment.into()).unwrap();
+
             let mut ctx = Context::new(registry.clone(), ibc_store, &ek);
             ctx.set_timestamp((Time::now() + Duration::from_secs(60)).unwrap());
 
-            let res = lcp_client.update_state(&mut ctx, lcp_client_id, header);
-            assert!(res.is_ok(), "res={:?}", res);
-        }
+            let res = lcp_client.verify_batch_state(
+                &ctx,
+                lcp_client_id,
+                states,
+                update_commitment.new_height,
+                proof.ethabi_encode(),
+            );
+            assert!(res.is_ok(), "res={:?}", res);
+        }
+    }
+
+    #[test]
+    fn test_initialise_errors() {
+        let ek = EnclaveKey::new().unwrap();
+        let registry = build_lc_registry();
+        let lcp_client = LCPClient::default();
+        let client_id = ClientId::from_str(&format!("{}-0", lcp_client.client_type())).unwrap();
+        let valid_client_state = ClientState {
+            latest_height: Height::zero(),
+            mr_enclave: [0u8; 32].to_vec(),
+            key_expiration: Duration::from_secs(60 * 60 * 24 * 7),
+            allowed_quote_statuses: vec![],
+            allowed_advisory_ids: vec![],
+            frozen: false,
+            authorities: vec![],
+            upgrade_sequence: 0,
+            revocation_sequence: 0,
+        };
+        let valid_consensus_state = ConsensusState {
+            state_id: Default::default(),
+            timestamp: Time::unix_epoch(),
+        };
+
+        let cases = vec![
+            (
+                ClientState {
+                    key_expiration: Duration::ZERO,
+                    ..valid_client_state.clone()
+                },
+                valid_consensus_state.clone(),
+            ),
+            (
+                ClientState {
+                    latest_height: Height::new(0, 1),
+                    ..valid_client_state.clone()
+                },
+                valid_consensus_state.clone(),
+            ),
+            (
+                ClientState {
+                    mr_enclave: [0u8; 31].to_vec(),
+                    ..valid_client_state.clone()
+                },
+                valid_consensus_state.clone(),
+            ),
+            (
+                valid_client_state.clone(),
+                ConsensusState {
+                    state_id: [1u8; 32].into(),
+                    ..valid_consensus_state.clone()
+                },
+            ),
+        ];
+        for (i, (client_state, consensus_state)) in cases.into_iter().enumerate() {
+            let mut ctx = Context::new(
+                registry.clone(),
+                Rc::new(RefCell::new(MemStore::default())),
+                &ek,
+            );
+            ctx.set_timestamp(Time::now());
+            let res =
+                lcp_client.initialise(&mut ctx, client_id.clone(), client_state, consensus_state);
+            match (i, res.as_ref().map_err(|e| e.detail())) {
+                (0, Err(ErrorDetail::InvalidKeyExpiration(_))) => {}
+                (1, Err(ErrorDetail::InvalidInitialLatestHeight(_))) => {}
+                (2, Err(ErrorDetail::InvalidMrenclaveLength(_))) => {}
+                (3, Err(ErrorDetail::InvalidInitialConsensusState(_))) => {}
+                _ => panic!("case={} res={:?}", i, res),
+            }
+        }
+
+        let mut ctx = Context::new(registry, Rc::new(RefCell::new(MemStore::default())), &ek);
+        ctx.set_timestamp(Time::now());
+        let res = lcp_client.initialise(
+            &mut ctx,
+            client_id,
+            valid_client_state,
+            valid_consensus_state,
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+    }
+
+    #[test]
+    fn test_update_client_errors() {
+        let ek = EnclaveKey::new().unwrap();
+        let unregistered_ek = EnclaveKey::new().unwrap();
+        let ibc_store = Rc::new(RefCell::new(MemStore::default()));
+        let registry = build_lc_registry();
+        let lcp_client = LCPClient::default();
+        let client_id = setup_lcp_client(&registry, &ibc_store, &ek);
+
+        let mut ctx = Context::new(registry, ibc_store, &ek);
+        ctx.set_timestamp(Time::now());
+
+        let height = Height::new(0, 1);
+        let initial_commitment = UpdateClientCommitment {
+            prev_state_id: None,
+            new_state_id: [1u8; 32].into(),
+            new_state: Some(Any::new("/dummy.ClientState".to_string(), vec![])),
+            prev_height: None,
+            new_height: height,
+            timestamp: Time::now(),
+            context: CommitmentContext::Empty,
+        };
+
+        // the initial update must include the new state
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_update_client_message(
+                &ek,
+                ek.get_pubkey().as_address(),
+                UpdateClientCommitment {
+                    new_state: None,
+                    ..initial_commitment.clone()
+                },
+            ),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::MissingNewState(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the signer must be registered
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_update_client_message(
+                &unregistered_ek,
+                unregistered_ek.get_pubkey().as_address(),
+                initial_commitment.clone(),
+            ),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::EnclaveKeyNotFound(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the signer must be the actual prover of the commitment
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_update_client_message(
+                &unregistered_ek,
+                ek.get_pubkey().as_address(),
+                initial_commitment.clone(),
+            ),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::SignerMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_update_client_message(
+                &ek,
+                ek.get_pubkey().as_address(),
+                initial_commitment.clone(),
+            ),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+
+        let next_commitment = UpdateClientCommitment {
+            prev_state_id: Some(initial_commitment.new_state_id),
+            new_state_id: [2u8; 32].into(),
+            new_state: None,
+            prev_height: Some(height),
+            new_height: Height::new(0, 2),
+            ..initial_commitment
+        };
+
+        // the update must specify the previous state
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_update_client_message(
+                &ek,
+                ek.get_pubkey().as_address(),
+                UpdateClientCommitment {
+                    prev_state_id: None,
+                    ..next_commitment.clone()
+                },
+            ),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::MissingPrevState(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the previous state must match the stored consensus state
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_update_client_message(
+                &ek,
+                ek.get_pubkey().as_address(),
+                UpdateClientCommitment {
+                    prev_state_id: Some([3u8; 32].into()),
+                    ..next_commitment.clone()
+                },
+            ),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::StateIdMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id,
+            build_update_client_message(&ek, ek.get_pubkey().as_address(), next_commitment),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+    }
+
+    #[test]
+    fn test_verify_membership_errors() {
+        let ek = EnclaveKey::new().unwrap();
+        let unregistered_ek = EnclaveKey::new().unwrap();
+        let ibc_store = Rc::new(RefCell::new(MemStore::default()));
+        let registry = build_lc_registry();
+        let lcp_client = LCPClient::default();
+        let client_id = setup_lcp_client(&registry, &ibc_store, &ek);
+
+        let height = Height::new(0, 1);
+        let state_id = [1u8; 32].into();
+        let mut ctx = Context::new(registry, ibc_store, &ek);
+        ctx.set_timestamp(Time::now());
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_update_client_message(
+                &ek,
+                ek.get_pubkey().as_address(),
+                UpdateClientCommitment {
+                    prev_state_id: None,
+                    new_state_id: state_id,
+                    new_state: Some(Any::new("/dummy.ClientState".to_string(), vec![])),
+                    prev_height: None,
+                    new_height: height,
+                    timestamp: Time::now(),
+                    context: CommitmentContext::Empty,
+                },
+            ),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+
+        let prefix = b"ibc".to_vec();
+        let path = "connections/connection-0".to_string();
+        let value = b"connection".to_vec();
+        let commitment = StateCommitment::new(
+            prefix.clone(),
+            path.clone(),
+            Some(value.keccak256()),
+            height,
+            state_id,
+        );
+        let verify = |ctx: &dyn HostClientReader,
+                      commitment: StateCommitment,
+                      signer: &EnclaveKey,
+                      signer_address: Address| {
+            let proof = prove_commitment(signer, signer_address, commitment.into()).unwrap();
+            lcp_client.verify_membership(
+                ctx,
+                client_id.clone(),
+                prefix.clone(),
+                path.clone(),
+                value.clone(),
+                height,
+                proof.ethabi_encode(),
+            )
+        };
+        let ek_address = ek.get_pubkey().as_address();
+
+        let res = verify(&ctx, commitment.clone(), &ek, ek_address);
+        assert!(res.is_ok(), "res={:?}", res);
+
+        let res = lcp_client.verify_membership(
+            &ctx,
+            client_id.clone(),
+            prefix.clone(),
+            path.clone(),
+            value.clone(),
+            height,
+            vec![0u8; 10],
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::CommitmentProof(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        let res = verify(
+            &ctx,
+            StateCommitment {
+                prefix: b"other".to_vec(),
+                ..commitment.clone()
+            },
+            &ek,
+            ek_address,
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::PrefixMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        let res = verify(
+            &ctx,
+            StateCommitment {
+                path: "connections/connection-1".to_string(),
+                ..commitment.clone()
+            },
+            &ek,
+            ek_address,
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::PathMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        let res = verify(
+            &ctx,
+            StateCommitment {
+                height: Height::new(0, 2),
+                ..commitment.clone()
+            },
+            &ek,
+            ek_address,
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::HeightMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        let res = verify(
+            &ctx,
+            StateCommitment {
+                value: Some(b"other".to_vec().keccak256()),
+                ..commitment.clone()
+            },
+            &ek,
+            ek_address,
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::ValueMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        let res = verify(
+            &ctx,
+            StateCommitment {
+                state_id: [2u8; 32].into(),
+                ..commitment.clone()
+            },
+            &ek,
+            ek_address,
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::StateIdMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        let res = verify(&ctx, commitment.clone(), &unregistered_ek, ek_address);
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::SignerMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        let res = verify(
+            &ctx,
+            commitment.clone(),
+            &unregistered_ek,
+            unregistered_ek.get_pubkey().as_address(),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::EnclaveKeyNotFound(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the key expires after `key_expiration` from the attestation time
+        ctx.set_timestamp((Time::now() + Duration::from_secs(60 * 60 * 24 * 8)).unwrap());
+        let res = verify(&ctx, commitment, &ek, ek_address);
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::ExpiredEnclaveKey(_))
+            ),
+            "res={:?}",
+            res
+        );
+    }
+
+    #[test]
+    fn test_verify_batch_state_errors() {
+        let ek = EnclaveKey::new().unwrap();
+        let ibc_store = Rc::new(RefCell::new(MemStore::default()));
+        let registry = build_lc_registry();
+        let lcp_client = LCPClient::default();
+        let client_id = setup_lcp_client(&registry, &ibc_store, &ek);
+
+        let height = Height::new(0, 1);
+        let state_id = [1u8; 32].into();
+        let mut ctx = Context::new(registry, ibc_store, &ek);
+        ctx.set_timestamp(Time::now());
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_update_client_message(
+                &ek,
+                ek.get_pubkey().as_address(),
+                UpdateClientCommitment {
+                    prev_state_id: None,
+                    new_state_id: state_id,
+                    new_state: Some(Any::new("/dummy.ClientState".to_string(), vec![])),
+                    prev_height: None,
+                    new_height: height,
+                    timestamp: Time::now(),
+                    context: CommitmentContext::Empty,
+                },
+            ),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+
+        let states = vec![
+            (
+                b"ibc".to_vec(),
+                "connections/connection-0".to_string(),
+                Some(b"connection".to_vec()),
+            ),
+            (
+                b"ibc".to_vec(),
+                "channelEnds/ports/transfer/channels/channel-0".to_string(),
+                None,
+            ),
+        ];
+        let commitment = BatchStateCommitment::new(
+            states
+                .iter()
+                .map(|(prefix, path, value)| {
+                    StateCommitmentEntry::new(
+                        prefix.clone(),
+                        path.clone(),
+                        value.as_ref().map(|v| v.keccak256()),
+                    )
+                })
+                .collect(),
+            height,
+            state_id,
+        );
+        let verify = |states: Vec<(CommitmentPrefix, String, Option<Vec<u8>>)>,
+                      commitment: BatchStateCommitment| {
+            let proof =
+                prove_commitment(&ek, ek.get_pubkey().as_address(), commitment.into()).unwrap();
+            lcp_client.verify_batch_state(
+                &ctx,
+                client_id.clone(),
+                states,
+                height,
+                proof.ethabi_encode(),
+            )
+        };
+        let with_entry = |i: usize, f: &dyn Fn(&mut StateCommitmentEntry)| {
+            let mut commitment = commitment.clone();
+            f(&mut commitment.entries[i]);
+            commitment
+        };
+
+        let res = verify(states.clone(), commitment.clone());
+        assert!(res.is_ok(), "res={:?}", res);
+
+        let res = verify(states[..1].to_vec(), commitment.clone());
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::EntriesLengthMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // every entry shares the proof height
+        let res = verify(
+            states.clone(),
+            BatchStateCommitment {
+                height: Height::new(0, 2),
+                ..commitment.clone()
+            },
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::HeightMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // a mismatch of any entry fails the whole verification
+        for i in 0..states.len() {
+            let res = verify(
+                states.clone(),
+                with_entry(i, &|entry| entry.prefix = b"other".to_vec()),
+            );
+            assert!(
+                matches!(
+                    res.as_ref().map_err(|e| e.detail()),
+                    Err(ErrorDetail::PrefixMismatch(_))
+                ),
+                "i={} res={:?}",
+                i,
+                res
+            );
+
+            let res = verify(
+                states.clone(),
+                with_entry(i, &|entry| {
+                    entry.path = "connections/connection-1".to_string()
+                }),
+            );
+            assert!(
+                matches!(
+                    res.as_ref().map_err(|e| e.detail()),
+                    Err(ErrorDetail::PathMismatch(_))
+                ),
+                "i={} res={:?}",
+                i,
+                res
+            );
+
+            let res = verify(
+                states.clone(),
+                with_entry(i, &|entry| {
+                    entry.value = Some(b"other".to_vec().keccak256())
+                }),
+            );
+            assert!(
+                matches!(
+                    res.as_ref().map_err(|e| e.detail()),
+                    Err(ErrorDetail::ValueMismatch(_))
+                ),
+                "i={} res={:?}",
+                i,
+                res
+            );
+        }
+
+        // the existence and the non-existence of a state are not interchangeable
+        let res = verify(states.clone(), with_entry(0, &|entry| entry.value = None));
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::ValueMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        let res = verify(
+            states,
+            BatchStateCommitment {
+                state_id: [2u8; 32].into(),
+                ..commitment
+            },
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::StateIdMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+    }
+
+    #[test]
+    fn test_verify_non_membership() {
+        let ek = EnclaveKey::new().unwrap();
+        let ibc_store = Rc::new(RefCell::new(MemStore::default()));
+        let registry = build_lc_registry();
+        let lcp_client = LCPClient::default();
+        let client_id = setup_lcp_client(&registry, &ibc_store, &ek);
+
+        let height = Height::new(0, 1);
+        let state_id = [1u8; 32].into();
+        let mut ctx = Context::new(registry, ibc_store, &ek);
+        ctx.set_timestamp(Time::now());
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_update_client_message(
+                &ek,
+                ek.get_pubkey().as_address(),
+                UpdateClientCommitment {
+                    prev_state_id: None,
+                    new_state_id: state_id,
+                    new_state: Some(Any::new("/dummy.ClientState".to_string(), vec![])),
+                    prev_height: None,
+                    new_height: height,
+                    timestamp: Time::now(),
+                    context: CommitmentContext::Empty,
+                },
+            ),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+
+        let prefix = b"ibc".to_vec();
+        let path = "receipts/ports/transfer/channels/channel-0/sequences/1".to_string();
+        let verify = |commitment: StateCommitment| {
+            let proof =
+                prove_commitment(&ek, ek.get_pubkey().as_address(), commitment.into()).unwrap();
+            lcp_client.verify_non_membership(
+                &ctx,
+                client_id.clone(),
+                prefix.clone(),
+                path.clone(),
+                height,
+                proof.ethabi_encode(),
+            )
+        };
+
+        let commitment = StateCommitment::new(prefix.clone(), path.clone(), None, height, state_id);
+        let res = verify(commitment.clone());
+        assert!(res.is_ok(), "res={:?}", res);
+
+        // the commitment must not have a value
+        let res = verify(StateCommitment {
+            value: Some(b"receipt".to_vec().keccak256()),
+            ..commitment.clone()
+        });
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::ValueMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        let res = verify(StateCommitment {
+            path: "receipts/ports/transfer/channels/channel-0/sequences/2".to_string(),
+            ..commitment.clone()
+        });
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::PathMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        let res = verify(StateCommitment {
+            state_id: [2u8; 32].into(),
+            ..commitment
+        });
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::StateIdMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+    }
+
+    #[test]
+    fn test_register_enclave_key_with_allowed_statuses() {
+        let registry = build_lc_registry();
+        let lcp_client = LCPClient::default();
+        let client_id = ClientId::from_str(&format!("{}-0", lcp_client.client_type())).unwrap();
+
+        let cases: Vec<(&str, Vec<String>, bool)> = vec![
+            (QUOTE_STATUS_OK, vec![], true),
+            ("GROUP_OUT_OF_DATE", vec![], true),
+            (
+                "GROUP_OUT_OF_DATE",
+                vec!["INTEL-SA-00219".to_string()],
+                true,
+            ),
+            ("CONFIGURATION_NEEDED", vec![], false),
+            (
+                "GROUP_OUT_OF_DATE",
+                vec!["INTEL-SA-00000".to_string()],
+                false,
+            ),
+            (
+                QUOTE_STATUS_OK,
+                vec!["INTEL-SA-00219".to_string(), "INTEL-SA-00000".to_string()],
+                false,
+            ),
+        ];
+        for (i, (status, advisory_ids, expected)) in cases.into_iter().enumerate() {
+            let ek = EnclaveKey::new().unwrap();
+            let mut ctx = Context::new(
+                registry.clone(),
+                Rc::new(RefCell::new(MemStore::default())),
+                &ek,
+            );
+            ctx.set_timestamp(Time::now());
+
+            let res = lcp_client.initialise(
+                &mut ctx,
+                client_id.clone(),
+                ClientState {
+                    latest_height: Height::zero(),
+                    mr_enclave: [0u8; 32].to_vec(),
+                    key_expiration: Duration::from_secs(60 * 60 * 24 * 7),
+                    allowed_quote_statuses: vec!["GROUP_OUT_OF_DATE".to_string()],
+                    allowed_advisory_ids: vec!["INTEL-SA-00219".to_string()],
+                    frozen: false,
+                    authorities: vec![],
+                    upgrade_sequence: 0,
+                    revocation_sequence: 0,
+                },
+                ConsensusState {
+                    state_id: Default::default(),
+                    timestamp: Time::unix_epoch(),
+                },
+            );
+            assert!(res.is_ok(), "res={:?}", res);
+
+            let res = lcp_client.update_state(
+                &mut ctx,
+                client_id.clone(),
+                ClientMessage::RegisterEnclaveKey(RegisterEnclaveKeyMessage(
+                    generate_dummy_eavr_with_params(
+                        &ek.get_pubkey(),
+                        [0u8; 32],
+                        status,
+                        advisory_ids,
+                    ),
+                )),
+            );
+            if expected {
+                assert!(res.is_ok(), "case={} res={:?}", i, res);
+            } else {
+                assert!(
+                    matches!(
+                        res.as_ref().map_err(|e| e.detail()),
+                        Err(ErrorDetail::DisallowedQuoteStatus(_))
+                            | Err(ErrorDetail::DisallowedAdvisoryIds(_))
+                    ),
+                    "case={} res={:?}",
+                    i,
+                    res
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_upgrade_client() {
+        let ek = EnclaveKey::new().unwrap();
+        let new_ek = EnclaveKey::new().unwrap();
+        let authority = EnclaveKey::new().unwrap();
+        let authority_address = authority.get_pubkey().as_address();
+        let ibc_store = Rc::new(RefCell::new(MemStore::default()));
+        let registry = build_lc_registry();
+        let lcp_client = LCPClient::default();
+        let client_id = setup_lcp_client(&registry, &ibc_store, &ek);
+
+        let height = Height::new(0, 1);
+        let state_id = [1u8; 32].into();
+        let mut ctx = Context::new(registry, ibc_store, &ek);
+        ctx.set_timestamp(Time::now());
+        set_authorities(&mut ctx, &client_id, vec![authority_address]);
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_update_client_message(
+                &ek,
+                ek.get_pubkey().as_address(),
+                UpdateClientCommitment {
+                    prev_state_id: None,
+                    new_state_id: state_id,
+                    new_state: Some(Any::new("/dummy.ClientState".to_string(), vec![])),
+                    prev_height: None,
+                    new_height: height,
+                    timestamp: Time::now(),
+                    context: CommitmentContext::Empty,
+                },
+            ),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+
+        let prefix = b"ibc".to_vec();
+        let path = "connections/connection-0".to_string();
+        let value = b"connection".to_vec();
+        let commitment = StateCommitment::new(
+            prefix.clone(),
+            path.clone(),
+            Some(value.keccak256()),
+            height,
+            state_id,
+        );
+        let verify = |ctx: &dyn HostClientReader, signer: &EnclaveKey| {
+            let proof = prove_commitment(
+                signer,
+                signer.get_pubkey().as_address(),
+                commitment.clone().into(),
+            )
+            .unwrap();
+            lcp_client.verify_membership(
+                ctx,
+                client_id.clone(),
+                prefix.clone(),
+                path.clone(),
+                value.clone(),
+                height,
+                proof.ethabi_encode(),
+            )
+        };
+        let res = verify(&ctx, &ek);
+        assert!(res.is_ok(), "res={:?}", res);
+
+        let upgrade =
+            |ctx: &mut dyn HostClientKeeper, options: UpgradeOptions, signer: &EnclaveKey| {
+                let client_state =
+                    ClientState::try_from(ctx.client_state(&client_id).unwrap()).unwrap();
+                let signature = signer
+                    .sign(&upgrade_client_sign_bytes(
+                        &client_id,
+                        &client_state.mr_enclave,
+                        client_state.upgrade_sequence,
+                        &options,
+                    ))
+                    .unwrap();
+                lcp_client.upgrade_client(
+                    ctx,
+                    client_id.clone(),
+                    options,
+                    signer.get_pubkey().as_address(),
+                    signature,
+                )
+            };
+
+        // invalid options
+        let res = upgrade(
+            &mut ctx,
+            UpgradeOptions {
+                mr_enclave: [1u8; 31].to_vec(),
+                key_expiration: None,
+            },
+            &authority,
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::InvalidMrenclaveLength(_))
+            ),
+            "res={:?}",
+            res
+        );
+        let res = upgrade(
+            &mut ctx,
+            UpgradeOptions {
+                mr_enclave: [1u8; 32].to_vec(),
+                key_expiration: Some(Duration::ZERO),
+            },
+            &authority,
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::InvalidKeyExpiration(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        let options = UpgradeOptions {
+            mr_enclave: [1u8; 32].to_vec(),
+            key_expiration: Some(Duration::from_secs(60 * 60 * 24)),
+        };
+
+        // the upgrade must be signed by an authority
+        let res = upgrade(&mut ctx, options.clone(), &ek);
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::NotAuthority(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the signer must match the actual signer of the signature
+        let signature = ek
+            .sign(&upgrade_client_sign_bytes(
+                &client_id, &[0u8; 32], 0, &options,
+            ))
+            .unwrap();
+        let res = lcp_client.upgrade_client(
+            &mut ctx,
+            client_id.clone(),
+            options.clone(),
+            authority_address,
+            signature,
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::SignerMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        let res = upgrade(&mut ctx, options.clone(), &authority);
+        assert!(res.is_ok(), "res={:?}", res);
+
+        let client_state = ClientState::try_from(ctx.client_state(&client_id).unwrap()).unwrap();
+        assert_eq!(client_state.mr_enclave, [1u8; 32].to_vec());
+        assert_eq!(
+            client_state.key_expiration,
+            Duration::from_secs(60 * 60 * 24)
+        );
+        assert_eq!(client_state.latest_height, height);
+        assert_eq!(client_state.upgrade_sequence, 1);
+
+        // the key bound to the previous mr_enclave is no longer available
+        let res = verify(&ctx, &ek);
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::EnclaveKeyNotFound(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // a key attested with the previous mr_enclave cannot be registered
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            ClientMessage::RegisterEnclaveKey(RegisterEnclaveKeyMessage(generate_dummy_eavr(
+                &new_ek.get_pubkey(),
+            ))),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::MrenclaveMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // a key attested with the new mr_enclave can verify the states kept over the upgrade
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            ClientMessage::RegisterEnclaveKey(RegisterEnclaveKeyMessage(
+                generate_dummy_eavr_with_params(
+                    &new_ek.get_pubkey(),
+                    [1u8; 32],
+                    QUOTE_STATUS_OK,
+                    vec![],
+                ),
+            )),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+        let res = verify(&ctx, &new_ek);
+        assert!(res.is_ok(), "res={:?}", res);
+
+        // the signature for the previous upgrade cannot be replayed
+        let signature = authority
+            .sign(&upgrade_client_sign_bytes(
+                &client_id, &[0u8; 32], 0, &options,
+            ))
+            .unwrap();
+        let replay = |ctx: &mut dyn HostClientKeeper| {
+            lcp_client.upgrade_client(
+                ctx,
+                client_id.clone(),
+                options.clone(),
+                authority_address,
+                signature.clone(),
+            )
+        };
+        let res = replay(&mut ctx);
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::SignerMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the signature cannot be replayed even if the client is upgraded back to the previous mr_enclave
+        let res = upgrade(
+            &mut ctx,
+            UpgradeOptions {
+                mr_enclave: [0u8; 32].to_vec(),
+                key_expiration: None,
+            },
+            &authority,
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+        let client_state = ClientState::try_from(ctx.client_state(&client_id).unwrap()).unwrap();
+        assert_eq!(client_state.mr_enclave, [0u8; 32].to_vec());
+        assert_eq!(client_state.upgrade_sequence, 2);
+        let res = replay(&mut ctx);
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::SignerMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // a frozen client cannot be upgraded
+        let client_state = ClientState::try_from(ctx.client_state(&client_id).unwrap()).unwrap();
+        ctx.store_any_client_state(client_id.clone(), client_state.freeze().into())
+            .unwrap();
+        let res = upgrade(
+            &mut ctx,
+            UpgradeOptions {
+                mr_enclave: [2u8; 32].to_vec(),
+                key_expiration: None,
+            },
+            &authority,
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::ClientFrozen(_))
+            ),
+            "res={:?}",
+            res
+        );
+    }
+
+    #[test]
+    fn test_recover_client() {
+        let ek = EnclaveKey::new().unwrap();
+        let authority = EnclaveKey::new().unwrap();
+        let authority_address = authority.get_pubkey().as_address();
+        let ibc_store = Rc::new(RefCell::new(MemStore::default()));
+        let registry = build_lc_registry();
+        let lcp_client = LCPClient::default();
+        let client_id = setup_lcp_client(&registry, &ibc_store, &ek);
+
+        let height = Height::new(0, 1);
+        let state_id: StateID = [1u8; 32].into();
+        let mut ctx = Context::new(registry, ibc_store, &ek);
+        ctx.set_timestamp(Time::now());
+        set_authorities(&mut ctx, &client_id, vec![authority_address]);
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_update_client_message(
+                &ek,
+                ek.get_pubkey().as_address(),
+                UpdateClientCommitment {
+                    prev_state_id: None,
+                    new_state_id: state_id,
+                    new_state: Some(Any::new("/dummy.ClientState".to_string(), vec![])),
+                    prev_height: None,
+                    new_height: height,
+                    timestamp: Time::now(),
+                    context: CommitmentContext::Empty,
+                },
+            ),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+        // the ELC was frozen
+        let client_state = ClientState::try_from(ctx.client_state(&client_id).unwrap()).unwrap();
+        ctx.store_any_client_state(client_id.clone(), client_state.freeze().into())
+            .unwrap();
+
+        let new_height = Height::new(0, 5);
+        let new_state_id: StateID = [2u8; 32].into();
+        let commitment = RecoverClientCommitment {
+            prev_state_id: state_id,
+            new_state_id,
+            new_state: Any::new("/dummy.ClientState".to_string(), vec![1]),
+            prev_height: height,
+            new_height,
+            timestamp: Time::now(),
+        };
+        let proof =
+            prove_commitment(&ek, ek.get_pubkey().as_address(), commitment.clone().into()).unwrap();
+        let recover =
+            |ctx: &mut dyn HostClientKeeper, proof: &CommitmentProof, signer: &EnclaveKey| {
+                let signature = signer
+                    .sign(&recover_client_sign_bytes(
+                        &client_id,
+                        &proof.commitment_bytes,
+                    ))
+                    .unwrap();
+                lcp_client.recover_client(
+                    ctx,
+                    client_id.clone(),
+                    proof.clone().ethabi_encode(),
+                    signer.get_pubkey().as_address(),
+                    signature,
+                )
+            };
+
+        // the recovery commitment cannot be submitted as an update without the approval of an authority
+        let res = UpdateClientMessage::try_from(RawUpdateClientMessage {
+            commitment: proof.commitment_bytes.clone(),
+            signer: proof.signer.into(),
+            signature: proof.signature.clone(),
+        });
+        assert!(res.is_err(), "res={:?}", res);
+
+        // the recovery must be approved by an authority
+        let res = recover(&mut ctx, &proof, &ek);
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::NotAuthority(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the approval cannot be used for another recovery
+        let other_proof = prove_commitment(
+            &ek,
+            ek.get_pubkey().as_address(),
+            RecoverClientCommitment {
+                new_state_id: [3u8; 32].into(),
+                ..commitment.clone()
+            }
+            .into(),
+        )
+        .unwrap();
+        let signature = authority
+            .sign(&recover_client_sign_bytes(
+                &client_id,
+                &proof.commitment_bytes,
+            ))
+            .unwrap();
+        let res = lcp_client.recover_client(
+            &mut ctx,
+            client_id.clone(),
+            other_proof.ethabi_encode(),
+            authority_address,
+            signature,
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::SignerMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the recovery must be derived from the state stored in the client
+        let invalid_proof = prove_commitment(
+            &ek,
+            ek.get_pubkey().as_address(),
+            RecoverClientCommitment {
+                prev_state_id: [9u8; 32].into(),
+                ..commitment.clone()
+            }
+            .into(),
+        )
+        .unwrap();
+        let res = recover(&mut ctx, &invalid_proof, &authority);
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::StateIdMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the recovery commitment must be proven by a registered enclave key
+        let unknown_ek = EnclaveKey::new().unwrap();
+        let unknown_proof = prove_commitment(
+            &unknown_ek,
+            unknown_ek.get_pubkey().as_address(),
+            commitment.clone().into(),
+        )
+        .unwrap();
+        let res = recover(&mut ctx, &unknown_proof, &authority);
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::EnclaveKeyNotFound(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        let res = recover(&mut ctx, &proof, &authority);
+        assert!(res.is_ok(), "res={:?}", res);
+
+        let client_state = ClientState::try_from(ctx.client_state(&client_id).unwrap()).unwrap();
+        assert!(!client_state.frozen);
+        assert_eq!(client_state.latest_height, new_height);
+        let consensus_state =
+            ConsensusState::try_from(ctx.consensus_state(&client_id, &new_height).unwrap())
+                .unwrap();
+        assert_eq!(consensus_state.state_id, new_state_id);
+        assert_eq!(
+            consensus_state.timestamp.as_unix_timestamp_secs(),
+            commitment.timestamp.as_unix_timestamp_secs()
+        );
+    }
+
+    #[test]
+    fn test_legacy_enclave_key_path() {
+        let ek = EnclaveKey::new().unwrap();
+        let legacy_ek = EnclaveKey::new().unwrap();
+        let legacy_address = legacy_ek.get_pubkey().as_address();
+        let authority = EnclaveKey::new().unwrap();
+        let ibc_store = Rc::new(RefCell::new(MemStore::default()));
+        let registry = build_lc_registry();
+        let lcp_client = LCPClient::default();
+        let client_id = setup_lcp_client(&registry, &ibc_store, &ek);
+
+        let mut ctx = Context::new(registry, ibc_store, &ek);
+        ctx.set_timestamp(Time::now());
+        set_authorities(
+            &mut ctx,
+            &client_id,
+            vec![authority.get_pubkey().as_address()],
+        );
+
+        // a key registered before the path included `mr_enclave`
+        let expired_at = Time::now().as_unix_timestamp_secs() + 60 * 60;
+        ctx.set(
+            legacy_enclave_key_path(&client_id, legacy_address),
+            expired_at.to_be_bytes().to_vec(),
+        );
+        let client_state = ClientState::try_from(ctx.client_state(&client_id).unwrap()).unwrap();
+        let res =
+            lcp_client.ensure_active_enclave_key(&ctx, &client_id, &client_state, legacy_address);
+        assert!(res.is_ok(), "res={:?}", res);
+
+        // the legacy key is bound to the initial `mr_enclave`, so it is not available after the upgrade
+        let options = UpgradeOptions {
+            mr_enclave: [1u8; 32].to_vec(),
+            key_expiration: None,
+        };
+        let signature = authority
+            .sign(&upgrade_client_sign_bytes(
+                &client_id,
+                &client_state.mr_enclave,
+                client_state.upgrade_sequence,
+                &options,
+            ))
+            .unwrap();
+        let res = lcp_client.upgrade_client(
+            &mut ctx,
+            client_id.clone(),
+            options,
+            authority.get_pubkey().as_address(),
+            signature,
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+        let client_state = ClientState::try_from(ctx.client_state(&client_id).unwrap()).unwrap();
+        let res =
+            lcp_client.ensure_active_enclave_key(&ctx, &client_id, &client_state, legacy_address);
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::EnclaveKeyNotFound(_))
+            ),
+            "res={:?}",
+            res
+        );
+    }
+
+    #[test]
+    fn test_misbehaviour() {
+        let ek = EnclaveKey::new().unwrap();
+        let unregistered_ek = EnclaveKey::new().unwrap();
+        let ibc_store = Rc::new(RefCell::new(MemStore::default()));
+        let registry = build_lc_registry();
+        let lcp_client = LCPClient::default();
+        let client_id = setup_lcp_client(&registry, &ibc_store, &ek);
+
+        let height = Height::new(0, 1);
+        let state_id = [1u8; 32].into();
+        let mut ctx = Context::new(registry, ibc_store, &ek);
+        ctx.set_timestamp(Time::now());
+        let initial_commitment = UpdateClientCommitment {
+            prev_state_id: None,
+            new_state_id: state_id,
+            new_state: Some(Any::new("/dummy.ClientState".to_string(), vec![])),
+            prev_height: None,
+            new_height: height,
+            timestamp: Time::now(),
+            context: CommitmentContext::Empty,
+        };
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_update_client_message(
+                &ek,
+                ek.get_pubkey().as_address(),
+                initial_commitment.clone(),
+            ),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+
+        let commitment_1 = UpdateClientCommitment {
+            prev_state_id: Some(state_id),
+            new_state_id: [2u8; 32].into(),
+            new_state: None,
+            prev_height: Some(height),
+            new_height: Height::new(0, 2),
+            ..initial_commitment
+        };
+        let commitment_2 = UpdateClientCommitment {
+            new_state_id: [3u8; 32].into(),
+            ..commitment_1.clone()
+        };
+        let build_misbehaviour =
+            |c1: UpdateClientCommitment, c2: UpdateClientCommitment, signer: &EnclaveKey| {
+                let address = signer.get_pubkey().as_address();
+                let update = |c| match build_update_client_message(signer, address, c) {
+                    ClientMessage::UpdateClient(m) => m,
+                    _ => unreachable!(),
+                };
+                ClientMessage::Misbehaviour(MisbehaviourMessage {
+                    update_1: update(c1),
+                    update_2: update(c2),
+                })
+            };
+
+        // the new states must be different
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_misbehaviour(commitment_1.clone(), commitment_1.clone(), &ek),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::InvalidMisbehaviour(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the new heights must be the same
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_misbehaviour(
+                commitment_1.clone(),
+                UpdateClientCommitment {
+                    new_height: Height::new(0, 3),
+                    ..commitment_2.clone()
+                },
+                &ek,
+            ),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::InvalidMisbehaviour(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the commitments must be signed by the registered keys
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_misbehaviour(commitment_1.clone(), commitment_2.clone(), &unregistered_ek),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::EnclaveKeyNotFound(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // each commitment must match its own previous state stored in the client
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_misbehaviour(
+                commitment_1.clone(),
+                UpdateClientCommitment {
+                    prev_state_id: Some([9u8; 32].into()),
+                    ..commitment_2.clone()
+                },
+                &ek,
+            ),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::StateIdMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_misbehaviour(
+                commitment_1.clone(),
+                UpdateClientCommitment {
+                    prev_state_id: None,
+                    prev_height: None,
+                    ..commitment_2.clone()
+                },
+                &ek,
+            ),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::MissingPrevState(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the commitments may be derived from different previous states
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_update_client_message(&ek, ek.get_pubkey().as_address(), commitment_1.clone()),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_misbehaviour(
+                UpdateClientCommitment {
+                    new_height: Height::new(0, 3),
+                    new_state_id: [4u8; 32].into(),
+                    ..commitment_1.clone()
+                },
+                UpdateClientCommitment {
+                    prev_height: Some(commitment_1.new_height),
+                    prev_state_id: Some(commitment_1.new_state_id),
+                    new_height: Height::new(0, 3),
+                    new_state_id: [5u8; 32].into(),
+                    ..commitment_2
+                },
+                &ek,
+            ),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+        let client_state = ClientState::try_from(ctx.client_state(&client_id).unwrap()).unwrap();
+        assert!(client_state.frozen);
+
+        // the frozen client refuses updates and verifications
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_update_client_message(&ek, ek.get_pubkey().as_address(), commitment_1),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::ClientFrozen(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        let prefix = b"ibc".to_vec();
+        let path = "connections/connection-0".to_string();
+        let value = b"connection".to_vec();
+        let proof = prove_commitment(
+            &ek,
+            ek.get_pubkey().as_address(),
+            StateCommitment::new(
+                prefix.clone(),
+                path.clone(),
+                Some(value.keccak256()),
+                height,
+                state_id,
+            )
+            .into(),
+        )
+        .unwrap();
+        let res = lcp_client.verify_membership(
+            &ctx,
+            client_id,
+            prefix,
+            path,
+            value,
+            height,
+            proof.ethabi_encode(),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::ClientFrozen(_))
+            ),
+            "res={:?}",
+            res
+        );
+    }
+
+    #[test]
+    fn test_mock_submit_misbehaviour() {
+        let ek = EnclaveKey::new().unwrap();
+        let registry = build_lc_registry();
+        let mock_client = MockLightClient::default();
+        let mut ctx = Context::new(registry, MemStore::default(), &ek);
+        ctx.set_timestamp(Time::now());
+
+        let header = MockHeader::new(ICS02Height::new(0, 1).unwrap());
+        let client_id = ClientId::from_str(&format!("{}-0", mock_client.client_type())).unwrap();
+        ctx.store_client_type(client_id.clone(), mock_client.client_type())
+            .unwrap();
+        ctx.store_any_client_state(
+            client_id.clone(),
+            mock_lc::ClientState::from(MockClientState::new(header)).into(),
+        )
+        .unwrap();
+        ctx.store_any_consensus_state(
+            client_id.clone(),
+            Height::new(0, 1),
+            mock_lc::ConsensusState::from(MockConsensusState::new(header)).into(),
+        )
+        .unwrap();
+
+        let build_misbehaviour = |height_1: u64, height_2: u64| {
+            let header = |height: u64, nanos: u64| {
+                MockHeader::new(ICS02Height::new(0, height).unwrap())
+                    .with_timestamp(Timestamp::from_nanoseconds(nanos).unwrap())
+            };
+            Any::new(
+                MOCK_MISBEHAVIOUR_TYPE_URL.to_string(),
+                MockMisbehaviour {
+                    client_id: client_id.clone().into(),
+                    header1: header(height_1, 1),
+                    header2: header(height_2, 2),
+                }
+                .encode_vec()
+                .unwrap(),
+            )
+        };
+
+        // the headers must have the same height
+        let res =
+            mock_client.submit_misbehaviour(&ctx, client_id.clone(), build_misbehaviour(2, 3));
+        assert!(res.is_err(), "res={:?}", res);
+
+        let res =
+            mock_client.submit_misbehaviour(&ctx, client_id.clone(), build_misbehaviour(2, 2));
+        assert!(res.is_ok(), "res={:?}", res);
+        let res = res.unwrap();
+        let commitment = MisbehaviourCommitment::try_from(res.commitment).unwrap();
+        assert_eq!(commitment.client_id, client_id);
+        assert_eq!(
+            commitment
+                .prev_states
+                .iter()
+                .map(|s| s.height)
+                .collect::<Vec<_>>(),
+            vec![Height::new(0, 1)]
+        );
+        let client_state =
+            mock_lc::ClientState::try_from(res.new_any_client_state.clone()).unwrap();
+        assert!(client_state.frozen_height.is_some());
+
+        // the frozen client refuses another misbehaviour
+        ctx.store_any_client_state(client_id.clone(), res.new_any_client_state)
+            .unwrap();
+        let res = mock_client.submit_misbehaviour(&ctx, client_id, build_misbehaviour(2, 2));
+        assert!(res.is_err(), "res={:?}", res);
+    }
+
+    #[test]
+    fn test_elc_misbehaviour() {
+        let ek = EnclaveKey::new().unwrap();
+        let unregistered_ek = EnclaveKey::new().unwrap();
+        let ibc_store = Rc::new(RefCell::new(MemStore::default()));
+        let registry = build_lc_registry();
+        let lcp_client = LCPClient::default();
+        let client_id = setup_lcp_client(&registry, &ibc_store, &ek);
+
+        let height = Height::new(0, 1);
+        let state_id = [1u8; 32].into();
+        let mut ctx = Context::new(registry, ibc_store, &ek);
+        ctx.set_timestamp(Time::now());
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_update_client_message(
+                &ek,
+                ek.get_pubkey().as_address(),
+                UpdateClientCommitment {
+                    prev_state_id: None,
+                    new_state_id: state_id,
+                    new_state: Some(Any::new("/dummy.ClientState".to_string(), vec![])),
+                    prev_height: None,
+                    new_height: height,
+                    timestamp: Time::now(),
+                    context: CommitmentContext::Empty,
+                },
+            ),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+
+        // the commitment's client_id is the ELC's one
+        let elc_client_id = ClientId::from_str("07-tendermint-0").unwrap();
+        let commitment = MisbehaviourCommitment {
+            client_id: elc_client_id,
+            frozen_height: Height::new(0, 2),
+            prev_states: vec![PrevState::new(height, state_id)],
+            context: CommitmentContext::Empty,
+        };
+        let build_misbehaviour = |commitment: MisbehaviourCommitment, signer: &EnclaveKey| {
+            let proof = prove_commitment(
+                signer,
+                signer.get_pubkey().as_address(),
+                commitment.clone().into(),
+            )
+            .unwrap();
+            ClientMessage::ELCMisbehaviour(ELCMisbehaviourMessage {
+                commitment_bytes: proof.commitment_bytes,
+                signer: proof.signer,
+                signature: proof.signature,
+                commitment,
+            })
+        };
+
+        // the message can be decoded from `Any`
+        let message = build_misbehaviour(commitment.clone(), &ek);
+        assert_eq!(
+            ClientMessage::try_from(Any::from(message.clone())).unwrap(),
+            message
+        );
+
+        // the misbehaviour must be verified against the states stored in the client
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_misbehaviour(
+                MisbehaviourCommitment {
+                    prev_states: vec![],
+                    ..commitment.clone()
+                },
+                &ek,
+            ),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::InvalidMisbehaviour(_))
+            ),
+            "res={:?}",
+            res
+        );
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_misbehaviour(
+                MisbehaviourCommitment {
+                    prev_states: vec![
+                        PrevState::new(height, state_id),
+                        PrevState::new(height, [9u8; 32].into()),
+                    ],
+                    ..commitment.clone()
+                },
+                &ek,
+            ),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::StateIdMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the commitment must be signed by a registered key
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_misbehaviour(commitment.clone(), &unregistered_ek),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::EnclaveKeyNotFound(_))
+            ),
+            "res={:?}",
+            res
+        );
+        let client_state = ClientState::try_from(ctx.client_state(&client_id).unwrap()).unwrap();
+        assert!(!client_state.frozen);
+
+        let res = lcp_client.update_state(&mut ctx, client_id.clone(), message.clone());
+        assert!(res.is_ok(), "res={:?}", res);
+        let client_state = ClientState::try_from(ctx.client_state(&client_id).unwrap()).unwrap();
+        assert!(client_state.frozen);
+
+        // the frozen client refuses another misbehaviour
+        let res = lcp_client.update_state(&mut ctx, client_id, message);
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::ClientFrozen(_))
+            ),
+            "res={:?}",
+            res
+        );
+    }
+
+    #[test]
+    fn test_revoke_enclave_key() {
+        let ek = EnclaveKey::new().unwrap();
+        let ek2 = EnclaveKey::new().unwrap();
+        let authority = EnclaveKey::new().unwrap();
+        let ibc_store = Rc::new(RefCell::new(MemStore::default()));
+        let registry = build_lc_registry();
+        let lcp_client = LCPClient::default();
+        let client_id = setup_lcp_client(&registry, &ibc_store, &ek);
+        let (ek_address, ek2_address, authority_address) = (
+            ek.get_pubkey().as_address(),
+            ek2.get_pubkey().as_address(),
+            authority.get_pubkey().as_address(),
+        );
+
+        let mut ctx = Context::new(registry, ibc_store, &ek);
+        ctx.set_timestamp(Time::now());
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            ClientMessage::RegisterEnclaveKey(RegisterEnclaveKeyMessage(generate_dummy_eavr(
+                &ek2.get_pubkey(),
+            ))),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+
+        let build_revoke_message =
+            |sequence: u64, key: Address, signer: &EnclaveKey, signer_address: Address| {
+                ClientMessage::RevokeEnclaveKey(RevokeEnclaveKeyMessage {
+                    key,
+                    signer: signer_address,
+                    signature: signer
+                        .sign(&revoke_enclave_key_sign_bytes(&client_id, sequence, key))
+                        .unwrap(),
+                })
+            };
+
+        // the signer must be a registered enclave key or an authority
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_revoke_message(0, ek_address, &authority, authority_address),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::EnclaveKeyNotFound(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the signer must match the actual signer of the signature
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_revoke_message(0, ek_address, &ek2, ek_address),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::SignerMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // an active enclave key can revoke another key
+        let message = build_revoke_message(0, ek_address, &ek2, ek2_address);
+        let res = lcp_client.update_state(&mut ctx, client_id.clone(), message.clone());
+        assert!(res.is_ok(), "res={:?}", res);
+        let client_state = ClientState::try_from(ctx.client_state(&client_id).unwrap()).unwrap();
+        assert_eq!(client_state.revocation_sequence, 1);
+
+        // the signature for the previous revocation cannot be replayed
+        let res = lcp_client.update_state(&mut ctx, client_id.clone(), message);
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::SignerMismatch(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the revoked key can no longer update the client
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_update_client_message(
+                &ek,
+                ek_address,
+                UpdateClientCommitment {
+                    prev_state_id: None,
+                    new_state_id: [1u8; 32].into(),
+                    new_state: Some(Any::new("/dummy.ClientState".to_string(), vec![])),
+                    prev_height: None,
+                    new_height: Height::new(0, 1),
+                    timestamp: Time::now(),
+                    context: CommitmentContext::Empty,
+                },
+            ),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::EnclaveKeyNotFound(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the revoked key cannot be registered again
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            ClientMessage::RegisterEnclaveKey(RegisterEnclaveKeyMessage(generate_dummy_eavr(
+                &ek.get_pubkey(),
+            ))),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::RevokedEnclaveKey(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // an authority can revoke the key
+        let client_state = ClientState::try_from(ctx.client_state(&client_id).unwrap()).unwrap();
+        ctx.store_any_client_state(
+            client_id.clone(),
+            ClientState {
+                authorities: vec![authority_address],
+                ..client_state
+            }
+            .into(),
+        )
+        .unwrap();
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            build_revoke_message(1, ek2_address, &authority, authority_address),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+        let client_state = ClientState::try_from(ctx.client_state(&client_id).unwrap()).unwrap();
+        let res =
+            lcp_client.ensure_active_enclave_key(&ctx, &client_id, &client_state, ek2_address);
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::RevokedEnclaveKey(_))
+            ),
+            "res={:?}",
+            res
+        );
+    }
+
+    #[test]
+    fn test_revoke_enclave_key_by_non_authority() {
+        let ek = EnclaveKey::new().unwrap();
+        let authority = EnclaveKey::new().unwrap();
+        let outsider = EnclaveKey::new().unwrap();
+        let ibc_store = Rc::new(RefCell::new(MemStore::default()));
+        let registry = build_lc_registry();
+        let lcp_client = LCPClient::default();
+        let client_id = setup_lcp_client(&registry, &ibc_store, &ek);
+        let (ek_address, outsider_address) = (
+            ek.get_pubkey().as_address(),
+            outsider.get_pubkey().as_address(),
+        );
+
+        let mut ctx = Context::new(registry, ibc_store, &ek);
+        ctx.set_timestamp(Time::now());
+        set_authorities(
+            &mut ctx,
+            &client_id,
+            vec![authority.get_pubkey().as_address()],
+        );
+
+        // a key that is neither an authority nor a registered enclave key cannot revoke the key
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            ClientMessage::RevokeEnclaveKey(RevokeEnclaveKeyMessage {
+                key: ek_address,
+                signer: outsider_address,
+                signature: outsider
+                    .sign(&revoke_enclave_key_sign_bytes(&client_id, 0, ek_address))
+                    .unwrap(),
+            }),
+        );
+        assert!(
+            matches!(
+                res.as_ref().map_err(|e| e.detail()),
+                Err(ErrorDetail::EnclaveKeyNotFound(_))
+            ),
+            "res={:?}",
+            res
+        );
+
+        // the key is still active
+        let client_state = ClientState::try_from(ctx.client_state(&client_id).unwrap()).unwrap();
+        let res = lcp_client.ensure_active_enclave_key(&ctx, &client_id, &client_state, ek_address);
+        assert!(res.is_ok(), "res={:?}", res);
+    }
+
+    fn set_authorities(
+        ctx: &mut dyn HostClientKeeper,
+        client_id: &ClientId,
+        authorities: Vec<Address>,
+    ) {
+        let client_state = ClientState::try_from(ctx.client_state(client_id).unwrap()).unwrap();
+        ctx.store_any_client_state(
+            client_id.clone(),
+            ClientState {
+                authorities,
+                ..client_state
+            }
+            .into(),
+        )
+        .unwrap();
+    }
+
+    fn setup_lcp_client(
+        registry: &Arc<dyn LightClientResolver>,
+        ibc_store: &Rc<RefCell<MemStore>>,
+        ek: &EnclaveKey,
+    ) -> ClientId {
+        let lcp_client = LCPClient::default();
+        let mut ctx = Context::new(registry.clone(), ibc_store.clone(), ek);
+        ctx.set_timestamp(Time::now());
+
+        let client_id = ClientId::from_str(&format!("{}-0", lcp_client.client_type())).unwrap();
+        let res = lcp_client.initialise(
+            &mut ctx,
+            client_id.clone(),
+            ClientState {
+                latest_height: Height::zero(),
+                mr_enclave: [0u8; 32].to_vec(),
+                key_expiration: Duration::from_secs(60 * 60 * 24 * 7),
+                allowed_quote_statuses: vec![],
+                allowed_advisory_ids: vec![],
+                frozen: false,
+                authorities: vec![],
+                upgrade_sequence: 0,
+                revocation_sequence: 0,
+            },
+            ConsensusState {
+                state_id: Default::default(),
+                timestamp: Time::unix_epoch(),
+            },
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+
+        let res = lcp_client.update_state(
+            &mut ctx,
+            client_id.clone(),
+            ClientMessage::RegisterEnclaveKey(RegisterEnclaveKeyMessage(generate_dummy_eavr(
+                &ek.get_pubkey(),
+            ))),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+        client_id
+    }
+
+    fn build_update_client_message(
+        signer: &EnclaveKey,
+        signer_address: Address,
+        commitment: UpdateClientCommitment,
+    ) -> ClientMessage {
+        let proof = prove_commitment(signer, signer_address, commitment.clone().into()).unwrap();
+        ClientMessage::UpdateClient(UpdateClientMessage {
+            commitment_bytes: proof.commitment_bytes,
+            signer: proof.signer,
+            signature: proof.signature,
+            commitment,
+        })
     }
 
     fn build_lc_registry() -> Arc<dyn LightClientResolver> {
@@ -421,10 +3073,20 @@ mod tests {
     }
 
     fn generate_dummy_eavr(key: &EnclavePublicKey) -> EndorsedAttestationVerificationReport {
+        generate_dummy_eavr_with_params(key, [0u8; 32], QUOTE_STATUS_OK, vec![])
+    }
+
+    fn generate_dummy_eavr_with_params(
+        key: &EnclavePublicKey,
+        mr_enclave: [u8; 32],
+        isv_enclave_quote_status: &str,
+        advisory_ids: Vec<String>,
+    ) -> EndorsedAttestationVerificationReport {
         let quote = sgx_quote_t {
             version: 4,
             report_body: sgx_report_body_t {
                 report_data: key.as_report_data(),
+                mr_enclave: sgx_measurement_t { m: mr_enclave },
                 ..Default::default()
             },
             ..Default::default()
@@ -448,8 +3110,8 @@ mod tests {
             ),
             version: 4,
             advisory_url: "https://security-center.intel.com".to_string(),
-            // advisory_ids,
-            // isv_enclave_quote_status,
+            advisory_ids,
+            isv_enclave_quote_status: isv_enclave_quote_status.to_string(),
             platform_info_blob: None,
             isv_enclave_quote_body: base64::encode(&quote.as_slice()[..432]),
             ..Default::default()
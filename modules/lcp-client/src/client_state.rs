@@ -2,6 +2,7 @@ use crate::errors::Error;
 use crate::message::CommitmentReader;
 use crate::prelude::*;
 use core::time::Duration;
+use crypto::Address;
 use light_client::types::proto::{
     ibc::{
         core::client::v1::Height as ProtoHeight,
@@ -20,9 +21,37 @@ pub struct ClientState {
     pub latest_height: Height,
     pub mr_enclave: Vec<u8>,
     pub key_expiration: Duration,
+    /// quote statuses allowed in addition to "OK"
+    pub allowed_quote_statuses: Vec<String>,
+    /// advisory IDs allowed to be included in the report
+    pub allowed_advisory_ids: Vec<String>,
+    /// whether the client is frozen due to misbehaviour
+    pub frozen: bool,
+    /// keys that are allowed to revoke enclave keys, upgrade the client and approve the recovery of the client
+    pub authorities: Vec<Address>,
+    /// number of upgrades applied to the client
+    pub upgrade_sequence: u64,
+    /// number of enclave keys revoked in the client
+    pub revocation_sequence: u64,
 }
 
 impl ClientState {
+    /// upgrade returns a new client state with the given options applied and the upgrade sequence incremented
+    pub fn upgrade(mut self, options: UpgradeOptions) -> Self {
+        self.mr_enclave = options.mr_enclave;
+        if let Some(key_expiration) = options.key_expiration {
+            self.key_expiration = key_expiration;
+        }
+        self.upgrade_sequence += 1;
+        self
+    }
+
+    /// freeze returns a frozen client state
+    pub fn freeze(mut self) -> Self {
+        self.frozen = true;
+        self
+    }
+
     pub fn with_header<C: CommitmentReader>(mut self, header: &C) -> Self {
         if self.latest_height < header.height() {
             self.latest_height = header.height();
@@ -40,8 +69,12 @@ impl From<ClientState> for RawClientState {
             }),
             mrenclave: value.mr_enclave,
             key_expiration: value.key_expiration.as_secs(),
-            allowed_quote_statuses: Default::default(),
-            allowed_advisory_ids: Default::default(),
+            allowed_quote_statuses: value.allowed_quote_statuses,
+            allowed_advisory_ids: value.allowed_advisory_ids,
+            frozen: value.frozen,
+            authorities: value.authorities.into_iter().map(Into::into).collect(),
+            upgrade_sequence: value.upgrade_sequence,
+            revocation_sequence: value.revocation_sequence,
         }
     }
 }
@@ -50,11 +83,21 @@ impl TryFrom<RawClientState> for ClientState {
     type Error = Error;
 
     fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
-        let height = raw.latest_height.unwrap();
+        let height = raw.latest_height.ok_or_else(Error::missing_latest_height)?;
         Ok(ClientState {
             latest_height: Height::new(height.revision_number, height.revision_height),
             mr_enclave: raw.mrenclave,
             key_expiration: Duration::from_secs(raw.key_expiration),
+            allowed_quote_statuses: raw.allowed_quote_statuses,
+            allowed_advisory_ids: raw.allowed_advisory_ids,
+            frozen: raw.frozen,
+            authorities: raw
+                .authorities
+                .iter()
+                .map(|bz| Address::try_from(bz.as_slice()))
+                .collect::<Result<_, _>>()?,
+            upgrade_sequence: raw.upgrade_sequence,
+            revocation_sequence: raw.revocation_sequence,
         })
     }
 }
@@ -74,12 +117,18 @@ impl TryFrom<Any> for ClientState {
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         match raw.type_url.as_str() {
             LCP_CLIENT_STATE_TYPE_URL => Ok(ClientState::try_from(
-                RawClientState::decode(&*raw.value).unwrap(),
+                RawClientState::decode(&*raw.value).map_err(Error::proto_decode)?,
             )?),
             type_url => Err(Error::unexpected_client_type(type_url.to_owned())),
         }
     }
 }
 
+/// UpgradeOptions is a set of parameters to upgrade the client state
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-pub struct UpgradeOptions {}
+pub struct UpgradeOptions {
+    /// a new MRENCLAVE of the enclave
+    pub mr_enclave: Vec<u8>,
+    /// a new key expiration; if None, the current value is kept
+    pub key_expiration: Option<Duration>,
+}
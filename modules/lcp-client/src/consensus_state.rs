@@ -35,7 +35,7 @@ impl TryFrom<RawConsensusState> for ConsensusState {
 
     fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
         Ok(ConsensusState {
-            state_id: raw.state_id.as_slice().try_into().unwrap(),
+            state_id: raw.state_id.as_slice().try_into()?,
             timestamp: Time::from_unix_timestamp_nanos(
                 (raw.timestamp as u128).checked_mul(1_000_000_000).unwrap(),
             )?,
@@ -61,9 +61,9 @@ impl TryFrom<Any> for ConsensusState {
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         match raw.type_url.as_str() {
-            LCP_CONSENSUS_STATE_TYPE_URL => {
-                ConsensusState::try_from(RawConsensusState::decode(&*raw.value).unwrap())
-            }
+            LCP_CONSENSUS_STATE_TYPE_URL => ConsensusState::try_from(
+                RawConsensusState::decode(&*raw.value).map_err(Error::proto_decode)?,
+            ),
             type_url => Err(Error::unexpected_client_type(type_url.to_owned())),
         }
     }
@@ -1,6 +1,9 @@
 use crate::prelude::*;
 use core::time::Duration;
+use crypto::Address;
 use flex_error::*;
+use light_client::commitments::{CommitmentPrefix, StateID};
+use light_client::types::{ClientId, Height, Time};
 
 define_error! {
     #[derive(Debug, PartialEq, Eq)]
@@ -36,6 +39,157 @@ define_error! {
             format_args!("Mrenclave mismatch: expected={:?} actual={:?}", e.expected, e.actual)
         },
 
+        InvalidKeyExpiration
+        |_| { "key_expiration must not be zero" },
+
+        InvalidInitialLatestHeight {
+            latest_height: Height
+        }
+        |e| {
+            format_args!("an initial client state's latest height must be zero: latest_height={}", e.latest_height)
+        },
+
+        InvalidMrenclaveLength {
+            length: usize
+        }
+        |e| {
+            format_args!("invalid mrenclave length: expected=32 actual={}", e.length)
+        },
+
+        InvalidInitialConsensusState
+        |_| { "an initial consensus state must be empty" },
+
+        MissingLatestHeight
+        |_| { "latest_height must be non-nil" },
+
+        MissingNewState
+        |_| { "the commitment's new_state must be non-nil if the client state's latest height is zero" },
+
+        MissingPrevState
+        |_| { "the commitment's prev_height and prev_state_id must be non-nil if the client state's latest height is non-zero" },
+
+        EnclaveKeyNotFound {
+            address: Address
+        }
+        |e| {
+            format_args!("enclave key not found: address={}", e.address)
+        },
+
+        NotAuthority {
+            address: Address
+        }
+        |e| {
+            format_args!("the signer is not an authority of the client: address={}", e.address)
+        },
+
+        RevokedEnclaveKey {
+            address: Address
+        }
+        |e| {
+            format_args!("enclave key is revoked: address={}", e.address)
+        },
+
+        ExpiredEnclaveKey {
+            address: Address,
+            expired_at: u64,
+            current_timestamp: Time
+        }
+        |e| {
+            format_args!("enclave key is expired: address={} expired_at={} current_timestamp={}", e.address, e.expired_at, e.current_timestamp)
+        },
+
+        InvalidEnclaveKeyExpiration {
+            address: Address,
+            bytes: Vec<u8>
+        }
+        |e| {
+            format_args!("invalid enclave key expiration: address={} bytes={:?}", e.address, e.bytes)
+        },
+
+        SignerMismatch {
+            expected: Address,
+            actual: Address
+        }
+        |e| {
+            format_args!("signer mismatch: expected={} actual={}", e.expected, e.actual)
+        },
+
+        StateIdMismatch {
+            expected: StateID,
+            actual: StateID
+        }
+        |e| {
+            format_args!("state_id mismatch: expected={} actual={}", e.expected, e.actual)
+        },
+
+        PrefixMismatch {
+            expected: CommitmentPrefix,
+            actual: CommitmentPrefix
+        }
+        |e| {
+            format_args!("prefix mismatch: expected={:?} actual={:?}", e.expected, e.actual)
+        },
+
+        PathMismatch {
+            expected: String,
+            actual: String
+        }
+        |e| {
+            format_args!("path mismatch: expected={} actual={}", e.expected, e.actual)
+        },
+
+        HeightMismatch {
+            expected: Height,
+            actual: Height
+        }
+        |e| {
+            format_args!("height mismatch: expected={} actual={}", e.expected, e.actual)
+        },
+
+        ValueMismatch {
+            expected: Option<[u8; 32]>,
+            actual: Option<[u8; 32]>
+        }
+        |e| {
+            format_args!("value mismatch: expected={:?} actual={:?}", e.expected, e.actual)
+        },
+
+        EntriesLengthMismatch {
+            expected: usize,
+            actual: usize
+        }
+        |e| {
+            format_args!("the number of entries mismatch: expected={} actual={}", e.expected, e.actual)
+        },
+
+        ClientFrozen {
+            client_id: ClientId
+        }
+        |e| {
+            format_args!("client is frozen: client_id={}", e.client_id)
+        },
+
+        InvalidMisbehaviour {
+            descr: String
+        }
+        |e| {
+            format_args!("invalid misbehaviour: descr={}", e.descr)
+        },
+
+        DisallowedQuoteStatus {
+            status: String
+        }
+        |e| {
+            format_args!("disallowed quote status: status={}", e.status)
+        },
+
+        DisallowedAdvisoryIds {
+            advisory_ids: Vec<String>
+        }
+        |e| {
+            format_args!("disallowed advisory ids: advisory_ids={:?}", e.advisory_ids)
+        },
+
         AttestationReport
         [attestation_report::Error]
         |_| { "Attestation report error" },
@@ -58,7 +212,11 @@ define_error! {
 
         IbcProto
         [TraceError<light_client::types::proto::protobuf::Error>]
-        |_| { "IBCProto error" }
+        |_| { "IBCProto error" },
+
+        ProtoDecode
+        [TraceError<prost::DecodeError>]
+        |_| { "Proto decode error" }
     }
 }
 
@@ -1,25 +1,40 @@
+use crate::client_state::UpgradeOptions;
 use crate::errors::Error;
 use crate::prelude::*;
 use attestation_report::EndorsedAttestationVerificationReport;
-use crypto::Address;
-use light_client::commitments::{Commitment, CommitmentContext, StateID, UpdateClientCommitment};
+use crypto::{Address, Keccak256};
+pub use light_client::commitments::revoke_enclave_key_sign_bytes;
+use light_client::commitments::{
+    Commitment, CommitmentContext, MisbehaviourCommitment, StateID, UpdateClientCommitment,
+};
 use light_client::types::proto::ibc::lightclients::lcp::v1::{
+    ElcMisbehaviourMessage as RawELCMisbehaviourMessage,
+    MisbehaviourMessage as RawMisbehaviourMessage,
     RegisterEnclaveKeyMessage as RawRegisterEnclaveKeyMessage,
+    RevokeEnclaveKeyMessage as RawRevokeEnclaveKeyMessage,
     UpdateClientMessage as RawUpdateClientMessage,
 };
 use light_client::types::proto::protobuf::Protobuf;
-use light_client::types::{Any, Height, Time};
+use light_client::types::{Any, ClientId, Height, Time};
 use serde::{Deserialize, Serialize};
 
 pub const LCP_REGISTER_ENCLAVE_KEY_MESSAGE_TYPE_URL: &str =
     "/ibc.lightclients.lcp.v1.RegisterEnclaveKeyMessage";
 pub const LCP_UPDATE_CLIENT_MESSAGE_TYPE_URL: &str = "/ibc.lightclients.lcp.v1.UpdateClientMessage";
+pub const LCP_MISBEHAVIOUR_MESSAGE_TYPE_URL: &str = "/ibc.lightclients.lcp.v1.MisbehaviourMessage";
+pub const LCP_ELC_MISBEHAVIOUR_MESSAGE_TYPE_URL: &str =
+    "/ibc.lightclients.lcp.v1.ELCMisbehaviourMessage";
+pub const LCP_REVOKE_ENCLAVE_KEY_MESSAGE_TYPE_URL: &str =
+    "/ibc.lightclients.lcp.v1.RevokeEnclaveKeyMessage";
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum ClientMessage {
     RegisterEnclaveKey(RegisterEnclaveKeyMessage),
     UpdateClient(UpdateClientMessage),
+    Misbehaviour(MisbehaviourMessage),
+    ELCMisbehaviour(ELCMisbehaviourMessage),
+    RevokeEnclaveKey(RevokeEnclaveKeyMessage),
 }
 
 impl Protobuf<Any> for ClientMessage {}
@@ -35,6 +50,15 @@ impl TryFrom<Any> for ClientMessage {
             LCP_UPDATE_CLIENT_MESSAGE_TYPE_URL => Ok(ClientMessage::UpdateClient(
                 UpdateClientMessage::decode_vec(&raw.value).map_err(Error::ibc_proto)?,
             )),
+            LCP_MISBEHAVIOUR_MESSAGE_TYPE_URL => Ok(ClientMessage::Misbehaviour(
+                MisbehaviourMessage::decode_vec(&raw.value).map_err(Error::ibc_proto)?,
+            )),
+            LCP_ELC_MISBEHAVIOUR_MESSAGE_TYPE_URL => Ok(ClientMessage::ELCMisbehaviour(
+                ELCMisbehaviourMessage::decode_vec(&raw.value).map_err(Error::ibc_proto)?,
+            )),
+            LCP_REVOKE_ENCLAVE_KEY_MESSAGE_TYPE_URL => Ok(ClientMessage::RevokeEnclaveKey(
+                RevokeEnclaveKeyMessage::decode_vec(&raw.value).map_err(Error::ibc_proto)?,
+            )),
             type_url => Err(Error::unexpected_header_type(type_url.to_owned())),
         }
     }
@@ -51,6 +75,18 @@ impl From<ClientMessage> for Any {
                 LCP_UPDATE_CLIENT_MESSAGE_TYPE_URL.to_string(),
                 h.encode_vec().unwrap(),
             ),
+            ClientMessage::Misbehaviour(h) => Any::new(
+                LCP_MISBEHAVIOUR_MESSAGE_TYPE_URL.to_string(),
+                h.encode_vec().unwrap(),
+            ),
+            ClientMessage::ELCMisbehaviour(h) => Any::new(
+                LCP_ELC_MISBEHAVIOUR_MESSAGE_TYPE_URL.to_string(),
+                h.encode_vec().unwrap(),
+            ),
+            ClientMessage::RevokeEnclaveKey(h) => Any::new(
+                LCP_REVOKE_ENCLAVE_KEY_MESSAGE_TYPE_URL.to_string(),
+                h.encode_vec().unwrap(),
+            ),
         }
     }
 }
@@ -115,6 +151,149 @@ impl From<UpdateClientMessage> for RawUpdateClientMessage {
     }
 }
 
+/// MisbehaviourMessage is a pair of conflicting commitments signed by enclave keys
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct MisbehaviourMessage {
+    pub update_1: UpdateClientMessage,
+    pub update_2: UpdateClientMessage,
+}
+
+impl Protobuf<RawMisbehaviourMessage> for MisbehaviourMessage {}
+
+impl TryFrom<RawMisbehaviourMessage> for MisbehaviourMessage {
+    type Error = Error;
+    fn try_from(value: RawMisbehaviourMessage) -> Result<Self, Self::Error> {
+        Ok(MisbehaviourMessage {
+            update_1: value
+                .update_1
+                .ok_or_else(|| Error::invalid_misbehaviour("update_1 must be non-nil".into()))?
+                .try_into()?,
+            update_2: value
+                .update_2
+                .ok_or_else(|| Error::invalid_misbehaviour("update_2 must be non-nil".into()))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<MisbehaviourMessage> for RawMisbehaviourMessage {
+    fn from(value: MisbehaviourMessage) -> Self {
+        RawMisbehaviourMessage {
+            update_1: Some(value.update_1.into()),
+            update_2: Some(value.update_2.into()),
+        }
+    }
+}
+
+/// ELCMisbehaviourMessage is a misbehaviour commitment signed by an enclave key
+///
+/// Unlike `MisbehaviourMessage`, the misbehaviour is detected by the ELC in the enclave,
+/// e.g. a double signing of the validators of the counterparty chain.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ELCMisbehaviourMessage {
+    pub commitment_bytes: Vec<u8>,
+    pub signer: Address,
+    pub signature: Vec<u8>,
+    pub commitment: MisbehaviourCommitment,
+}
+
+impl Protobuf<RawELCMisbehaviourMessage> for ELCMisbehaviourMessage {}
+
+impl TryFrom<RawELCMisbehaviourMessage> for ELCMisbehaviourMessage {
+    type Error = Error;
+    fn try_from(value: RawELCMisbehaviourMessage) -> Result<Self, Self::Error> {
+        Ok(ELCMisbehaviourMessage {
+            signer: Address::try_from(value.signer.as_slice())?,
+            signature: value.signature,
+            commitment: Commitment::from_commitment_bytes(&value.commitment)?.try_into()?,
+            commitment_bytes: value.commitment,
+        })
+    }
+}
+
+impl From<ELCMisbehaviourMessage> for RawELCMisbehaviourMessage {
+    fn from(value: ELCMisbehaviourMessage) -> Self {
+        RawELCMisbehaviourMessage {
+            commitment: Into::<Commitment>::into(value.commitment).to_commitment_bytes(),
+            signer: value.signer.into(),
+            signature: value.signature,
+        }
+    }
+}
+
+/// RevokeEnclaveKeyMessage is a message to revoke the enclave key
+///
+/// The signature must be generated over `revoke_enclave_key_sign_bytes` with the client's `revocation_sequence`
+/// by an active enclave key or an authority key.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RevokeEnclaveKeyMessage {
+    pub key: Address,
+    pub signer: Address,
+    pub signature: Vec<u8>,
+}
+
+impl Protobuf<RawRevokeEnclaveKeyMessage> for RevokeEnclaveKeyMessage {}
+
+impl TryFrom<RawRevokeEnclaveKeyMessage> for RevokeEnclaveKeyMessage {
+    type Error = Error;
+    fn try_from(value: RawRevokeEnclaveKeyMessage) -> Result<Self, Self::Error> {
+        Ok(RevokeEnclaveKeyMessage {
+            key: Address::try_from(value.key.as_slice())?,
+            signer: Address::try_from(value.signer.as_slice())?,
+            signature: value.signature,
+        })
+    }
+}
+
+impl From<RevokeEnclaveKeyMessage> for RawRevokeEnclaveKeyMessage {
+    fn from(value: RevokeEnclaveKeyMessage) -> Self {
+        RawRevokeEnclaveKeyMessage {
+            key: value.key.into(),
+            signer: value.signer.into(),
+            signature: value.signature,
+        }
+    }
+}
+
+/// upgrade_client_sign_bytes returns the bytes to be signed by an authority to upgrade the client
+///
+/// The current `mr_enclave` and `upgrade_sequence` are included so that the signature cannot be replayed after the upgrade,
+/// even if the client is upgraded back to the same `mr_enclave`.
+pub fn upgrade_client_sign_bytes(
+    client_id: &ClientId,
+    current_mr_enclave: &[u8],
+    upgrade_sequence: u64,
+    options: &UpgradeOptions,
+) -> Vec<u8> {
+    format!(
+        "upgrade_client/{}/{}/{}/{}/{}",
+        client_id,
+        upgrade_sequence,
+        hex_encode(current_mr_enclave),
+        hex_encode(&options.mr_enclave),
+        options
+            .key_expiration
+            .map_or(String::new(), |d| d.as_secs().to_string())
+    )
+    .into_bytes()
+}
+
+/// recover_client_sign_bytes returns the bytes to be signed by an authority to approve the recovery of the client
+///
+/// The hash of the commitment bytes is included so that the approval cannot be used for another recovery.
+pub fn recover_client_sign_bytes(client_id: &ClientId, commitment_bytes: &[u8]) -> Vec<u8> {
+    format!(
+        "recover_client/{}/{}",
+        client_id,
+        hex_encode(&commitment_bytes.keccak256())
+    )
+    .into_bytes()
+}
+
+pub(crate) fn hex_encode(bz: &[u8]) -> String {
+    bz.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 impl CommitmentReader for UpdateClientMessage {
     fn signer(&self) -> Address {
         self.signer
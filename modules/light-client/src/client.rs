@@ -2,7 +2,8 @@ use crate::commitments::{Commitment, CommitmentPrefix};
 use crate::context::HostClientReader;
 use crate::errors::Error;
 use crate::prelude::*;
-use crate::types::{Any, ClientId, Height};
+use crate::types::{Any, ClientId, ClientStatus, Height, Time};
+use core::time::Duration;
 
 #[allow(clippy::too_many_arguments)]
 pub trait LightClient {
@@ -16,6 +17,30 @@ pub trait LightClient {
         client_id: &ClientId,
     ) -> Result<Height, Error>;
 
+    /// status returns the status of the client
+    fn status(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: &ClientId,
+    ) -> Result<ClientStatus, Error>;
+
+    /// consensus_state_timestamp returns the timestamp of the consensus state at the given height
+    fn consensus_state_timestamp(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Time, Error>;
+
+    /// trusting_period returns the period during which the consensus states of the client can be trusted
+    /// The consensus states older than the period are pruned on update_client.
+    /// If None, the consensus states are never pruned automatically.
+    fn trusting_period(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: &ClientId,
+    ) -> Result<Option<Duration>, Error>;
+
     /// create_client creates a new light client
     fn create_client(
         &self,
@@ -32,6 +57,33 @@ pub trait LightClient {
         any_header: Any,
     ) -> Result<UpdateClientResult, Error>;
 
+    /// submit_misbehaviour verifies the misbehaviour and returns the frozen client state
+    fn submit_misbehaviour(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: ClientId,
+        any_misbehaviour: Any,
+    ) -> Result<MisbehaviourResult, Error>;
+
+    /// recover_client replaces the state of the subject client, which is expired or frozen, with the state of the active substitute client
+    fn recover_client(
+        &self,
+        ctx: &dyn HostClientReader,
+        subject_client_id: ClientId,
+        substitute_client_id: ClientId,
+    ) -> Result<RecoverClientResult, Error>;
+
+    /// upgrade_client verifies the upgraded client state and consensus state committed by the counterparty chain against the latest consensus state, and upgrades the client to them
+    fn upgrade_client(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: ClientId,
+        upgraded_any_client_state: Any,
+        upgraded_any_consensus_state: Any,
+        proof_upgrade_client: Vec<u8>,
+        proof_upgrade_consensus_state: Vec<u8>,
+    ) -> Result<UpgradeClientResult, Error>;
+
     /// verify_membership is a generic proof verification method which verifies a proof of the existence of a value at a given path at the specified height.
     fn verify_membership(
         &self,
@@ -80,6 +132,38 @@ pub struct UpdateClientResult {
     pub prove: bool,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct MisbehaviourResult {
+    /// frozen client state
+    pub new_any_client_state: Any,
+    /// commitment represents the client is frozen due to the misbehaviour
+    pub commitment: Commitment,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecoverClientResult {
+    /// new client state of the subject client
+    pub new_any_client_state: Any,
+    /// new consensus state of the subject client
+    pub new_any_consensus_state: Any,
+    /// height corresponding to the new state
+    pub height: Height,
+    /// commitment represents a state transition of the subject client
+    pub commitment: Commitment,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpgradeClientResult {
+    /// new client state of the upgraded client
+    pub new_any_client_state: Any,
+    /// new consensus state of the upgraded client
+    pub new_any_consensus_state: Any,
+    /// height corresponding to the new state
+    pub height: Height,
+    /// commitment represents a state transition across the upgrade
+    pub commitment: Commitment,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct StateVerificationResult {
     /// state commitment represents a result of the state verification
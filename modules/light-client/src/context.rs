@@ -1,9 +1,13 @@
-use crate::types::{Any, ClientId, Height, Time};
+use crate::types::{Any, ClientId, Height, Pagination, Time};
 use crate::{
     errors::Error,
-    path::{ClientConsensusStatePath, ClientStatePath, ClientTypePath, NEXT_CLIENT_SEQUENCE},
+    path::{
+        ClientConsensusStateHeightsPath, ClientConsensusStatePath, ClientStatePath, ClientTypePath,
+        CLIENTS_PREFIX, NEXT_CLIENT_SEQUENCE,
+    },
     prelude::*,
 };
+use core::str::FromStr;
 use store::KVStore;
 
 pub trait HostContext {
@@ -26,35 +30,141 @@ pub trait ClientReader: KVStore {
     fn client_state(&self, client_id: &ClientId) -> Result<Any, Error> {
         let value = self.get(format!("{}", ClientStatePath::new(client_id)).as_bytes());
         if let Some(value) = value {
-            Ok(
+            Ok(self.normalize_client_state_type_url(
                 bincode::serde::decode_from_slice(&value, bincode::config::standard())
                     .unwrap()
                     .0,
-            )
+            ))
         } else {
             Err(Error::client_state_not_found(client_id.clone()))
         }
     }
 
+    /// Returns the client state with the type URL that the light client accepts.
+    ///
+    /// The default implementation returns the client state as is.
+    /// A host that resolves type URL aliases overrides this so that a client state stored with an alias
+    /// is handed to the light client with the registered type URL.
+    fn normalize_client_state_type_url(&self, any_client_state: Any) -> Any {
+        any_client_state
+    }
+
     /// Retrieve the consensus state for the given client ID at the specified
     /// height.
     ///
     /// Returns an error if no such state exists.
     fn consensus_state(&self, client_id: &ClientId, height: &Height) -> Result<Any, Error> {
+        self.find_consensus_state(client_id, height)?
+            .ok_or_else(|| Error::consensus_state_not_found(client_id.clone(), *height))
+    }
+
+    /// Retrieve the consensus state for the given client ID at the specified
+    /// height.
+    ///
+    /// Returns None if no such state exists, and an error if the stored state cannot be decoded.
+    fn find_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Any>, Error> {
         let path = ClientConsensusStatePath::new(client_id, height);
         let value = match self.get(format!("{}", path).as_bytes()) {
             Some(value) => value,
-            None => {
-                return Err(Error::consensus_state_not_found(client_id.clone(), *height));
-            }
+            None => return Ok(None),
         };
-        Ok(
-            bincode::serde::decode_from_slice(&value, bincode::config::standard())
-                .unwrap()
-                .0,
+        match bincode::serde::decode_from_slice(&value, bincode::config::standard()) {
+            Ok((any, _)) => Ok(Some(any)),
+            Err(e) => Err(Error::invalid_consensus_state(
+                client_id.clone(),
+                *height,
+                e.to_string(),
+            )),
+        }
+    }
+
+    /// Returns a page of the heights of the consensus states that the client holds in ascending order.
+    ///
+    /// The page is read from the height index directly, so its size is bounded by `MAX_PAGE_LIMIT`.
+    fn consensus_state_heights(
+        &self,
+        client_id: &ClientId,
+        pagination: &Pagination<Height>,
+    ) -> Result<Vec<Height>, Error> {
+        self.consensus_state_heights_after(
+            client_id,
+            pagination.start_after.as_ref(),
+            pagination.page_size(),
         )
     }
 
+    /// Returns at most `limit` heights of the consensus states that the client holds in ascending order.
+    ///
+    /// If `start_after` is Some(h), the heights start from the first one strictly greater than `h`.
+    fn consensus_state_heights_after(
+        &self,
+        client_id: &ClientId,
+        start_after: Option<&Height>,
+        limit: usize,
+    ) -> Result<Vec<Height>, Error> {
+        let path = ClientConsensusStateHeightsPath::new(client_id);
+        let start_after = start_after.map(|h| path.key(h));
+        self.scan_prefix(path.to_string().as_bytes(), start_after.as_deref(), limit)
+            .into_iter()
+            .map(|(key, _)| {
+                path.parse_key(&key).ok_or_else(|| {
+                    Error::invalid_consensus_state_height_key(client_id.clone(), key)
+                })
+            })
+            .collect()
+    }
+
+    /// Returns at most `limit` identifiers of the clients in ascending order of the keys.
+    ///
+    /// If `start_after` is Some(id), the identifiers start from the client next to `id`.
+    /// The keys under `clients/{client_id}/` are contiguous in the key order,
+    /// so this skips over the keys of each client and reads only one key per client.
+    fn client_ids(
+        &self,
+        start_after: Option<&ClientId>,
+        limit: usize,
+    ) -> Result<Vec<ClientId>, Error> {
+        let prefix = CLIENTS_PREFIX.as_bytes();
+        let skip_client_keys = |client_id: &[u8]| {
+            let mut key = [prefix, client_id, b"/"].concat();
+            key.push(u8::MAX);
+            key
+        };
+        let mut client_ids = Vec::new();
+        let mut start_after = start_after.map(|client_id| skip_client_keys(client_id.as_bytes()));
+        while client_ids.len() < limit {
+            let key = match self.scan_prefix(prefix, start_after.as_deref(), 1).pop() {
+                Some((key, _)) => key,
+                None => break,
+            };
+            let id = match key[prefix.len()..].iter().position(|b| *b == b'/') {
+                Some(id_len) => key[prefix.len()..prefix.len() + id_len].to_vec(),
+                None => {
+                    start_after = Some(key);
+                    continue;
+                }
+            };
+            // skip the remaining keys of the client
+            start_after = Some(skip_client_keys(&id));
+
+            let client_id = match core::str::from_utf8(&id)
+                .ok()
+                .and_then(|s| ClientId::from_str(s).ok())
+            {
+                Some(client_id) => client_id,
+                None => continue,
+            };
+            if self.client_type(&client_id).is_ok() {
+                client_ids.push(client_id);
+            }
+        }
+        Ok(client_ids)
+    }
+
     /// Returns a natural number, counting how many clients have been created thus far.
     /// The value of this counter should increase only via method `ClientKeeper::increase_client_counter`.
     fn client_counter(&self) -> Result<u64, Error> {
@@ -104,6 +214,28 @@ pub trait ClientKeeper: ClientReader {
             bincode::serde::encode_to_vec(&consensus_state, bincode::config::standard()).unwrap();
         let path = ClientConsensusStatePath::new(&client_id, &height);
         self.set(format!("{}", path).into_bytes(), bz);
+
+        // update the height index
+        self.set(
+            ClientConsensusStateHeightsPath::new(&client_id).key(&height),
+            vec![],
+        );
+        Ok(())
+    }
+
+    /// Called upon pruning the consensus states.
+    /// Deletes the consensus states at the given heights and removes them from the height index.
+    fn delete_consensus_states(
+        &mut self,
+        client_id: &ClientId,
+        heights: &[Height],
+    ) -> Result<(), Error> {
+        let index = ClientConsensusStateHeightsPath::new(client_id);
+        for height in heights {
+            let path = ClientConsensusStatePath::new(client_id, height);
+            self.remove(format!("{}", path).as_bytes());
+            self.remove(&index.key(height));
+        }
         Ok(())
     }
 
@@ -122,3 +254,132 @@ pub trait ClientKeeper: ClientReader {
 pub trait HostClientReader: HostContext + ClientReader {}
 
 pub trait HostClientKeeper: HostContext + ClientKeeper {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ErrorDetail;
+    use crate::mock::MockContext;
+    use crate::types::MAX_PAGE_LIMIT;
+
+    #[test]
+    fn test_find_consensus_state() {
+        let mut ctx = MockContext::new(Time::unix_epoch());
+        let client_id = ClientId::new("testclient", 0).unwrap();
+        let any = Any::new("/test.ConsensusState".into(), vec![1, 2, 3]);
+        ctx.store_any_consensus_state(client_id.clone(), Height::new(0, 1), any.clone())
+            .unwrap();
+
+        // found
+        assert_eq!(
+            ctx.find_consensus_state(&client_id, &Height::new(0, 1))
+                .unwrap(),
+            Some(any.clone())
+        );
+        assert_eq!(
+            ctx.consensus_state(&client_id, &Height::new(0, 1)).unwrap(),
+            any
+        );
+
+        // not found
+        assert_eq!(
+            ctx.find_consensus_state(&client_id, &Height::new(0, 2))
+                .unwrap(),
+            None
+        );
+        assert!(matches!(
+            ctx.consensus_state(&client_id, &Height::new(0, 2))
+                .unwrap_err()
+                .detail(),
+            ErrorDetail::ConsensusStateNotFound(_)
+        ));
+
+        // the stored state cannot be decoded
+        ctx.set(
+            format!(
+                "{}",
+                ClientConsensusStatePath::new(&client_id, &Height::new(0, 3))
+            )
+            .into_bytes(),
+            vec![0xff; 4],
+        );
+        assert!(matches!(
+            ctx.find_consensus_state(&client_id, &Height::new(0, 3))
+                .unwrap_err()
+                .detail(),
+            ErrorDetail::InvalidConsensusState(_)
+        ));
+        assert!(matches!(
+            ctx.consensus_state(&client_id, &Height::new(0, 3))
+                .unwrap_err()
+                .detail(),
+            ErrorDetail::InvalidConsensusState(_)
+        ));
+    }
+
+    #[test]
+    fn test_consensus_state_heights() {
+        let mut ctx = MockContext::new(Time::unix_epoch());
+        let client_id = ClientId::new("testclient", 0).unwrap();
+        let n = MAX_PAGE_LIMIT + 10;
+        for i in 1..=n {
+            ctx.store_any_consensus_state(
+                client_id.clone(),
+                Height::new(0, i),
+                Any::new("/test.ConsensusState".into(), vec![]),
+            )
+            .unwrap();
+        }
+        let heights = |start_after: Option<u64>, limit: u64| {
+            ctx.consensus_state_heights(
+                &client_id,
+                &Pagination::new(start_after.map(|h| Height::new(0, h)), limit),
+            )
+            .unwrap()
+        };
+        let range = |from: u64, to: u64| (from..=to).map(|h| Height::new(0, h)).collect::<Vec<_>>();
+
+        // the page size is bounded by MAX_PAGE_LIMIT
+        assert_eq!(heights(None, 0), range(1, MAX_PAGE_LIMIT));
+        assert_eq!(heights(None, n), range(1, MAX_PAGE_LIMIT));
+        assert_eq!(
+            heights(Some(MAX_PAGE_LIMIT), 0),
+            range(MAX_PAGE_LIMIT + 1, n)
+        );
+
+        assert_eq!(heights(None, 2), range(1, 2));
+        assert_eq!(heights(Some(2), 2), range(3, 4));
+        assert!(heights(Some(n), 2).is_empty());
+    }
+
+    #[test]
+    fn test_client_ids() {
+        let mut ctx = MockContext::new(Time::unix_epoch());
+        let client_ids: Vec<ClientId> = (0..3)
+            .map(|i| ClientId::new("testclient", i).unwrap())
+            .collect();
+        for client_id in client_ids.iter() {
+            ctx.store_client_type(client_id.clone(), "testclient".into())
+                .unwrap();
+            ctx.store_any_client_state(
+                client_id.clone(),
+                Any::new("/test.ClientState".into(), vec![]),
+            )
+            .unwrap();
+            ctx.store_any_consensus_state(
+                client_id.clone(),
+                Height::new(0, 1),
+                Any::new("/test.ConsensusState".into(), vec![]),
+            )
+            .unwrap();
+        }
+
+        assert_eq!(ctx.client_ids(None, 10).unwrap(), client_ids);
+        assert_eq!(ctx.client_ids(None, 2).unwrap(), client_ids[..2].to_vec());
+        assert_eq!(
+            ctx.client_ids(Some(&client_ids[1]), 2).unwrap(),
+            client_ids[2..].to_vec()
+        );
+        assert!(ctx.client_ids(Some(&client_ids[2]), 2).unwrap().is_empty());
+    }
+}
@@ -34,6 +34,34 @@ define_error! {
             format_args!("consensus_state not found: client_id={} height={}", e.client_id, e.height)
         },
 
+        InvalidConsensusState
+        {
+            client_id: ClientId,
+            height: Height,
+            descr: String
+        }
+        |e| {
+            format_args!("invalid consensus_state: client_id={} height={} descr={}", e.client_id, e.height, e.descr)
+        },
+
+        InvalidConsensusStateHeightKey
+        {
+            client_id: ClientId,
+            key: Vec<u8>
+        }
+        |e| {
+            format_args!("invalid consensus state height key: client_id={} key={:?}", e.client_id, e.key)
+        },
+
+        InvalidConsensusStateKey
+        {
+            client_id: ClientId,
+            key: Vec<u8>
+        }
+        |e| {
+            format_args!("invalid consensus state key: client_id={} key={:?}", e.client_id, e.key)
+        },
+
         LightClientSpecific
         [TraceError<Box<dyn LightClientSpecificError>>]
         |_| { "Light Client specific error" }
@@ -23,15 +23,24 @@ mod prelude {
 pub use commitments;
 pub use lcp_types as types;
 
-pub use client::{CreateClientResult, LightClient, StateVerificationResult, UpdateClientResult};
+pub use client::{
+    CreateClientResult, LightClient, MisbehaviourResult, RecoverClientResult,
+    StateVerificationResult, UpdateClientResult, UpgradeClientResult,
+};
 pub use context::{ClientKeeper, ClientReader, HostClientKeeper, HostClientReader, HostContext};
 pub use errors::{Error, ErrorDetail, LightClientSpecificError, RegistryError};
-pub use registry::{LightClientRegistry, LightClientResolver, MapLightClientRegistry};
+pub use prune::{prunable_consensus_state_heights, prunable_consensus_state_heights_from_keys};
+pub use registry::{
+    LightClientInfo, LightClientRegistry, LightClientResolver, MapLightClientRegistry,
+};
 
 mod client;
 mod context;
 mod errors;
 #[cfg(feature = "ibc")]
 pub mod ibc;
+#[cfg(test)]
+mod mock;
 mod path;
+mod prune;
 mod registry;
@@ -0,0 +1,190 @@
+use crate::client::{
+    CreateClientResult, LightClient, MisbehaviourResult, RecoverClientResult,
+    StateVerificationResult, UpdateClientResult, UpgradeClientResult,
+};
+use crate::commitments::CommitmentPrefix;
+use crate::context::{ClientKeeper, ClientReader, HostClientReader, HostContext};
+use crate::errors::Error;
+use crate::prelude::*;
+use crate::types::{Any, ClientId, ClientStatus, Height, Time};
+use core::time::Duration;
+use store::memory::MemStore;
+use store::{KVStore, KV};
+
+/// MockContext is a context backed by the in-memory store for testing
+pub(crate) struct MockContext {
+    store: MemStore,
+    timestamp: Time,
+}
+
+impl MockContext {
+    pub fn new(timestamp: Time) -> Self {
+        Self {
+            store: MemStore::default(),
+            timestamp,
+        }
+    }
+}
+
+impl KVStore for MockContext {
+    fn set(&mut self, key: Vec<u8>, value: Vec<u8>) {
+        self.store.set(key, value)
+    }
+
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.store.get(key)
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.store.remove(key)
+    }
+
+    fn scan_prefix(&self, prefix: &[u8], start_after: Option<&[u8]>, limit: usize) -> Vec<KV> {
+        self.store.scan_prefix(prefix, start_after, limit)
+    }
+}
+
+impl HostContext for MockContext {
+    fn host_timestamp(&self) -> Time {
+        self.timestamp
+    }
+}
+
+impl ClientReader for MockContext {}
+impl ClientKeeper for MockContext {}
+impl HostClientReader for MockContext {}
+
+/// MockLightClient reads the timestamp of each consensus state from its value, which is the big-endian unix timestamp in seconds
+pub(crate) struct MockLightClient {
+    client_type: String,
+    latest_height: Height,
+    trusting_period: Option<Duration>,
+}
+
+impl MockLightClient {
+    pub fn new(
+        client_type: &str,
+        latest_height: Height,
+        trusting_period: Option<Duration>,
+    ) -> Self {
+        Self {
+            client_type: client_type.to_string(),
+            latest_height,
+            trusting_period,
+        }
+    }
+}
+
+impl LightClient for MockLightClient {
+    fn client_type(&self) -> String {
+        self.client_type.clone()
+    }
+
+    fn latest_height(
+        &self,
+        _ctx: &dyn HostClientReader,
+        _client_id: &ClientId,
+    ) -> Result<Height, Error> {
+        Ok(self.latest_height)
+    }
+
+    fn status(
+        &self,
+        _ctx: &dyn HostClientReader,
+        _client_id: &ClientId,
+    ) -> Result<ClientStatus, Error> {
+        Ok(ClientStatus::Active)
+    }
+
+    fn consensus_state_timestamp(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Time, Error> {
+        let any = ctx.consensus_state(client_id, height)?;
+        let secs = u64::from_be_bytes(any.value.as_slice().try_into().unwrap());
+        Ok(Time::from_unix_timestamp_nanos(secs as u128 * 1_000_000_000).unwrap())
+    }
+
+    fn trusting_period(
+        &self,
+        _ctx: &dyn HostClientReader,
+        _client_id: &ClientId,
+    ) -> Result<Option<Duration>, Error> {
+        Ok(self.trusting_period)
+    }
+
+    fn create_client(
+        &self,
+        _ctx: &dyn HostClientReader,
+        _any_client_state: Any,
+        _any_consensus_state: Any,
+    ) -> Result<CreateClientResult, Error> {
+        unimplemented!()
+    }
+
+    fn update_client(
+        &self,
+        _ctx: &dyn HostClientReader,
+        _client_id: ClientId,
+        _any_header: Any,
+    ) -> Result<UpdateClientResult, Error> {
+        unimplemented!()
+    }
+
+    fn submit_misbehaviour(
+        &self,
+        _ctx: &dyn HostClientReader,
+        _client_id: ClientId,
+        _any_misbehaviour: Any,
+    ) -> Result<MisbehaviourResult, Error> {
+        unimplemented!()
+    }
+
+    fn recover_client(
+        &self,
+        _ctx: &dyn HostClientReader,
+        _subject_client_id: ClientId,
+        _substitute_client_id: ClientId,
+    ) -> Result<RecoverClientResult, Error> {
+        unimplemented!()
+    }
+
+    fn upgrade_client(
+        &self,
+        _ctx: &dyn HostClientReader,
+        _client_id: ClientId,
+        _upgraded_any_client_state: Any,
+        _upgraded_any_consensus_state: Any,
+        _proof_upgrade_client: Vec<u8>,
+        _proof_upgrade_consensus_state: Vec<u8>,
+    ) -> Result<UpgradeClientResult, Error> {
+        unimplemented!()
+    }
+
+    fn verify_membership(
+        &self,
+        _ctx: &dyn HostClientReader,
+        _client_id: ClientId,
+        _prefix: CommitmentPrefix,
+        _path: String,
+        _value: Vec<u8>,
+        _proof_height: Height,
+        _proof: Vec<u8>,
+    ) -> Result<StateVerificationResult, Error> {
+        unimplemented!()
+    }
+
+    fn verify_non_membership(
+        &self,
+        _ctx: &dyn HostClientReader,
+        _client_id: ClientId,
+        _prefix: CommitmentPrefix,
+        _path: String,
+        _proof_height: Height,
+        _proof: Vec<u8>,
+    ) -> Result<StateVerificationResult, Error> {
+        unimplemented!()
+    }
+}
@@ -3,6 +3,9 @@ use derive_more::Display;
 
 pub static NEXT_CLIENT_SEQUENCE: &str = "nextClientSequence";
 
+/// The prefix of the paths under which each client stores its states
+pub static CLIENTS_PREFIX: &str = "clients/";
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display(fmt = "clients/{_0}/clientType")]
 pub struct ClientTypePath(pub ClientId);
@@ -23,6 +26,41 @@ impl ClientStatePath {
     }
 }
 
+/// The prefix of the height index of the consensus states
+///
+/// Each height is stored as a separate key: the prefix followed by the big-endian revision number and revision height,
+/// so that the keys are ordered by the height.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "clients/{_0}/consensusStateHeights/")]
+pub struct ClientConsensusStateHeightsPath(pub ClientId);
+
+impl ClientConsensusStateHeightsPath {
+    pub fn new(client_id: &ClientId) -> ClientConsensusStateHeightsPath {
+        ClientConsensusStateHeightsPath(client_id.clone())
+    }
+
+    /// key returns the index key of the height
+    pub fn key(&self, height: &Height) -> Vec<u8> {
+        let mut key = self.to_string().into_bytes();
+        key.extend(height.revision_number().to_be_bytes());
+        key.extend(height.revision_height().to_be_bytes());
+        key
+    }
+
+    /// parse_key returns the height of the index key, or None if the key is not an index key of the client
+    pub fn parse_key(&self, key: &[u8]) -> Option<Height> {
+        let suffix = key.strip_prefix(self.to_string().as_bytes())?;
+        if suffix.len() != 16 {
+            return None;
+        }
+        let (revision_number, revision_height) = suffix.split_at(8);
+        Some(Height::new(
+            u64::from_be_bytes(revision_number.try_into().ok()?),
+            u64::from_be_bytes(revision_height.try_into().ok()?),
+        ))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display(fmt = "clients/{client_id}/consensusStates/{epoch}-{height}")]
 pub struct ClientConsensusStatePath {
@@ -40,3 +78,21 @@ impl ClientConsensusStatePath {
         }
     }
 }
+
+/// The prefix of the paths of the consensus states of the client
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "clients/{_0}/consensusStates/")]
+pub struct ClientConsensusStatesPrefix(pub ClientId);
+
+impl ClientConsensusStatesPrefix {
+    pub fn new(client_id: &ClientId) -> ClientConsensusStatesPrefix {
+        ClientConsensusStatesPrefix(client_id.clone())
+    }
+
+    /// parse_key returns the height of the consensus state key, or None if the key is not a consensus state key of the client
+    pub fn parse_key(&self, key: &[u8]) -> Option<Height> {
+        let suffix = key.strip_prefix(self.to_string().as_bytes())?;
+        let (epoch, height) = core::str::from_utf8(suffix).ok()?.split_once('-')?;
+        Some(Height::new(epoch.parse().ok()?, height.parse().ok()?))
+    }
+}
@@ -0,0 +1,462 @@
+use crate::client::LightClient;
+use crate::context::HostClientReader;
+use crate::errors::Error;
+use crate::path::ClientConsensusStatesPrefix;
+use crate::prelude::*;
+use crate::types::{ClientId, Height};
+use core::time::Duration;
+
+/// The number of the heights read from the height index at once
+const CONSENSUS_STATE_HEIGHTS_PAGE_SIZE: usize = 16;
+
+/// The number of the consensus state keys read from the store at once
+const CONSENSUS_STATE_KEYS_PAGE_SIZE: usize = 100;
+
+/// `prunable_consensus_state_heights` returns the heights of the consensus states that can be pruned.
+///
+/// A consensus state is prunable if it is older than `retention_period` at the host timestamp.
+/// If `retention_period` is None, the trusting period of the client is used instead.
+/// The consensus state at the latest height of the client is still referenced by the client state, so it is never pruned.
+///
+/// The heights are read from the height index page by page in ascending order,
+/// and the check stops at the first consensus state that is not prunable or when `limit` heights are found.
+/// So the cost is proportional to the number of the checked states rather than the number of the stored states.
+pub fn prunable_consensus_state_heights(
+    ctx: &dyn HostClientReader,
+    lc: &dyn LightClient,
+    client_id: &ClientId,
+    retention_period: Option<Duration>,
+    limit: usize,
+) -> Result<Vec<Height>, Error> {
+    let mut page = Vec::new().into_iter();
+    let mut start_after: Option<Height> = None;
+    let mut is_last = false;
+    let heights = core::iter::from_fn(|| loop {
+        if let Some(height) = page.next() {
+            start_after = Some(height);
+            return Some(Ok(height));
+        }
+        if is_last {
+            return None;
+        }
+        match ctx.consensus_state_heights_after(
+            client_id,
+            start_after.as_ref(),
+            CONSENSUS_STATE_HEIGHTS_PAGE_SIZE,
+        ) {
+            Ok(heights) => {
+                is_last = heights.len() < CONSENSUS_STATE_HEIGHTS_PAGE_SIZE;
+                page = heights.into_iter();
+            }
+            Err(e) => {
+                is_last = true;
+                return Some(Err(e));
+            }
+        }
+    });
+    select_prunable_heights(ctx, lc, client_id, retention_period, limit, heights)
+}
+
+/// `prunable_consensus_state_heights_from_keys` returns the heights of the consensus states that can be pruned
+/// as `prunable_consensus_state_heights` does, but reads all the consensus state keys of the client instead of the height index.
+///
+/// This finds the consensus states stored before the height index was introduced,
+/// so it is used by the explicit pruning command rather than on each update of the client.
+pub fn prunable_consensus_state_heights_from_keys(
+    ctx: &dyn HostClientReader,
+    lc: &dyn LightClient,
+    client_id: &ClientId,
+    retention_period: Option<Duration>,
+    limit: usize,
+) -> Result<Vec<Height>, Error> {
+    let heights = stored_consensus_state_heights(ctx, client_id)?;
+    select_prunable_heights(
+        ctx,
+        lc,
+        client_id,
+        retention_period,
+        limit,
+        heights.into_iter().map(Ok),
+    )
+}
+
+/// `select_prunable_heights` returns the leading heights of `heights` which are prunable.
+///
+/// `heights` must be in ascending order.
+fn select_prunable_heights(
+    ctx: &dyn HostClientReader,
+    lc: &dyn LightClient,
+    client_id: &ClientId,
+    retention_period: Option<Duration>,
+    limit: usize,
+    mut heights: impl Iterator<Item = Result<Height, Error>>,
+) -> Result<Vec<Height>, Error> {
+    let retention_period = match retention_period {
+        Some(period) => period,
+        None => match lc.trusting_period(ctx, client_id)? {
+            Some(period) => period,
+            None => return Ok(vec![]),
+        },
+    };
+    // if the threshold is before the unix epoch, no consensus state is prunable
+    let threshold = match ctx.host_timestamp() - retention_period {
+        Ok(threshold) => threshold,
+        Err(_) => return Ok(vec![]),
+    };
+    let latest_height = lc.latest_height(ctx, client_id)?;
+    let mut prunable = Vec::new();
+    while prunable.len() < limit {
+        let height = match heights.next() {
+            Some(height) => height?,
+            None => break,
+        };
+        if height >= latest_height {
+            break;
+        }
+        if lc.consensus_state_timestamp(ctx, client_id, &height)? > threshold {
+            break;
+        }
+        prunable.push(height);
+    }
+    Ok(prunable)
+}
+
+/// `stored_consensus_state_heights` returns the heights of all the consensus states stored for the client in ascending order.
+///
+/// The heights are read from the consensus state keys themselves, so the result does not depend on the height index.
+/// The keys are not ordered by the height, so the heights are sorted after all the keys are read.
+fn stored_consensus_state_heights(
+    ctx: &dyn HostClientReader,
+    client_id: &ClientId,
+) -> Result<Vec<Height>, Error> {
+    let prefix = ClientConsensusStatesPrefix::new(client_id);
+    let prefix_bytes = prefix.to_string().into_bytes();
+    let mut heights = Vec::new();
+    let mut start_after: Option<Vec<u8>> = None;
+    loop {
+        let page = ctx.scan_prefix(
+            &prefix_bytes,
+            start_after.as_deref(),
+            CONSENSUS_STATE_KEYS_PAGE_SIZE,
+        );
+        let is_last = page.len() < CONSENSUS_STATE_KEYS_PAGE_SIZE;
+        for (key, _) in page {
+            match prefix.parse_key(&key) {
+                Some(height) => heights.push(height),
+                None => return Err(Error::invalid_consensus_state_key(client_id.clone(), key)),
+            }
+            start_after = Some(key);
+        }
+        if is_last {
+            break;
+        }
+    }
+    heights.sort();
+    Ok(heights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::{ClientKeeper, ClientReader};
+    use crate::mock::{MockContext, MockLightClient};
+    use crate::path::ClientConsensusStateHeightsPath;
+    use crate::types::{Any, Time};
+    use store::KVStore;
+
+    fn timestamp(secs: u64) -> Time {
+        Time::from_unix_timestamp_nanos(secs as u128 * 1_000_000_000).unwrap()
+    }
+
+    fn client_id() -> ClientId {
+        ClientId::new("testclient", 0).unwrap()
+    }
+
+    /// setup stores the consensus states at the given heights with the given timestamps in seconds
+    fn setup(host_secs: u64, states: &[(Height, u64)]) -> MockContext {
+        let mut ctx = MockContext::new(timestamp(host_secs));
+        for (height, secs) in states {
+            ctx.store_any_consensus_state(
+                client_id(),
+                *height,
+                Any::new("/test.ConsensusState".into(), secs.to_be_bytes().to_vec()),
+            )
+            .unwrap();
+        }
+        ctx
+    }
+
+    fn test_light_client(latest_height: Height, trusting_period: Option<u64>) -> MockLightClient {
+        MockLightClient::new(
+            "test",
+            latest_height,
+            trusting_period.map(Duration::from_secs),
+        )
+    }
+
+    fn h(revision_height: u64) -> Height {
+        Height::new(0, revision_height)
+    }
+
+    #[test]
+    fn test_prune_by_retention_period() {
+        let ctx = setup(
+            100,
+            &[(h(1), 10), (h(2), 20), (h(3), 30), (h(4), 40), (h(5), 50)],
+        );
+        let lc = test_light_client(h(5), None);
+
+        // threshold = 100 - 65 = 35
+        let heights = prunable_consensus_state_heights(
+            &ctx,
+            &lc,
+            &client_id(),
+            Some(Duration::from_secs(65)),
+            usize::MAX,
+        )
+        .unwrap();
+        assert_eq!(heights, vec![h(1), h(2), h(3)]);
+
+        // the state at the threshold is prunable
+        let heights = prunable_consensus_state_heights(
+            &ctx,
+            &lc,
+            &client_id(),
+            Some(Duration::from_secs(70)),
+            usize::MAX,
+        )
+        .unwrap();
+        assert_eq!(heights, vec![h(1), h(2), h(3)]);
+
+        // the threshold is before the unix epoch
+        let heights = prunable_consensus_state_heights(
+            &ctx,
+            &lc,
+            &client_id(),
+            Some(Duration::from_secs(101)),
+            usize::MAX,
+        )
+        .unwrap();
+        assert!(heights.is_empty());
+    }
+
+    #[test]
+    fn test_prune_by_trusting_period() {
+        let ctx = setup(
+            100,
+            &[(h(1), 10), (h(2), 20), (h(3), 30), (h(4), 40), (h(5), 50)],
+        );
+
+        let lc = test_light_client(h(5), Some(75));
+        let heights =
+            prunable_consensus_state_heights(&ctx, &lc, &client_id(), None, usize::MAX).unwrap();
+        assert_eq!(heights, vec![h(1), h(2)]);
+
+        // the client has no trusting period, so nothing is pruned without the retention period
+        let lc = test_light_client(h(5), None);
+        let heights =
+            prunable_consensus_state_heights(&ctx, &lc, &client_id(), None, usize::MAX).unwrap();
+        assert!(heights.is_empty());
+
+        // the retention period takes precedence over the trusting period
+        let lc = test_light_client(h(5), Some(75));
+        let heights = prunable_consensus_state_heights(
+            &ctx,
+            &lc,
+            &client_id(),
+            Some(Duration::from_secs(55)),
+            usize::MAX,
+        )
+        .unwrap();
+        assert_eq!(heights, vec![h(1), h(2), h(3), h(4)]);
+    }
+
+    #[test]
+    fn test_prune_keeps_latest_height() {
+        let ctx = setup(100, &[(h(1), 10), (h(2), 20), (h(3), 30)]);
+        let lc = test_light_client(h(3), None);
+        let heights = prunable_consensus_state_heights(
+            &ctx,
+            &lc,
+            &client_id(),
+            Some(Duration::from_secs(1)),
+            usize::MAX,
+        )
+        .unwrap();
+        assert_eq!(heights, vec![h(1), h(2)]);
+
+        // the states above the latest height are kept too
+        let lc = test_light_client(h(2), None);
+        let heights = prunable_consensus_state_heights(
+            &ctx,
+            &lc,
+            &client_id(),
+            Some(Duration::from_secs(1)),
+            usize::MAX,
+        )
+        .unwrap();
+        assert_eq!(heights, vec![h(1)]);
+    }
+
+    #[test]
+    fn test_prune_limit() {
+        let ctx = setup(100, &[(h(1), 10), (h(2), 20), (h(3), 30), (h(4), 40)]);
+        let lc = test_light_client(h(4), None);
+        for (limit, expected) in [
+            (0, vec![]),
+            (1, vec![h(1)]),
+            (2, vec![h(1), h(2)]),
+            (10, vec![h(1), h(2), h(3)]),
+        ] {
+            let heights = prunable_consensus_state_heights(
+                &ctx,
+                &lc,
+                &client_id(),
+                Some(Duration::from_secs(1)),
+                limit,
+            )
+            .unwrap();
+            assert_eq!(heights, expected, "limit={}", limit);
+        }
+    }
+
+    #[test]
+    fn test_prune_in_height_order() {
+        // the key order of these heights differs from the height order
+        let ctx = setup(
+            1000,
+            &[
+                (Height::new(1, 10), 40),
+                (Height::new(1, 9), 30),
+                (Height::new(0, 100), 10),
+                (Height::new(1, 2), 20),
+                (Height::new(1, 100), 500),
+                (Height::new(1, 11), 50),
+            ],
+        );
+        let lc = test_light_client(Height::new(1, 100), None);
+        let heights = prunable_consensus_state_heights(
+            &ctx,
+            &lc,
+            &client_id(),
+            Some(Duration::from_secs(955)),
+            usize::MAX,
+        )
+        .unwrap();
+        assert_eq!(
+            heights,
+            vec![
+                Height::new(0, 100),
+                Height::new(1, 2),
+                Height::new(1, 9),
+                Height::new(1, 10)
+            ]
+        );
+
+        // the check stops at the first state that is not prunable
+        let ctx = setup(100, &[(h(1), 10), (h(2), 90), (h(3), 20), (h(4), 90)]);
+        let lc = test_light_client(h(4), None);
+        let heights = prunable_consensus_state_heights(
+            &ctx,
+            &lc,
+            &client_id(),
+            Some(Duration::from_secs(50)),
+            usize::MAX,
+        )
+        .unwrap();
+        assert_eq!(heights, vec![h(1)]);
+    }
+
+    #[test]
+    fn test_prune_across_pages() {
+        let n = CONSENSUS_STATE_KEYS_PAGE_SIZE as u64 * 2 + 1;
+        assert!(n > CONSENSUS_STATE_HEIGHTS_PAGE_SIZE as u64 * 2);
+        let states: Vec<(Height, u64)> = (1..=n).map(|i| (h(i), i)).collect();
+        let ctx = setup(n * 2, &states);
+        let lc = test_light_client(h(n), None);
+        let expected = (1..=n - 10).map(h).collect::<Vec<_>>();
+        let heights = prunable_consensus_state_heights(
+            &ctx,
+            &lc,
+            &client_id(),
+            Some(Duration::from_secs(n + 10)),
+            usize::MAX,
+        )
+        .unwrap();
+        assert_eq!(heights, expected);
+        let heights = prunable_consensus_state_heights_from_keys(
+            &ctx,
+            &lc,
+            &client_id(),
+            Some(Duration::from_secs(n + 10)),
+            usize::MAX,
+        )
+        .unwrap();
+        assert_eq!(heights, expected);
+    }
+
+    #[test]
+    fn test_prune_states_without_height_index() {
+        let mut ctx = setup(100, &[(h(1), 10), (h(2), 20), (h(3), 30)]);
+        // the state at h(1) is stored before the height index was introduced
+        ctx.remove(&ClientConsensusStateHeightsPath::new(&client_id()).key(&h(1)));
+        let lc = test_light_client(h(3), None);
+
+        let heights = prunable_consensus_state_heights(
+            &ctx,
+            &lc,
+            &client_id(),
+            Some(Duration::from_secs(1)),
+            usize::MAX,
+        )
+        .unwrap();
+        assert_eq!(heights, vec![h(2)]);
+
+        // the states not in the height index are found by reading the consensus state keys
+        let heights = prunable_consensus_state_heights_from_keys(
+            &ctx,
+            &lc,
+            &client_id(),
+            Some(Duration::from_secs(1)),
+            usize::MAX,
+        )
+        .unwrap();
+        assert_eq!(heights, vec![h(1), h(2)]);
+    }
+
+    #[test]
+    fn test_invalid_consensus_state_key() {
+        let mut ctx = setup(100, &[(h(1), 10)]);
+        ctx.set(
+            format!("{}invalid", ClientConsensusStatesPrefix::new(&client_id())).into_bytes(),
+            vec![],
+        );
+        let lc = test_light_client(h(1), None);
+        assert!(prunable_consensus_state_heights_from_keys(
+            &ctx,
+            &lc,
+            &client_id(),
+            Some(Duration::from_secs(1)),
+            usize::MAX,
+        )
+        .is_err());
+
+        let mut ctx = setup(100, &[(h(1), 10)]);
+        ctx.set(
+            format!(
+                "{}invalid",
+                ClientConsensusStateHeightsPath::new(&client_id())
+            )
+            .into_bytes(),
+            vec![],
+        );
+        assert!(prunable_consensus_state_heights(
+            &ctx,
+            &lc,
+            &client_id(),
+            Some(Duration::from_secs(1)),
+            usize::MAX,
+        )
+        .is_err());
+    }
+}
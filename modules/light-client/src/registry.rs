@@ -10,22 +10,62 @@ pub trait LightClientRegistry: LightClientResolver {
         client_state_type_url: String,
         lc: Box<dyn LightClient>,
     ) -> Result<(), RegistryError>;
+
+    /// put_type_url_alias registers `alias_type_url` as another type URL of the registered light client
+    ///
+    /// This allows clients stored with a renamed or versioned type URL to be resolved.
+    fn put_type_url_alias(
+        &mut self,
+        alias_type_url: String,
+        client_state_type_url: String,
+    ) -> Result<(), RegistryError>;
 }
 
 pub trait LightClientResolver {
     #[allow(clippy::borrowed_box)]
     fn get_light_client(&self, type_url: &str) -> Option<&Box<dyn LightClient>>;
+
+    #[allow(clippy::borrowed_box)]
+    fn get_light_client_by_client_type(&self, client_type: &str) -> Option<&Box<dyn LightClient>>;
+
+    /// resolve_type_url returns the registered type URL for the given registered type URL or alias
+    fn resolve_type_url(&self, type_url: &str) -> Option<&str>;
+
+    /// list_light_clients returns the registered light clients in ascending order of the type URL
+    fn list_light_clients(&self) -> Vec<LightClientInfo>;
 }
 
 impl LightClientResolver for Arc<dyn LightClientResolver> {
     fn get_light_client(&self, type_url: &str) -> Option<&Box<dyn LightClient>> {
         self.as_ref().get_light_client(type_url)
     }
+
+    fn get_light_client_by_client_type(&self, client_type: &str) -> Option<&Box<dyn LightClient>> {
+        self.as_ref().get_light_client_by_client_type(client_type)
+    }
+
+    fn resolve_type_url(&self, type_url: &str) -> Option<&str> {
+        self.as_ref().resolve_type_url(type_url)
+    }
+
+    fn list_light_clients(&self) -> Vec<LightClientInfo> {
+        self.as_ref().list_light_clients()
+    }
+}
+
+/// `LightClientInfo` is a summary of a registered light client
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LightClientInfo {
+    pub client_type: String,
+    /// the registered type URL comes first, followed by its aliases
+    pub client_state_type_urls: Vec<String>,
 }
 
 #[derive(Default)]
 pub struct MapLightClientRegistry {
     registry: BTreeMap<String, Box<dyn LightClient>>,
+    /// alias type URL => registered type URL
+    aliases: BTreeMap<String, String>,
     sealed: bool,
 }
 
@@ -61,10 +101,179 @@ impl LightClientRegistry for MapLightClientRegistry {
             Ok(())
         }
     }
+
+    fn put_type_url_alias(
+        &mut self,
+        alias_type_url: String,
+        client_state_type_url: String,
+    ) -> Result<(), RegistryError> {
+        assert!(!self.sealed);
+        if self.get_light_client(&alias_type_url).is_some() {
+            Err(RegistryError::type_url_already_exists(alias_type_url))
+        } else if !self.registry.contains_key(&client_state_type_url) {
+            Err(RegistryError::type_url_not_found(client_state_type_url))
+        } else {
+            self.aliases.insert(alias_type_url, client_state_type_url);
+            Ok(())
+        }
+    }
 }
 
 impl LightClientResolver for MapLightClientRegistry {
     fn get_light_client(&self, client_state_type_url: &str) -> Option<&Box<dyn LightClient>> {
-        self.registry.get(client_state_type_url)
+        self.registry
+            .get(self.resolve_type_url(client_state_type_url)?)
+    }
+
+    fn get_light_client_by_client_type(&self, client_type: &str) -> Option<&Box<dyn LightClient>> {
+        self.registry
+            .values()
+            .find(|lc| lc.client_type() == client_type)
+    }
+
+    fn resolve_type_url(&self, client_state_type_url: &str) -> Option<&str> {
+        let type_url = self
+            .aliases
+            .get(client_state_type_url)
+            .map_or(client_state_type_url, String::as_str);
+        self.registry
+            .get_key_value(type_url)
+            .map(|(type_url, _)| type_url.as_str())
+    }
+
+    fn list_light_clients(&self) -> Vec<LightClientInfo> {
+        self.registry
+            .iter()
+            .map(|(type_url, lc)| {
+                let mut client_state_type_urls = vec![type_url.clone()];
+                client_state_type_urls.extend(
+                    self.aliases
+                        .iter()
+                        .filter(|(_, t)| *t == type_url)
+                        .map(|(alias, _)| alias.clone()),
+                );
+                LightClientInfo {
+                    client_type: lc.client_type(),
+                    client_state_type_urls,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockLightClient;
+    use crate::types::Height;
+
+    const TYPE_URL_A: &str = "/test.a.v1.ClientState";
+    const TYPE_URL_A_V2: &str = "/test.a.v2.ClientState";
+    const TYPE_URL_B: &str = "/test.b.v1.ClientState";
+
+    fn light_client(client_type: &str) -> Box<dyn LightClient> {
+        Box::new(MockLightClient::new(client_type, Height::new(0, 1), None))
+    }
+
+    fn registry() -> MapLightClientRegistry {
+        let mut registry = MapLightClientRegistry::new();
+        registry
+            .put_light_client(TYPE_URL_B.to_string(), light_client("b"))
+            .unwrap();
+        registry
+            .put_light_client(TYPE_URL_A_V2.to_string(), light_client("a"))
+            .unwrap();
+        registry
+            .put_type_url_alias(TYPE_URL_A.to_string(), TYPE_URL_A_V2.to_string())
+            .unwrap();
+        registry.seal().unwrap();
+        registry
+    }
+
+    #[test]
+    fn test_alias() {
+        let registry = registry();
+        assert_eq!(registry.resolve_type_url(TYPE_URL_A), Some(TYPE_URL_A_V2));
+        assert_eq!(
+            registry.resolve_type_url(TYPE_URL_A_V2),
+            Some(TYPE_URL_A_V2)
+        );
+        assert_eq!(registry.resolve_type_url(TYPE_URL_B), Some(TYPE_URL_B));
+        assert_eq!(registry.resolve_type_url("/test.c.v1.ClientState"), None);
+        assert_eq!(
+            registry.get_light_client(TYPE_URL_A).unwrap().client_type(),
+            "a"
+        );
+        assert_eq!(
+            registry
+                .get_light_client(TYPE_URL_A_V2)
+                .unwrap()
+                .client_type(),
+            "a"
+        );
+        assert!(registry
+            .get_light_client("/test.c.v1.ClientState")
+            .is_none());
+    }
+
+    #[test]
+    fn test_get_light_client_by_client_type() {
+        let registry = registry();
+        for client_type in ["a", "b"] {
+            assert_eq!(
+                registry
+                    .get_light_client_by_client_type(client_type)
+                    .unwrap()
+                    .client_type(),
+                client_type
+            );
+        }
+        assert!(registry.get_light_client_by_client_type("c").is_none());
+    }
+
+    #[test]
+    fn test_list_light_clients() {
+        assert_eq!(
+            registry().list_light_clients(),
+            vec![
+                LightClientInfo {
+                    client_type: "b".to_string(),
+                    client_state_type_urls: vec![TYPE_URL_B.to_string()],
+                },
+                LightClientInfo {
+                    client_type: "a".to_string(),
+                    client_state_type_urls: vec![TYPE_URL_A_V2.to_string(), TYPE_URL_A.to_string()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_type_url() {
+        let mut registry = MapLightClientRegistry::new();
+        registry
+            .put_light_client(TYPE_URL_A_V2.to_string(), light_client("a"))
+            .unwrap();
+        assert!(registry
+            .put_light_client(TYPE_URL_A_V2.to_string(), light_client("b"))
+            .is_err());
+        registry
+            .put_type_url_alias(TYPE_URL_A.to_string(), TYPE_URL_A_V2.to_string())
+            .unwrap();
+        // an alias cannot be registered as another light client, and vice versa
+        assert!(registry
+            .put_light_client(TYPE_URL_A.to_string(), light_client("b"))
+            .is_err());
+        assert!(registry
+            .put_type_url_alias(TYPE_URL_A_V2.to_string(), TYPE_URL_A_V2.to_string())
+            .is_err());
+        // an alias must refer to a registered type URL
+        assert!(registry
+            .put_type_url_alias(TYPE_URL_B.to_string(), "/test.c.v1.ClientState".to_string())
+            .is_err());
+        // a client type can be registered with multiple type URLs
+        registry
+            .put_light_client(TYPE_URL_B.to_string(), light_client("a"))
+            .unwrap();
     }
 }
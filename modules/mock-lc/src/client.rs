@@ -2,6 +2,7 @@ use crate::errors::Error;
 use crate::header::Header;
 use crate::prelude::*;
 use crate::state::{gen_state_id, ClientState, ConsensusState};
+use core::time::Duration;
 use ibc::core::ics02_client::client_state::{
     downcast_client_state, ClientState as Ics02ClientState, UpdatedState,
 };
@@ -10,11 +11,15 @@ use ibc::core::ics02_client::error::ClientError as ICS02Error;
 use ibc::core::ics02_client::header::Header as Ics02Header;
 use ibc::mock::client_state::{client_type, MockClientState, MOCK_CLIENT_STATE_TYPE_URL};
 use ibc::mock::consensus_state::MockConsensusState;
-use light_client::commitments::{gen_state_id_from_any, CommitmentContext, UpdateClientCommitment};
-use light_client::types::{Any, ClientId, Height, Time};
+use light_client::commitments::{
+    gen_state_id_from_any, CommitmentContext, MisbehaviourCommitment, PrevState,
+    UpdateClientCommitment,
+};
+use light_client::types::{Any, ClientId, ClientStatus, Height, Time};
 use light_client::{
     ibc::IBCContext, CreateClientResult, Error as LightClientError, HostClientReader, LightClient,
-    LightClientRegistry, StateVerificationResult, UpdateClientResult,
+    LightClientRegistry, MisbehaviourResult, RecoverClientResult, StateVerificationResult,
+    UpdateClientResult, UpgradeClientResult,
 };
 
 #[derive(Default)]
@@ -34,6 +39,39 @@ impl LightClient for MockLightClient {
         Ok(client_state.latest_height().into())
     }
 
+    fn status(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: &ClientId,
+    ) -> Result<ClientStatus, LightClientError> {
+        let client_state: ClientState = ctx.client_state(client_id)?.try_into()?;
+        // mock client never expires
+        if client_state.is_frozen() {
+            Ok(ClientStatus::Frozen)
+        } else {
+            Ok(ClientStatus::Active)
+        }
+    }
+
+    fn consensus_state_timestamp(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Time, LightClientError> {
+        let consensus_state: ConsensusState = ctx.consensus_state(client_id, height)?.try_into()?;
+        Ok(consensus_state.timestamp().into())
+    }
+
+    fn trusting_period(
+        &self,
+        _: &dyn HostClientReader,
+        _: &ClientId,
+    ) -> Result<Option<Duration>, LightClientError> {
+        // mock client has no trusting period
+        Ok(None)
+    }
+
     fn create_client(
         &self,
         _: &dyn HostClientReader,
@@ -146,6 +184,89 @@ impl LightClient for MockLightClient {
         })
     }
 
+    fn submit_misbehaviour(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: ClientId,
+        any_misbehaviour: Any,
+    ) -> Result<MisbehaviourResult, LightClientError> {
+        // Read client state from the host chain store.
+        let client_state: ClientState = ctx.client_state(&client_id)?.try_into()?;
+
+        if client_state.is_frozen() {
+            return Err(Error::ics02(ICS02Error::ClientFrozen {
+                client_id: client_id.into(),
+            })
+            .into());
+        }
+
+        let latest_height = client_state.latest_height();
+
+        // Read consensus state from the host chain store.
+        let latest_consensus_state: ConsensusState = ctx
+            .consensus_state(&client_id, &latest_height.into())
+            .map_err(|_| {
+                Error::ics02(ICS02Error::ConsensusStateNotFound {
+                    client_id: client_id.clone().into(),
+                    height: latest_height,
+                })
+            })?
+            .try_into()?;
+
+        let new_client_state = client_state
+            .check_misbehaviour_and_update_state(
+                &IBCContext::<MockClientState, MockConsensusState>::new(ctx),
+                client_id.clone().into(),
+                any_misbehaviour.into(),
+            )
+            .map_err(|e| {
+                Error::ics02(ICS02Error::MisbehaviourHandlingFailure {
+                    reason: e.to_string(),
+                })
+            })?;
+        let new_client_state = ClientState(
+            *downcast_client_state::<MockClientState>(new_client_state.as_ref()).unwrap(),
+        );
+        let frozen_height = new_client_state.frozen_height().ok_or_else(|| {
+            Error::ics02(ICS02Error::MisbehaviourHandlingFailure {
+                reason: "client state must be frozen after the misbehaviour".to_string(),
+            })
+        })?;
+
+        let prev_state_id = gen_state_id(client_state, latest_consensus_state)?;
+        Ok(MisbehaviourResult {
+            new_any_client_state: Any::try_from(new_client_state).unwrap(),
+            commitment: MisbehaviourCommitment {
+                client_id,
+                frozen_height: frozen_height.into(),
+                prev_states: vec![PrevState::new(latest_height.into(), prev_state_id)],
+                context: CommitmentContext::Empty,
+            }
+            .into(),
+        })
+    }
+
+    fn recover_client(
+        &self,
+        _: &dyn HostClientReader,
+        _: ClientId,
+        _: ClientId,
+    ) -> Result<RecoverClientResult, LightClientError> {
+        Err(Error::recover_client_not_supported().into())
+    }
+
+    fn upgrade_client(
+        &self,
+        _: &dyn HostClientReader,
+        _: ClientId,
+        _: Any,
+        _: Any,
+        _: Vec<u8>,
+        _: Vec<u8>,
+    ) -> Result<UpgradeClientResult, LightClientError> {
+        Err(Error::upgrade_client_not_supported().into())
+    }
+
     #[allow(unused_variables)]
     fn verify_membership(
         &self,
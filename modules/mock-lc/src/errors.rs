@@ -12,6 +12,12 @@ define_error! {
             format_args!("unexpected client_type error: type_url={}", e.type_url)
         },
 
+        RecoverClientNotSupported
+        |_| { "mock client does not support the client recovery" },
+
+        UpgradeClientNotSupported
+        |_| { "mock client does not support the client upgrade" },
+
         Ics02
         [TraceError<ibc::core::ics02_client::error::ClientError>]
         |_| { "ICS02 client error" },
@@ -3,7 +3,9 @@
 #![allow(clippy::large_enum_variant)]
 #![feature(generic_const_exprs)]
 extern crate alloc;
-pub use crate::store::{StoreCommand, StoreResult};
+pub use crate::store::{
+    StoreCommand, StoreResult, MAX_SCAN_PREFIX_LIMIT, MAX_SCAN_PREFIX_PAGE_SIZE,
+};
 pub use remote_attestation::{
     GetIASSocketResult, GetQuoteInput, GetQuoteResult, GetReportAttestationStatusInput,
     GetReportAttestationStatusResult, InitQuoteResult, RemoteAttestationCommand,
@@ -16,6 +18,9 @@ mod transmuter;
 
 use serde::{Deserialize, Serialize};
 
+/// `OCALL_OUTPUT_BUFFER_SIZE` is the size of the buffer that the enclave allocates for an ocall result
+pub const OCALL_OUTPUT_BUFFER_SIZE: usize = 65536;
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct OCallCommand {
     pub cmd: Command,
@@ -1,12 +1,21 @@
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
-use store::TxId;
+use store::{TxId, KV};
+
+/// `MAX_SCAN_PREFIX_LIMIT` is the maximum number of key-value pairs returned by a `ScanPrefix` command
+pub const MAX_SCAN_PREFIX_LIMIT: u32 = 1024;
+
+/// `MAX_SCAN_PREFIX_PAGE_SIZE` is the maximum total size of keys and values returned by a `ScanPrefix` command
+/// It leaves room for the encoding overhead so that the result fits in the ocall output buffer
+pub const MAX_SCAN_PREFIX_PAGE_SIZE: usize = crate::OCALL_OUTPUT_BUFFER_SIZE / 2;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub enum StoreCommand {
     Get(TxId, Vec<u8>),
     Set(TxId, Vec<u8>, Vec<u8>),
     Remove(TxId, Vec<u8>),
+    /// ScanPrefix(tx_id, prefix, start_after, limit)
+    ScanPrefix(TxId, Vec<u8>, Option<Vec<u8>>, u32),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -14,4 +23,7 @@ pub enum StoreResult {
     Get(Option<Vec<u8>>),
     Set,
     Remove,
+    /// ScanPrefix returns a page of key-value pairs
+    /// The page may contain fewer pairs than the requested limit, and an empty page indicates the end of the iteration
+    ScanPrefix(Vec<KV>),
 }
@@ -1,7 +1,7 @@
 use crate::errors::Result;
 use host_environment::Environment;
 use log::*;
-use ocall_commands::{StoreCommand, StoreResult};
+use ocall_commands::{StoreCommand, StoreResult, MAX_SCAN_PREFIX_LIMIT, MAX_SCAN_PREFIX_PAGE_SIZE};
 use store::transaction::TxAccessor;
 
 pub fn dispatch(env: &Environment, command: StoreCommand) -> Result<StoreResult> {
@@ -36,6 +36,34 @@ pub fn dispatch(env: &Environment, command: StoreCommand) -> Result<StoreResult>
             env.get_mut_store().tx_remove(tx_id, &key)?;
             StoreResult::Remove
         }
+        StoreCommand::ScanPrefix(tx_id, prefix, start_after, limit) => {
+            debug!(
+                "ScanPrefix: tx_id={} prefix={:?} prefix(utf8)={} start_after={:?} limit={}",
+                tx_id,
+                prefix,
+                String::from_utf8_lossy(&prefix),
+                start_after,
+                limit
+            );
+            let limit = limit.min(MAX_SCAN_PREFIX_LIMIT) as usize;
+            let mut kvs =
+                env.get_store()
+                    .tx_scan_prefix(tx_id, &prefix, start_after.as_deref(), limit)?;
+            // truncate the page to fit in the ocall output buffer
+            // at least one pair is kept so that the caller can make progress
+            let mut size = 0;
+            let page_len = kvs
+                .iter()
+                .position(|(k, v)| {
+                    // 16 bytes is a rough upper bound of the encoding overhead per pair
+                    size += k.len() + v.len() + 16;
+                    size > MAX_SCAN_PREFIX_PAGE_SIZE
+                })
+                .unwrap_or(kvs.len())
+                .max(1);
+            kvs.truncate(page_len);
+            StoreResult::ScanPrefix(kvs)
+        }
     };
     Ok(res)
 }
@@ -1,10 +1,16 @@
 use crate::service::AppService;
-use enclave_api::EnclaveProtoAPI;
+use enclave_api::{EnclaveProtoAPI, ErrorDetail};
 use lcp_proto::lcp::service::elc::v1::{
     msg_server::Msg, query_server::Query, MsgCreateClient, MsgCreateClientResponse,
-    MsgUpdateClient, MsgUpdateClientResponse, MsgVerifyMembership, MsgVerifyMembershipResponse,
-    MsgVerifyNonMembership, MsgVerifyNonMembershipResponse, QueryClientRequest,
-    QueryClientResponse,
+    MsgPruneConsensusStates, MsgPruneConsensusStatesResponse, MsgRecoverClient,
+    MsgRecoverClientResponse, MsgSubmitMisbehaviour, MsgSubmitMisbehaviourResponse,
+    MsgUpdateClient, MsgUpdateClientBatch, MsgUpdateClientBatchResponse, MsgUpdateClientResponse,
+    MsgUpgradeClient, MsgUpgradeClientResponse, MsgVerifyMembership, MsgVerifyMembershipResponse,
+    MsgVerifyNonMembership, MsgVerifyNonMembershipResponse, MsgVerifyStateBatch,
+    MsgVerifyStateBatchResponse, QueryClientRequest, QueryClientResponse, QueryClientStatusRequest,
+    QueryClientStatusResponse, QueryClientsRequest, QueryClientsResponse,
+    QueryConsensusStateHeightsRequest, QueryConsensusStateHeightsResponse,
+    QueryConsensusStateRequest, QueryConsensusStateResponse,
 };
 use store::transaction::CommitStore;
 use tonic::{Request, Response, Status};
@@ -35,6 +41,59 @@ where
         }
     }
 
+    async fn update_client_batch(
+        &self,
+        request: Request<MsgUpdateClientBatch>,
+    ) -> Result<Response<MsgUpdateClientBatchResponse>, Status> {
+        match self.enclave.proto_update_client_batch(request.into_inner()) {
+            Ok(res) => Ok(Response::new(res)),
+            Err(e) => Err(Status::aborted(e.to_string())),
+        }
+    }
+
+    async fn submit_misbehaviour(
+        &self,
+        request: Request<MsgSubmitMisbehaviour>,
+    ) -> Result<Response<MsgSubmitMisbehaviourResponse>, Status> {
+        match self.enclave.proto_submit_misbehaviour(request.into_inner()) {
+            Ok(res) => Ok(Response::new(res)),
+            Err(e) => Err(Status::aborted(e.to_string())),
+        }
+    }
+
+    async fn prune_consensus_states(
+        &self,
+        request: Request<MsgPruneConsensusStates>,
+    ) -> Result<Response<MsgPruneConsensusStatesResponse>, Status> {
+        match self
+            .enclave
+            .proto_prune_consensus_states(request.into_inner())
+        {
+            Ok(res) => Ok(Response::new(res)),
+            Err(e) => Err(Status::aborted(e.to_string())),
+        }
+    }
+
+    async fn recover_client(
+        &self,
+        request: Request<MsgRecoverClient>,
+    ) -> Result<Response<MsgRecoverClientResponse>, Status> {
+        match self.enclave.proto_recover_client(request.into_inner()) {
+            Ok(res) => Ok(Response::new(res)),
+            Err(e) => Err(Status::aborted(e.to_string())),
+        }
+    }
+
+    async fn upgrade_client(
+        &self,
+        request: Request<MsgUpgradeClient>,
+    ) -> Result<Response<MsgUpgradeClientResponse>, Status> {
+        match self.enclave.proto_upgrade_client(request.into_inner()) {
+            Ok(res) => Ok(Response::new(res)),
+            Err(e) => Err(Status::aborted(e.to_string())),
+        }
+    }
+
     async fn verify_membership(
         &self,
         request: Request<MsgVerifyMembership>,
@@ -57,6 +116,16 @@ where
             Err(e) => Err(Status::aborted(e.to_string())),
         }
     }
+
+    async fn verify_state_batch(
+        &self,
+        request: Request<MsgVerifyStateBatch>,
+    ) -> Result<Response<MsgVerifyStateBatchResponse>, Status> {
+        match self.enclave.proto_verify_state_batch(request.into_inner()) {
+            Ok(res) => Ok(Response::new(res)),
+            Err(e) => Err(Status::aborted(e.to_string())),
+        }
+    }
 }
 
 #[tonic::async_trait]
@@ -74,4 +143,53 @@ where
             Err(e) => Err(Status::aborted(e.to_string())),
         }
     }
+
+    async fn consensus_state(
+        &self,
+        request: Request<QueryConsensusStateRequest>,
+    ) -> Result<Response<QueryConsensusStateResponse>, Status> {
+        match self
+            .enclave
+            .proto_query_consensus_state(request.into_inner())
+        {
+            Ok(res) => Ok(Response::new(res)),
+            Err(e) => match e.detail() {
+                ErrorDetail::ConsensusStateNotFound(_) => Err(Status::not_found(e.to_string())),
+                _ => Err(Status::aborted(e.to_string())),
+            },
+        }
+    }
+
+    async fn consensus_state_heights(
+        &self,
+        request: Request<QueryConsensusStateHeightsRequest>,
+    ) -> Result<Response<QueryConsensusStateHeightsResponse>, Status> {
+        match self
+            .enclave
+            .proto_query_consensus_state_heights(request.into_inner())
+        {
+            Ok(res) => Ok(Response::new(res)),
+            Err(e) => Err(Status::aborted(e.to_string())),
+        }
+    }
+
+    async fn clients(
+        &self,
+        request: Request<QueryClientsRequest>,
+    ) -> Result<Response<QueryClientsResponse>, Status> {
+        match self.enclave.proto_query_clients(request.into_inner()) {
+            Ok(res) => Ok(Response::new(res)),
+            Err(e) => Err(Status::aborted(e.to_string())),
+        }
+    }
+
+    async fn client_status(
+        &self,
+        request: Request<QueryClientStatusRequest>,
+    ) -> Result<Response<QueryClientStatusResponse>, Status> {
+        match self.enclave.proto_query_client_status(request.into_inner()) {
+            Ok(res) => Ok(Response::new(res)),
+            Err(e) => Err(Status::aborted(e.to_string())),
+        }
+    }
 }
@@ -4,6 +4,7 @@ use enclave_api::EnclaveProtoAPI;
 use lcp_proto::lcp::service::enclave::v1::{
     query_server::Query, EnclaveKeyInfo, QueryAvailableEnclaveKeysRequest,
     QueryAvailableEnclaveKeysResponse, QueryEnclaveKeyRequest, QueryEnclaveKeyResponse,
+    QueryLightClientsRequest, QueryLightClientsResponse,
 };
 use lcp_types::Mrenclave;
 use store::transaction::CommitStore;
@@ -49,4 +50,14 @@ where
         let key = EnclaveKeyInfo::try_from(key).map_err(|e| Status::aborted(e.to_string()))?;
         Ok(Response::new(QueryEnclaveKeyResponse { key: Some(key) }))
     }
+
+    async fn light_clients(
+        &self,
+        req: Request<QueryLightClientsRequest>,
+    ) -> Result<Response<QueryLightClientsResponse>, Status> {
+        match self.enclave.proto_query_light_clients(req.into_inner()) {
+            Ok(res) => Ok(Response::new(res)),
+            Err(e) => Err(Status::aborted(e.to_string())),
+        }
+    }
 }
@@ -0,0 +1,709 @@
+use crate::errors::Error;
+use crate::header::{commitment_path, sign_bytes, Header, Misbehaviour, TimestampedSignatureData};
+use crate::prelude::*;
+use crate::state::{gen_state_id, ClientState, ConsensusState, SOLOMACHINE_CLIENT_STATE_TYPE_URL};
+use core::time::Duration;
+use crypto::{verify_signature_address, Address, Keccak256};
+use light_client::commitments::{
+    CommitmentContext, CommitmentPrefix, MisbehaviourCommitment, PrevState, StateCommitment,
+    StateID, UpdateClientCommitment,
+};
+use light_client::types::{Any, ClientId, ClientStatus, Height, Time};
+use light_client::{
+    CreateClientResult, Error as LightClientError, HostClientReader, LightClient,
+    LightClientRegistry, MisbehaviourResult, RecoverClientResult, StateVerificationResult,
+    UpdateClientResult, UpgradeClientResult,
+};
+
+pub const SOLOMACHINE_CLIENT_TYPE: &str = "solomachine-multisig";
+
+/// SoloMachineLightClient is a light client for a solo machine whose states are signed by a multisig
+///
+/// Every state is signed by at least `threshold` of the signers in the client state:
+/// - a header is signed at the latest sequence, rotates the signers and increments the sequence
+/// - a proof of the state verification is signed at the latest sequence and does not increment it
+#[derive(Default)]
+pub struct SoloMachineLightClient;
+
+impl LightClient for SoloMachineLightClient {
+    fn client_type(&self) -> String {
+        SOLOMACHINE_CLIENT_TYPE.to_string()
+    }
+
+    fn latest_height(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: &ClientId,
+    ) -> Result<Height, LightClientError> {
+        let client_state: ClientState = ctx.client_state(client_id)?.try_into()?;
+        Ok(client_state.latest_height())
+    }
+
+    fn status(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: &ClientId,
+    ) -> Result<ClientStatus, LightClientError> {
+        let client_state: ClientState = ctx.client_state(client_id)?.try_into()?;
+        // solo machine client never expires
+        if client_state.is_frozen {
+            Ok(ClientStatus::Frozen)
+        } else {
+            Ok(ClientStatus::Active)
+        }
+    }
+
+    fn consensus_state_timestamp(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Time, LightClientError> {
+        let consensus_state: ConsensusState = ctx.consensus_state(client_id, height)?.try_into()?;
+        Ok(consensus_state.timestamp)
+    }
+
+    fn trusting_period(
+        &self,
+        _: &dyn HostClientReader,
+        _: &ClientId,
+    ) -> Result<Option<Duration>, LightClientError> {
+        // solo machine client has no trusting period
+        Ok(None)
+    }
+
+    fn create_client(
+        &self,
+        _: &dyn HostClientReader,
+        any_client_state: Any,
+        any_consensus_state: Any,
+    ) -> Result<CreateClientResult, LightClientError> {
+        let client_state = ClientState::try_from(any_client_state.clone())?;
+        let consensus_state = ConsensusState::try_from(any_consensus_state)?;
+        client_state.validate()?;
+        if client_state.is_frozen {
+            return Err(Error::invalid_client_state(
+                "an initial client state must not be frozen".to_string(),
+            )
+            .into());
+        }
+        if consensus_state.sequence != client_state.sequence {
+            return Err(Error::invalid_consensus_state(format!(
+                "sequence mismatch: client_state={} consensus_state={}",
+                client_state.sequence, consensus_state.sequence
+            ))
+            .into());
+        }
+
+        let height = client_state.latest_height();
+        let timestamp = consensus_state.timestamp;
+        let state_id = gen_state_id(client_state, consensus_state)?;
+
+        Ok(CreateClientResult {
+            height,
+            commitment: UpdateClientCommitment {
+                prev_state_id: None,
+                new_state_id: state_id,
+                new_state: Some(any_client_state),
+                prev_height: None,
+                new_height: height,
+                timestamp,
+                context: CommitmentContext::Empty,
+            }
+            .into(),
+            prove: false,
+        })
+    }
+
+    fn update_client(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: ClientId,
+        any_header: Any,
+    ) -> Result<UpdateClientResult, LightClientError> {
+        let header = Header::try_from(any_header)?;
+
+        let client_state: ClientState = ctx.client_state(&client_id)?.try_into()?;
+        if client_state.is_frozen {
+            return Err(Error::client_frozen(client_id).into());
+        }
+
+        let latest_height = client_state.latest_height();
+        let consensus_state: ConsensusState = ctx
+            .consensus_state(&client_id, &latest_height)?
+            .try_into()?;
+        if header.timestamp < consensus_state.timestamp {
+            return Err(Error::invalid_header(format!(
+                "header timestamp must not be less than the consensus state timestamp: header={} consensus_state={}",
+                header.timestamp, consensus_state.timestamp
+            ))
+            .into());
+        }
+
+        verify_signatures(
+            &client_state,
+            &header.sign_bytes(client_state.sequence, &client_state.diversifier),
+            &header.signatures,
+        )?;
+
+        let new_client_state = ClientState {
+            sequence: client_state.sequence + 1,
+            signers: header.new_signers,
+            threshold: header.new_threshold,
+            diversifier: header.new_diversifier,
+            is_frozen: false,
+        };
+        new_client_state.validate()?;
+        let new_consensus_state = ConsensusState {
+            sequence: new_client_state.sequence,
+            timestamp: header.timestamp,
+        };
+
+        let height = new_client_state.latest_height();
+        let prev_state_id = gen_state_id(client_state, consensus_state)?;
+        let new_state_id = gen_state_id(new_client_state.clone(), new_consensus_state.clone())?;
+
+        Ok(UpdateClientResult {
+            new_any_client_state: new_client_state.into(),
+            new_any_consensus_state: new_consensus_state.into(),
+            height,
+            commitment: UpdateClientCommitment {
+                prev_state_id: Some(prev_state_id),
+                new_state_id,
+                new_state: None,
+                prev_height: Some(latest_height),
+                new_height: height,
+                timestamp: header.timestamp,
+                context: CommitmentContext::Empty,
+            }
+            .into(),
+            prove: true,
+        })
+    }
+
+    fn submit_misbehaviour(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: ClientId,
+        any_misbehaviour: Any,
+    ) -> Result<MisbehaviourResult, LightClientError> {
+        let misbehaviour = Misbehaviour::try_from(any_misbehaviour)?;
+
+        let client_state: ClientState = ctx.client_state(&client_id)?.try_into()?;
+        if client_state.is_frozen {
+            return Err(Error::client_frozen(client_id).into());
+        }
+
+        // the signers can be verified only at the latest sequence because they may be rotated by the header
+        if misbehaviour.sequence != client_state.sequence {
+            return Err(Error::invalid_misbehaviour(format!(
+                "sequence mismatch: client_state={} misbehaviour={}",
+                client_state.sequence, misbehaviour.sequence
+            ))
+            .into());
+        }
+        misbehaviour.validate_conflict()?;
+        for sig in [&misbehaviour.signature_one, &misbehaviour.signature_two] {
+            verify_signatures(
+                &client_state,
+                &sig.sign_bytes(misbehaviour.sequence, &client_state.diversifier),
+                &sig.signatures,
+            )?;
+        }
+
+        let latest_height = client_state.latest_height();
+        let consensus_state: ConsensusState = ctx
+            .consensus_state(&client_id, &latest_height)?
+            .try_into()?;
+        let prev_state_id = gen_state_id(client_state.clone(), consensus_state)?;
+
+        Ok(MisbehaviourResult {
+            new_any_client_state: client_state.freeze().into(),
+            commitment: MisbehaviourCommitment {
+                client_id,
+                frozen_height: latest_height,
+                prev_states: vec![PrevState::new(latest_height, prev_state_id)],
+                context: CommitmentContext::Empty,
+            }
+            .into(),
+        })
+    }
+
+    fn recover_client(
+        &self,
+        _: &dyn HostClientReader,
+        _: ClientId,
+        _: ClientId,
+    ) -> Result<RecoverClientResult, LightClientError> {
+        Err(Error::recover_client_not_supported().into())
+    }
+
+    fn upgrade_client(
+        &self,
+        _: &dyn HostClientReader,
+        _: ClientId,
+        _: Any,
+        _: Any,
+        _: Vec<u8>,
+        _: Vec<u8>,
+    ) -> Result<UpgradeClientResult, LightClientError> {
+        Err(Error::upgrade_client_not_supported().into())
+    }
+
+    fn verify_membership(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: ClientId,
+        prefix: CommitmentPrefix,
+        path: String,
+        value: Vec<u8>,
+        proof_height: Height,
+        proof: Vec<u8>,
+    ) -> Result<StateVerificationResult, LightClientError> {
+        // the empty value is reserved for the absence of the state
+        if value.is_empty() {
+            return Err(Error::empty_value().into());
+        }
+        let state_id = verify_signed_data(
+            ctx,
+            client_id,
+            &prefix,
+            &path,
+            value.clone(),
+            proof_height,
+            &proof,
+        )?;
+        Ok(StateVerificationResult {
+            state_commitment: StateCommitment::new(
+                prefix,
+                path,
+                Some(value.keccak256()),
+                proof_height,
+                state_id,
+            )
+            .into(),
+        })
+    }
+
+    fn verify_non_membership(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: ClientId,
+        prefix: CommitmentPrefix,
+        path: String,
+        proof_height: Height,
+        proof: Vec<u8>,
+    ) -> Result<StateVerificationResult, LightClientError> {
+        // the absence is represented by the signature over empty data, which verify_membership never accepts
+        let state_id =
+            verify_signed_data(ctx, client_id, &prefix, &path, vec![], proof_height, &proof)?;
+        Ok(StateVerificationResult {
+            state_commitment: StateCommitment::new(prefix, path, None, proof_height, state_id)
+                .into(),
+        })
+    }
+}
+
+pub fn register_implementations(registry: &mut dyn LightClientRegistry) {
+    registry
+        .put_light_client(
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+            Box::new(SoloMachineLightClient),
+        )
+        .unwrap()
+}
+
+/// verify_signed_data verifies that the data at the path is signed by the signers at the proof height,
+/// and returns the state id of the client at the height
+fn verify_signed_data(
+    ctx: &dyn HostClientReader,
+    client_id: ClientId,
+    prefix: &[u8],
+    path: &str,
+    data: Vec<u8>,
+    proof_height: Height,
+    proof: &[u8],
+) -> Result<StateID, LightClientError> {
+    let client_state: ClientState = ctx.client_state(&client_id)?.try_into()?;
+    if client_state.is_frozen {
+        return Err(Error::client_frozen(client_id).into());
+    }
+    // the proof must be signed by the current signers, so only the latest height can be verified
+    let latest_height = client_state.latest_height();
+    if proof_height != latest_height {
+        return Err(Error::height_mismatch(latest_height, proof_height).into());
+    }
+    let consensus_state: ConsensusState =
+        ctx.consensus_state(&client_id, &proof_height)?.try_into()?;
+
+    let proof = TimestampedSignatureData::try_from(proof)?;
+    if proof.timestamp < consensus_state.timestamp {
+        return Err(Error::invalid_proof(format!(
+            "proof timestamp must not be less than the consensus state timestamp: proof={} consensus_state={}",
+            proof.timestamp, consensus_state.timestamp
+        ))
+        .into());
+    }
+    verify_signatures(
+        &client_state,
+        &sign_bytes(
+            client_state.sequence,
+            proof.timestamp,
+            &client_state.diversifier,
+            commitment_path(prefix, path),
+            data,
+        ),
+        &proof.signatures,
+    )?;
+
+    Ok(gen_state_id(client_state, consensus_state)?)
+}
+
+/// verify_signatures verifies that the sign bytes are signed by at least `threshold` distinct signers of the client state
+fn verify_signatures(
+    client_state: &ClientState,
+    sign_bytes: &[u8],
+    signatures: &[Vec<u8>],
+) -> Result<(), Error> {
+    let mut signers: Vec<Address> = Vec::with_capacity(signatures.len());
+    for signature in signatures {
+        if signature.len() != 65 {
+            return Err(Error::invalid_signature_length(signature.len()));
+        }
+        let signer = verify_signature_address(sign_bytes, signature)?;
+        if !client_state.signers.contains(&signer) {
+            return Err(Error::unknown_signer(signer));
+        }
+        if signers.contains(&signer) {
+            return Err(Error::duplicate_signer(signer));
+        }
+        signers.push(signer);
+    }
+    if signers.len() < client_state.threshold as usize {
+        return Err(Error::insufficient_signatures(
+            client_state.threshold,
+            signers.len(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::{SignatureAndData, SENTINEL_HEADER_PATH};
+    use context::Context;
+    use crypto::{EnclaveKey, NopSigner, Signer};
+    use light_client::{ClientKeeper, MapLightClientRegistry};
+    use store::memory::MemStore;
+
+    const DIVERSIFIER: &str = "diversifier";
+
+    #[test]
+    fn test_update_client_and_verify_membership() {
+        let lc = SoloMachineLightClient;
+        let mut ctx = Context::new(
+            MapLightClientRegistry::new(),
+            MemStore::default(),
+            &NopSigner,
+        );
+        let keys = gen_keys(3);
+        let client_id = create_client(&mut ctx, &keys, 2);
+
+        // the header must be signed by at least `threshold` of the current signers
+        let new_keys = gen_keys(2);
+        let header = build_header(&keys[..1], 1, timestamp(1), &new_keys, 2);
+        let res = lc.update_client(&ctx, client_id.clone(), header.into());
+        assert!(res.is_err(), "res={:?}", res);
+
+        let header = build_header(&keys[1..], 1, timestamp(1), &new_keys, 2);
+        let res = lc
+            .update_client(&ctx, client_id.clone(), header.into())
+            .unwrap();
+        assert_eq!(res.height, Height::new(0, 2));
+        ctx.store_any_client_state(client_id.clone(), res.new_any_client_state)
+            .unwrap();
+        ctx.store_any_consensus_state(client_id.clone(), res.height, res.new_any_consensus_state)
+            .unwrap();
+
+        // the proof must be signed by the new signers at the latest height
+        let (prefix, path, value) = (b"ibc".to_vec(), "path".to_string(), b"value".to_vec());
+        let proof = build_proof(&new_keys, 2, timestamp(2), &prefix, &path, &value);
+        let res = lc.verify_membership(
+            &ctx,
+            client_id.clone(),
+            prefix.clone(),
+            path.clone(),
+            value.clone(),
+            Height::new(0, 2),
+            proof.clone(),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+
+        let res = lc.verify_membership(
+            &ctx,
+            client_id.clone(),
+            prefix.clone(),
+            path.clone(),
+            b"other".to_vec(),
+            Height::new(0, 2),
+            proof,
+        );
+        assert!(res.is_err(), "res={:?}", res);
+
+        let proof = build_proof(&keys, 2, timestamp(2), &prefix, &path, &value);
+        let res = lc.verify_membership(
+            &ctx,
+            client_id.clone(),
+            prefix.clone(),
+            path.clone(),
+            value,
+            Height::new(0, 2),
+            proof,
+        );
+        assert!(res.is_err(), "res={:?}", res);
+
+        let proof = build_proof(&new_keys, 2, timestamp(2), &prefix, &path, &[]);
+        let res = lc.verify_non_membership(
+            &ctx,
+            client_id.clone(),
+            prefix.clone(),
+            path.clone(),
+            Height::new(0, 2),
+            proof.clone(),
+        );
+        assert!(res.is_ok(), "res={:?}", res);
+
+        // the proof of the absence cannot be used as a proof of the empty value
+        let res = lc.verify_membership(
+            &ctx,
+            client_id.clone(),
+            prefix.clone(),
+            path.clone(),
+            vec![],
+            Height::new(0, 2),
+            proof.clone(),
+        );
+        assert!(res.is_err(), "res={:?}", res);
+
+        // only the latest height can be verified
+        let res = lc.verify_non_membership(&ctx, client_id, prefix, path, Height::new(0, 1), proof);
+        assert!(res.is_err(), "res={:?}", res);
+    }
+
+    #[test]
+    fn test_submit_misbehaviour() {
+        let lc = SoloMachineLightClient;
+        let mut ctx = Context::new(
+            MapLightClientRegistry::new(),
+            MemStore::default(),
+            &NopSigner,
+        );
+        let keys = gen_keys(2);
+        let client_id = create_client(&mut ctx, &keys, 2);
+
+        let sig1 = build_signature_and_data(&keys, 1, b"path", b"data1");
+        let sig2 = build_signature_and_data(&keys, 1, b"path", b"data1");
+        let res = lc.submit_misbehaviour(
+            &ctx,
+            client_id.clone(),
+            Misbehaviour {
+                sequence: 1,
+                signature_one: sig1.clone(),
+                signature_two: sig2,
+            }
+            .into(),
+        );
+        assert!(res.is_err(), "res={:?}", res);
+
+        // honest proofs for different paths do not conflict
+        let sig2 = build_signature_and_data(&keys, 1, b"other", b"data2");
+        let res = lc.submit_misbehaviour(
+            &ctx,
+            client_id.clone(),
+            Misbehaviour {
+                sequence: 1,
+                signature_one: sig1.clone(),
+                signature_two: sig2,
+            }
+            .into(),
+        );
+        assert!(res.is_err(), "res={:?}", res);
+
+        let sig2 = build_signature_and_data(&keys, 1, b"path", b"data2");
+        let res = lc
+            .submit_misbehaviour(
+                &ctx,
+                client_id.clone(),
+                Misbehaviour {
+                    sequence: 1,
+                    signature_one: sig1,
+                    signature_two: sig2,
+                }
+                .into(),
+            )
+            .unwrap();
+        ctx.store_any_client_state(client_id.clone(), res.new_any_client_state)
+            .unwrap();
+        assert_eq!(lc.status(&ctx, &client_id).unwrap(), ClientStatus::Frozen);
+
+        let header = build_header(&keys, 1, timestamp(1), &keys, 2);
+        let res = lc.update_client(&ctx, client_id, header.into());
+        assert!(res.is_err(), "res={:?}", res);
+    }
+
+    #[test]
+    fn test_submit_header_misbehaviour() {
+        let lc = SoloMachineLightClient;
+        let mut ctx = Context::new(
+            MapLightClientRegistry::new(),
+            MemStore::default(),
+            &NopSigner,
+        );
+        let keys = gen_keys(2);
+        let client_id = create_client(&mut ctx, &keys, 2);
+
+        let header_signature = |header: &Header| SignatureAndData {
+            signatures: header.signatures.clone(),
+            path: SENTINEL_HEADER_PATH.to_vec(),
+            data: header.header_data(),
+            timestamp: header.timestamp,
+        };
+
+        // the same header signed at different timestamps is not a misbehaviour
+        let header1 = build_header(&keys, 1, timestamp(1), &keys, 2);
+        let header2 = build_header(&keys, 1, timestamp(2), &keys, 2);
+        let res = lc.submit_misbehaviour(
+            &ctx,
+            client_id.clone(),
+            Misbehaviour {
+                sequence: 1,
+                signature_one: header_signature(&header1),
+                signature_two: header_signature(&header2),
+            }
+            .into(),
+        );
+        assert!(res.is_err(), "res={:?}", res);
+
+        // two different headers signed at the same sequence are a misbehaviour
+        let header2 = build_header(&keys, 1, timestamp(1), &gen_keys(2), 2);
+        let res = lc
+            .submit_misbehaviour(
+                &ctx,
+                client_id.clone(),
+                Misbehaviour {
+                    sequence: 1,
+                    signature_one: header_signature(&header1),
+                    signature_two: header_signature(&header2),
+                }
+                .into(),
+            )
+            .unwrap();
+        ctx.store_any_client_state(client_id.clone(), res.new_any_client_state)
+            .unwrap();
+        assert_eq!(lc.status(&ctx, &client_id).unwrap(), ClientStatus::Frozen);
+    }
+
+    fn create_client<C: ClientKeeper + HostClientReader>(
+        ctx: &mut C,
+        keys: &[EnclaveKey],
+        threshold: u32,
+    ) -> ClientId {
+        let client_id = ClientId::new(SOLOMACHINE_CLIENT_TYPE, 0).unwrap();
+        let client_state = ClientState {
+            sequence: 1,
+            signers: addresses(keys),
+            threshold,
+            diversifier: DIVERSIFIER.to_string(),
+            is_frozen: false,
+        };
+        let consensus_state = ConsensusState {
+            sequence: 1,
+            timestamp: timestamp(0),
+        };
+        let res = SoloMachineLightClient
+            .create_client(
+                ctx,
+                client_state.clone().into(),
+                consensus_state.clone().into(),
+            )
+            .unwrap();
+        ctx.store_any_client_state(client_id.clone(), client_state.into())
+            .unwrap();
+        ctx.store_any_consensus_state(client_id.clone(), res.height, consensus_state.into())
+            .unwrap();
+        client_id
+    }
+
+    fn build_header(
+        keys: &[EnclaveKey],
+        sequence: u64,
+        timestamp: Time,
+        new_keys: &[EnclaveKey],
+        new_threshold: u32,
+    ) -> Header {
+        let mut header = Header {
+            timestamp,
+            signatures: vec![],
+            new_signers: addresses(new_keys),
+            new_threshold,
+            new_diversifier: DIVERSIFIER.to_string(),
+        };
+        header.signatures = sign(keys, &header.sign_bytes(sequence, DIVERSIFIER));
+        header
+    }
+
+    fn build_proof(
+        keys: &[EnclaveKey],
+        sequence: u64,
+        timestamp: Time,
+        prefix: &[u8],
+        path: &str,
+        data: &[u8],
+    ) -> Vec<u8> {
+        let sign_bytes = sign_bytes(
+            sequence,
+            timestamp,
+            DIVERSIFIER,
+            commitment_path(prefix, path),
+            data.to_vec(),
+        );
+        TimestampedSignatureData {
+            signatures: sign(keys, &sign_bytes),
+            timestamp,
+        }
+        .into()
+    }
+
+    fn build_signature_and_data(
+        keys: &[EnclaveKey],
+        sequence: u64,
+        path: &[u8],
+        data: &[u8],
+    ) -> SignatureAndData {
+        let mut sig = SignatureAndData {
+            signatures: vec![],
+            path: path.to_vec(),
+            data: data.to_vec(),
+            timestamp: timestamp(1),
+        };
+        sig.signatures = sign(keys, &sig.sign_bytes(sequence, DIVERSIFIER));
+        sig
+    }
+
+    fn gen_keys(n: usize) -> Vec<EnclaveKey> {
+        (0..n).map(|_| EnclaveKey::new().unwrap()).collect()
+    }
+
+    fn addresses(keys: &[EnclaveKey]) -> Vec<Address> {
+        keys.iter().map(|k| k.get_pubkey().as_address()).collect()
+    }
+
+    fn sign(keys: &[EnclaveKey], sign_bytes: &[u8]) -> Vec<Vec<u8>> {
+        keys.iter().map(|k| k.sign(sign_bytes).unwrap()).collect()
+    }
+
+    fn timestamp(secs: u64) -> Time {
+        Time::from_unix_timestamp_nanos((1_700_000_000 + secs as u128) * 1_000_000_000).unwrap()
+    }
+}
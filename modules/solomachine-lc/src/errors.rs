@@ -0,0 +1,155 @@
+use crate::prelude::*;
+use crypto::Address;
+use flex_error::*;
+use light_client::types::{ClientId, Height};
+use light_client::LightClientSpecificError;
+
+define_error! {
+    #[derive(Debug, PartialEq, Eq)]
+    Error {
+        UnexpectedClientType {
+            type_url: String
+        }
+        |e| {
+            format_args!("unexpected client_type: type_url={}", e.type_url)
+        },
+
+        UnexpectedHeaderType {
+            type_url: String
+        }
+        |e| {
+            format_args!("unexpected header type: type_url={}", e.type_url)
+        },
+
+        UnexpectedMisbehaviourType {
+            type_url: String
+        }
+        |e| {
+            format_args!("unexpected misbehaviour type: type_url={}", e.type_url)
+        },
+
+        InvalidClientState {
+            descr: String
+        }
+        |e| {
+            format_args!("invalid client state: descr={}", e.descr)
+        },
+
+        InvalidConsensusState {
+            descr: String
+        }
+        |e| {
+            format_args!("invalid consensus state: descr={}", e.descr)
+        },
+
+        InvalidHeader {
+            descr: String
+        }
+        |e| {
+            format_args!("invalid header: descr={}", e.descr)
+        },
+
+        InvalidMisbehaviour {
+            descr: String
+        }
+        |e| {
+            format_args!("invalid misbehaviour: descr={}", e.descr)
+        },
+
+        InvalidProof {
+            descr: String
+        }
+        |e| {
+            format_args!("invalid proof: descr={}", e.descr)
+        },
+
+        EmptyValue
+        |_| { "the value of the membership verification must not be empty" },
+
+        ClientFrozen {
+            client_id: ClientId
+        }
+        |e| {
+            format_args!("client is frozen: client_id={}", e.client_id)
+        },
+
+        HeightMismatch {
+            expected: Height,
+            actual: Height
+        }
+        |e| {
+            format_args!("height mismatch: expected={} actual={}", e.expected, e.actual)
+        },
+
+        InvalidSignatureLength {
+            length: usize
+        }
+        |e| {
+            format_args!("invalid signature length: expected=65 actual={}", e.length)
+        },
+
+        UnknownSigner {
+            address: Address
+        }
+        |e| {
+            format_args!("unknown signer: address={}", e.address)
+        },
+
+        DuplicateSigner {
+            address: Address
+        }
+        |e| {
+            format_args!("duplicate signer: address={}", e.address)
+        },
+
+        InsufficientSignatures {
+            threshold: u32,
+            actual: usize
+        }
+        |e| {
+            format_args!("insufficient signatures: threshold={} actual={}", e.threshold, e.actual)
+        },
+
+        RecoverClientNotSupported
+        |_| { "solo machine client does not support the client recovery" },
+
+        UpgradeClientNotSupported
+        |_| { "solo machine client does not support the client upgrade" },
+
+        Time
+        [light_client::types::TimeError]
+        |_| { "Time error" },
+
+        CryptoError
+        [crypto::Error]
+        |_| { "Crypto error" },
+
+        Commitment
+        [light_client::commitments::Error]
+        |_| { "Commitment error" },
+
+        ProtoDecode
+        [TraceError<prost::DecodeError>]
+        |_| { "Proto decode error" }
+    }
+}
+
+impl LightClientSpecificError for Error {}
+
+impl From<light_client::types::TimeError> for Error {
+    fn from(err: light_client::types::TimeError) -> Self {
+        Error::time(err)
+    }
+}
+
+impl From<crypto::Error> for Error {
+    fn from(value: crypto::Error) -> Self {
+        Self::crypto_error(value)
+    }
+}
+
+impl From<light_client::commitments::Error> for Error {
+    fn from(value: light_client::commitments::Error) -> Self {
+        Self::commitment(value)
+    }
+}
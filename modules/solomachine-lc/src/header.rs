@@ -0,0 +1,296 @@
+use crate::errors::Error;
+use crate::prelude::*;
+use crypto::Address;
+use light_client::types::proto::lcp::lightclients::solomachine::v1::{
+    Header as RawHeader, HeaderData as RawHeaderData, Misbehaviour as RawMisbehaviour,
+    SignBytes as RawSignBytes, SignatureAndData as RawSignatureAndData,
+    TimestampedSignatureData as RawTimestampedSignatureData,
+};
+use light_client::types::{Any, Time};
+use prost::Message;
+
+pub const SOLOMACHINE_HEADER_TYPE_URL: &str = "/lcp.lightclients.solomachine.v1.Header";
+pub const SOLOMACHINE_MISBEHAVIOUR_TYPE_URL: &str = "/lcp.lightclients.solomachine.v1.Misbehaviour";
+
+/// SENTINEL_HEADER_PATH is the path of the sign bytes for the header
+pub const SENTINEL_HEADER_PATH: &[u8] = b"solomachine:header";
+
+/// sign_bytes returns the bytes that the signers sign for the given path and data at the sequence
+pub fn sign_bytes(
+    sequence: u64,
+    timestamp: Time,
+    diversifier: &str,
+    path: Vec<u8>,
+    data: Vec<u8>,
+) -> Vec<u8> {
+    RawSignBytes {
+        sequence,
+        timestamp: timestamp.as_unix_timestamp_nanos() as u64,
+        diversifier: diversifier.to_string(),
+        path,
+        data,
+    }
+    .encode_to_vec()
+}
+
+/// commitment_path returns the path of the sign bytes for the state verification
+///
+/// The path is `{prefix}/{path}`, e.g. `ibc/clients/07-tendermint-0/clientState`.
+pub fn commitment_path(prefix: &[u8], path: &str) -> Vec<u8> {
+    let mut bz = prefix.to_vec();
+    bz.push(b'/');
+    bz.extend_from_slice(path.as_bytes());
+    bz
+}
+
+/// Header updates the signers of the solo machine and increments the sequence
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub timestamp: Time,
+    pub signatures: Vec<Vec<u8>>,
+    pub new_signers: Vec<Address>,
+    pub new_threshold: u32,
+    pub new_diversifier: String,
+}
+
+impl Header {
+    /// header_data returns the data that the current signers sign with the sentinel header path
+    pub fn header_data(&self) -> Vec<u8> {
+        RawHeaderData {
+            new_signers: self.new_signers.iter().map(|s| s.to_vec()).collect(),
+            new_threshold: self.new_threshold,
+            new_diversifier: self.new_diversifier.clone(),
+        }
+        .encode_to_vec()
+    }
+
+    /// sign_bytes returns the bytes that the current signers sign for the header at the sequence
+    pub fn sign_bytes(&self, sequence: u64, diversifier: &str) -> Vec<u8> {
+        sign_bytes(
+            sequence,
+            self.timestamp,
+            diversifier,
+            SENTINEL_HEADER_PATH.to_vec(),
+            self.header_data(),
+        )
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        RawHeader {
+            timestamp: value.timestamp.as_unix_timestamp_nanos() as u64,
+            signatures: value.signatures,
+            new_signers: value.new_signers.into_iter().map(Into::into).collect(),
+            new_threshold: value.new_threshold,
+            new_diversifier: value.new_diversifier,
+        }
+    }
+}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        Ok(Header {
+            timestamp: Time::from_unix_timestamp_nanos(raw.timestamp as u128)?,
+            signatures: raw.signatures,
+            new_signers: raw
+                .new_signers
+                .iter()
+                .map(|bz| Address::try_from(bz.as_slice()))
+                .collect::<Result<_, _>>()?,
+            new_threshold: raw.new_threshold,
+            new_diversifier: raw.new_diversifier,
+        })
+    }
+}
+
+impl From<Header> for Any {
+    fn from(value: Header) -> Self {
+        Any::new(
+            SOLOMACHINE_HEADER_TYPE_URL.to_string(),
+            RawHeader::from(value).encode_to_vec(),
+        )
+    }
+}
+
+impl TryFrom<Any> for Header {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            SOLOMACHINE_HEADER_TYPE_URL => {
+                Header::try_from(RawHeader::decode(&*raw.value).map_err(Error::proto_decode)?)
+            }
+            type_url => Err(Error::unexpected_header_type(type_url.to_owned())),
+        }
+    }
+}
+
+/// SignatureAndData is a data signed by the signers at a sequence
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignatureAndData {
+    pub signatures: Vec<Vec<u8>>,
+    pub path: Vec<u8>,
+    pub data: Vec<u8>,
+    pub timestamp: Time,
+}
+
+impl SignatureAndData {
+    pub fn sign_bytes(&self, sequence: u64, diversifier: &str) -> Vec<u8> {
+        sign_bytes(
+            sequence,
+            self.timestamp,
+            diversifier,
+            self.path.clone(),
+            self.data.clone(),
+        )
+    }
+}
+
+impl From<SignatureAndData> for RawSignatureAndData {
+    fn from(value: SignatureAndData) -> Self {
+        RawSignatureAndData {
+            signatures: value.signatures,
+            path: value.path,
+            data: value.data,
+            timestamp: value.timestamp.as_unix_timestamp_nanos() as u64,
+        }
+    }
+}
+
+impl TryFrom<RawSignatureAndData> for SignatureAndData {
+    type Error = Error;
+
+    fn try_from(raw: RawSignatureAndData) -> Result<Self, Self::Error> {
+        Ok(SignatureAndData {
+            signatures: raw.signatures,
+            path: raw.path,
+            data: raw.data,
+            timestamp: Time::from_unix_timestamp_nanos(raw.timestamp as u128)?,
+        })
+    }
+}
+
+/// Misbehaviour is a proof that the signers signed two different data for the same path at the same sequence
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub sequence: u64,
+    pub signature_one: SignatureAndData,
+    pub signature_two: SignatureAndData,
+}
+
+impl Misbehaviour {
+    /// validate_conflict returns an error if the signatures do not conflict with each other
+    ///
+    /// The signatures for different paths are honest proofs of different states, so they never conflict.
+    /// The signatures for the sentinel header path conflict only if they are for different headers.
+    pub fn validate_conflict(&self) -> Result<(), Error> {
+        let (one, two) = (&self.signature_one, &self.signature_two);
+        if one.path != two.path {
+            return Err(Error::invalid_misbehaviour(
+                "signed paths must be the same".to_string(),
+            ));
+        }
+        if one.path == SENTINEL_HEADER_PATH {
+            let decode = |sig: &SignatureAndData| {
+                RawHeaderData::decode(&*sig.data)
+                    .map_err(|e| Error::invalid_misbehaviour(format!("invalid header data: {}", e)))
+            };
+            if decode(one)? == decode(two)? {
+                return Err(Error::invalid_misbehaviour(
+                    "signed headers must be different".to_string(),
+                ));
+            }
+        } else if one.data == two.data {
+            return Err(Error::invalid_misbehaviour(
+                "signed data must be different".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        RawMisbehaviour {
+            sequence: value.sequence,
+            signature_one: Some(value.signature_one.into()),
+            signature_two: Some(value.signature_two.into()),
+        }
+    }
+}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        Ok(Misbehaviour {
+            sequence: raw.sequence,
+            signature_one: raw
+                .signature_one
+                .ok_or_else(|| {
+                    Error::invalid_misbehaviour("signature_one must be non-nil".to_string())
+                })?
+                .try_into()?,
+            signature_two: raw
+                .signature_two
+                .ok_or_else(|| {
+                    Error::invalid_misbehaviour("signature_two must be non-nil".to_string())
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(value: Misbehaviour) -> Self {
+        Any::new(
+            SOLOMACHINE_MISBEHAVIOUR_TYPE_URL.to_string(),
+            RawMisbehaviour::from(value).encode_to_vec(),
+        )
+    }
+}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => Misbehaviour::try_from(
+                RawMisbehaviour::decode(&*raw.value).map_err(Error::proto_decode)?,
+            ),
+            type_url => Err(Error::unexpected_misbehaviour_type(type_url.to_owned())),
+        }
+    }
+}
+
+/// TimestampedSignatureData is the proof of the state verification
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimestampedSignatureData {
+    pub signatures: Vec<Vec<u8>>,
+    pub timestamp: Time,
+}
+
+impl From<TimestampedSignatureData> for Vec<u8> {
+    fn from(value: TimestampedSignatureData) -> Self {
+        RawTimestampedSignatureData {
+            signatures: value.signatures,
+            timestamp: value.timestamp.as_unix_timestamp_nanos() as u64,
+        }
+        .encode_to_vec()
+    }
+}
+
+impl TryFrom<&[u8]> for TimestampedSignatureData {
+    type Error = Error;
+
+    fn try_from(bz: &[u8]) -> Result<Self, Self::Error> {
+        let raw = RawTimestampedSignatureData::decode(bz).map_err(Error::proto_decode)?;
+        Ok(TimestampedSignatureData {
+            signatures: raw.signatures,
+            timestamp: Time::from_unix_timestamp_nanos(raw.timestamp as u128)?,
+        })
+    }
+}
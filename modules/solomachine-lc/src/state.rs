@@ -0,0 +1,175 @@
+use crate::errors::Error;
+use crate::prelude::*;
+use crypto::Address;
+use light_client::commitments::{gen_state_id_from_any, StateID};
+use light_client::types::proto::lcp::lightclients::solomachine::v1::{
+    ClientState as RawClientState, ConsensusState as RawConsensusState,
+};
+use light_client::types::{Any, Height, Time};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+pub const SOLOMACHINE_CLIENT_STATE_TYPE_URL: &str = "/lcp.lightclients.solomachine.v1.ClientState";
+pub const SOLOMACHINE_CONSENSUS_STATE_TYPE_URL: &str =
+    "/lcp.lightclients.solomachine.v1.ConsensusState";
+
+/// The revision number of the solo machine is always zero, and the revision height is the sequence.
+pub const SOLOMACHINE_REVISION_NUMBER: u64 = 0;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientState {
+    /// latest sequence of the client state
+    pub sequence: u64,
+    /// addresses of the keys that sign the states of the solo machine
+    pub signers: Vec<Address>,
+    /// the number of signatures required to sign the states
+    pub threshold: u32,
+    /// diversifier allows the same keys to be reused across different solo machines
+    pub diversifier: String,
+    /// whether the client is frozen due to misbehaviour
+    pub is_frozen: bool,
+}
+
+impl ClientState {
+    pub fn latest_height(&self) -> Height {
+        Height::new(SOLOMACHINE_REVISION_NUMBER, self.sequence)
+    }
+
+    /// validate checks that the signers are unique and the threshold can be reached
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.signers.is_empty() {
+            return Err(Error::invalid_client_state(
+                "signers must not be empty".to_string(),
+            ));
+        }
+        for (i, signer) in self.signers.iter().enumerate() {
+            if self.signers[..i].contains(signer) {
+                return Err(Error::duplicate_signer(*signer));
+            }
+        }
+        if self.threshold == 0 || self.threshold as usize > self.signers.len() {
+            return Err(Error::invalid_client_state(format!(
+                "threshold must be between 1 and the number of signers: threshold={} signers={}",
+                self.threshold,
+                self.signers.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// freeze returns a frozen client state
+    pub fn freeze(mut self) -> Self {
+        self.is_frozen = true;
+        self
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            sequence: value.sequence,
+            signers: value.signers.into_iter().map(Into::into).collect(),
+            threshold: value.threshold,
+            diversifier: value.diversifier,
+            is_frozen: value.is_frozen,
+        }
+    }
+}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        Ok(ClientState {
+            sequence: raw.sequence,
+            signers: raw
+                .signers
+                .iter()
+                .map(|bz| Address::try_from(bz.as_slice()))
+                .collect::<Result<_, _>>()?,
+            threshold: raw.threshold,
+            diversifier: raw.diversifier,
+            is_frozen: raw.is_frozen,
+        })
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(value: ClientState) -> Self {
+        Any::new(
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+            RawClientState::from(value).encode_to_vec(),
+        )
+    }
+}
+
+impl TryFrom<Any> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => ClientState::try_from(
+                RawClientState::decode(&*raw.value).map_err(Error::proto_decode)?,
+            ),
+            type_url => Err(Error::unexpected_client_type(type_url.to_owned())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusState {
+    pub sequence: u64,
+    pub timestamp: Time,
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        RawConsensusState {
+            sequence: value.sequence,
+            timestamp: value.timestamp.as_unix_timestamp_nanos() as u64,
+        }
+    }
+}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        Ok(ConsensusState {
+            sequence: raw.sequence,
+            timestamp: Time::from_unix_timestamp_nanos(raw.timestamp as u128)?,
+        })
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(value: ConsensusState) -> Self {
+        Any::new(
+            SOLOMACHINE_CONSENSUS_STATE_TYPE_URL.to_string(),
+            RawConsensusState::from(value).encode_to_vec(),
+        )
+    }
+}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CONSENSUS_STATE_TYPE_URL => ConsensusState::try_from(
+                RawConsensusState::decode(&*raw.value).map_err(Error::proto_decode)?,
+            ),
+            type_url => Err(Error::unexpected_client_type(type_url.to_owned())),
+        }
+    }
+}
+
+pub fn gen_state_id(
+    client_state: ClientState,
+    consensus_state: ConsensusState,
+) -> Result<StateID, Error> {
+    Ok(gen_state_id_from_any(
+        &client_state.into(),
+        &consensus_state.into(),
+    )?)
+}
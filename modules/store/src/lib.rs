@@ -20,7 +20,7 @@ mod prelude {
 }
 
 pub use crate::errors::{Error, Result};
-pub use crate::store::{KVStore, TxId};
+pub use crate::store::{KVStore, TxId, KV};
 
 mod errors;
 #[cfg(feature = "std")]
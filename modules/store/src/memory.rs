@@ -1,8 +1,8 @@
 use crate::prelude::*;
-use crate::store::TxId;
+use crate::store::{is_in_scan_range, merge_scan, TxId, KV};
 use crate::transaction::{CommitStore, CreatedTx, Tx, TxAccessor};
 use crate::{KVStore, Result};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 // MemStore is only available for testing purposes
 #[derive(Default, Debug)]
@@ -40,6 +40,28 @@ impl KVStore for MemStore {
             self.committed_data.remove(key);
         }
     }
+
+    fn scan_prefix(&self, prefix: &[u8], start_after: Option<&[u8]>, limit: usize) -> Vec<KV> {
+        let committed: BTreeMap<&Vec<u8>, &Vec<u8>> = self
+            .committed_data
+            .iter()
+            .filter(|(k, _)| is_in_scan_range(k, prefix, start_after))
+            .collect();
+        let uncommitted = if self.running_tx_exists {
+            self.uncommitted_data
+                .iter()
+                .filter(|(k, _)| is_in_scan_range(k, prefix, start_after))
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect()
+        } else {
+            BTreeMap::default()
+        };
+        merge_scan(
+            committed.into_iter().map(|(k, v)| (k.clone(), v.clone())),
+            uncommitted,
+            limit,
+        )
+    }
 }
 
 impl TxAccessor for MemStore {
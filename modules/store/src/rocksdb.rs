@@ -1,13 +1,14 @@
+use crate::store::{is_in_scan_range, merge_scan, scan_start_key, KV};
 use crate::transaction::{CommitStore, CreatedTx, Tx, TxAccessor, UpdateKey};
 use crate::{Error, KVStore, Result, TxId};
 use core::marker::PhantomData;
 use log::*;
 use ouroboros::self_referencing;
 use rocksdb::{
-    Error as RocksDBError, SnapshotWithThreadMode, Transaction, TransactionDB, TransactionOptions,
-    WriteOptions, DB,
+    Direction, Error as RocksDBError, IteratorMode, SnapshotWithThreadMode, Transaction,
+    TransactionDB, TransactionOptions, WriteOptions, DB,
 };
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 use std::rc::Rc;
 use std::sync::{Mutex, MutexGuard};
@@ -95,6 +96,10 @@ impl KVStore for RocksDBStore {
     fn remove(&mut self, key: &[u8]) {
         self.borrow_db().remove(key)
     }
+
+    fn scan_prefix(&self, prefix: &[u8], start_after: Option<&[u8]>, limit: usize) -> Vec<KV> {
+        self.borrow_db().scan_prefix(prefix, start_after, limit)
+    }
 }
 
 impl TxAccessor for RocksDBStore {
@@ -233,6 +238,48 @@ impl InnerDB {
             Self::ReadOnlyDB(db) => db.delete(key).unwrap(),
         }
     }
+
+    pub(crate) fn scan_prefix(
+        &self,
+        prefix: &[u8],
+        start_after: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<KV> {
+        let mode = IteratorMode::From(scan_start_key(prefix, start_after), Direction::Forward);
+        match self {
+            Self::TransactionDB(db) => scan_db_iterator(db.iterator(mode), prefix, start_after)
+                .take(limit)
+                .collect(),
+            Self::ReadOnlyDB(db) => scan_db_iterator(db.iterator(mode), prefix, start_after)
+                .take(limit)
+                .collect(),
+        }
+    }
+}
+
+/// `scan_db_iterator` converts a forward iterator of RocksDB into an iterator over the scan range
+fn scan_db_iterator<'a>(
+    iter: impl Iterator<Item = core::result::Result<(Box<[u8]>, Box<[u8]>), RocksDBError>> + 'a,
+    prefix: &'a [u8],
+    start_after: Option<&'a [u8]>,
+) -> impl Iterator<Item = KV> + 'a {
+    iter.map(|r| r.unwrap())
+        .take_while(move |(k, _)| k.starts_with(prefix))
+        .filter(move |(k, _)| is_in_scan_range(k, prefix, start_after))
+        .map(|(k, v)| (k.into_vec(), v.into_vec()))
+}
+
+/// `scan_buffer` returns the entries of `buffer` within the scan range
+fn scan_buffer(
+    buffer: &HashMap<Vec<u8>, Option<Vec<u8>>>,
+    prefix: &[u8],
+    start_after: Option<&[u8]>,
+) -> BTreeMap<Vec<u8>, Option<Vec<u8>>> {
+    buffer
+        .iter()
+        .filter(|(k, _)| is_in_scan_range(k, prefix, start_after))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect()
 }
 
 /// StoreTransaction implements multiple transaction types
@@ -283,6 +330,14 @@ impl<'a> KVStore for StoreTransaction<'a> {
             StoreTransaction::ReadSnapshot(stx) => stx.remove(key),
         }
     }
+
+    fn scan_prefix(&self, prefix: &[u8], start_after: Option<&[u8]>, limit: usize) -> Vec<KV> {
+        match self {
+            StoreTransaction::Read(stx) => stx.scan_prefix(prefix, start_after, limit),
+            StoreTransaction::Update(stx) => stx.scan_prefix(prefix, start_after, limit),
+            StoreTransaction::ReadSnapshot(stx) => stx.scan_prefix(prefix, start_after, limit),
+        }
+    }
 }
 
 /// ReadTransaction is a `read-only` transaction.
@@ -309,6 +364,15 @@ impl<'a> KVStore for ReadTransaction<'a> {
     fn remove(&mut self, key: &[u8]) {
         self.buffer.insert(key.to_vec(), None);
     }
+
+    fn scan_prefix(&self, prefix: &[u8], start_after: Option<&[u8]>, limit: usize) -> Vec<KV> {
+        let mode = IteratorMode::From(scan_start_key(prefix, start_after), Direction::Forward);
+        merge_scan(
+            scan_db_iterator(self.snapshot.iterator(mode), prefix, start_after),
+            scan_buffer(&self.buffer, prefix, start_after),
+            limit,
+        )
+    }
 }
 
 /// UpdateTransaction is a `writable` transaction
@@ -347,6 +411,16 @@ impl<'a> KVStore for UpdateTransaction<'a> {
     fn remove(&mut self, key: &[u8]) {
         self.with_tx(|tx| tx.delete(key)).unwrap()
     }
+
+    fn scan_prefix(&self, prefix: &[u8], start_after: Option<&[u8]>, limit: usize) -> Vec<KV> {
+        let mode = IteratorMode::From(scan_start_key(prefix, start_after), Direction::Forward);
+        // the iterator of the transaction's snapshot also reflects the uncommitted writes in the transaction
+        self.with_snapshot(|snapshot| {
+            scan_db_iterator(snapshot.iterator(mode), prefix, start_after)
+                .take(limit)
+                .collect()
+        })
+    }
 }
 
 /// ReadSnapshot is a `read-only` transaction.
@@ -373,6 +447,15 @@ impl<'a> KVStore for ReadSnapshot<'a> {
     fn remove(&mut self, key: &[u8]) {
         self.buffer.insert(key.to_vec(), None);
     }
+
+    fn scan_prefix(&self, prefix: &[u8], start_after: Option<&[u8]>, limit: usize) -> Vec<KV> {
+        let mode = IteratorMode::From(scan_start_key(prefix, start_after), Direction::Forward);
+        merge_scan(
+            scan_db_iterator(self.snapshot.iterator(mode), prefix, start_after),
+            scan_buffer(&self.buffer, prefix, start_after),
+            limit,
+        )
+    }
 }
 
 /// RocksDBTx is a transaction handle corresponding to `StoreTransaction`
@@ -554,6 +637,87 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_scan_prefix() {
+        let _ = env_logger::try_init();
+        let tmp_dir = TempDir::new().unwrap();
+        let mut store = RocksDBStore::open(tmp_dir.as_ref());
+
+        // pre: k0..k4 -> v0..v4, other -> v0
+        {
+            let tx = store.create_transaction(Some("test".into())).unwrap();
+            let tx = tx.prepare().unwrap();
+            store.begin(&tx).unwrap();
+            for i in 0..5 {
+                store.tx_set(tx.get_id(), key(i), value(i)).unwrap();
+            }
+            store
+                .tx_set(tx.get_id(), b"other".to_vec(), value(0))
+                .unwrap();
+            // uncommitted writes are visible in the update tx
+            assert_eq!(
+                store
+                    .tx_scan_prefix(tx.get_id(), b"k", None, usize::MAX)
+                    .unwrap(),
+                (0..5).map(|i| (key(i), value(i))).collect::<Vec<_>>()
+            );
+            store.commit(tx).unwrap();
+        }
+
+        // case1: paginate keys with `start_after`
+        assert_eq!(
+            store.scan_prefix(b"k", None, 2),
+            vec![(key(0), value(0)), (key(1), value(1))]
+        );
+        assert_eq!(
+            store.scan_prefix(b"k", Some(&key(1)), 2),
+            vec![(key(2), value(2)), (key(3), value(3))]
+        );
+        assert_eq!(
+            store.scan_prefix(b"k", Some(&key(3)), 2),
+            vec![(key(4), value(4))]
+        );
+        assert!(store.scan_prefix(b"k", Some(&key(4)), 2).is_empty());
+        assert!(store.scan_prefix(b"x", None, 2).is_empty());
+
+        // case2: the buffer of a read tx is merged into the results
+        {
+            let tx = store.create_transaction(None).unwrap();
+            let tx = tx.prepare().unwrap();
+            store.begin(&tx).unwrap();
+            store.tx_remove(tx.get_id(), &key(1)).unwrap();
+            store.tx_set(tx.get_id(), key(2), value(0)).unwrap();
+            store.tx_set(tx.get_id(), key(5), value(5)).unwrap();
+            assert_eq!(
+                store
+                    .tx_scan_prefix(tx.get_id(), b"k", Some(&key(0)), 3)
+                    .unwrap(),
+                vec![(key(2), value(0)), (key(3), value(3)), (key(4), value(4))]
+            );
+            assert_eq!(
+                store
+                    .tx_scan_prefix(tx.get_id(), b"k", Some(&key(4)), 3)
+                    .unwrap(),
+                vec![(key(5), value(5))]
+            );
+            store.commit(tx).unwrap();
+        }
+
+        // case3: removals in an update tx are reflected
+        {
+            let tx = store.create_transaction(Some("test".into())).unwrap();
+            let tx = tx.prepare().unwrap();
+            store.begin(&tx).unwrap();
+            store.tx_remove(tx.get_id(), &key(0)).unwrap();
+            assert_eq!(
+                store.tx_scan_prefix(tx.get_id(), b"k", None, 1).unwrap(),
+                vec![(key(1), value(1))]
+            );
+            store.rollback(tx);
+        }
+        assert_eq!(store.scan_prefix(b"k", None, 1), vec![(key(0), value(0))]);
+    }
+
     #[test]
     fn test_concurrent_write_tx_with_same_update_key_1() {
         let (_tmp_dir, store, [r1, r2]) = get_test_helpers::<2>(vec![]);
@@ -10,6 +10,67 @@ pub trait KVStore {
     fn set(&mut self, key: Vec<u8>, value: Vec<u8>);
     fn get(&self, key: &[u8]) -> Option<Vec<u8>>;
     fn remove(&mut self, key: &[u8]);
+
+    /// `scan_prefix` returns key-value pairs whose keys start with `prefix` in ascending key order
+    /// if `start_after` is Some(k), the iteration starts from the first key strictly greater than `k`
+    /// at most `limit` pairs are returned, and the caller can continue the iteration by passing the last returned key as `start_after`
+    fn scan_prefix(&self, prefix: &[u8], start_after: Option<&[u8]>, limit: usize) -> Vec<KV>;
+}
+
+/// `KV` is a key-value pair returned by `KVStore::scan_prefix`
+pub type KV = (Vec<u8>, Vec<u8>);
+
+/// `is_in_scan_range` returns true if `key` is within the range specified by `prefix` and `start_after`
+#[cfg(feature = "std")]
+pub(crate) fn is_in_scan_range(key: &[u8], prefix: &[u8], start_after: Option<&[u8]>) -> bool {
+    key.starts_with(prefix) && start_after.map_or(true, |s| key > s)
+}
+
+/// `scan_start_key` returns the key where an ascending iteration for `scan_prefix` should start
+#[cfg(feature = "std")]
+pub(crate) fn scan_start_key<'a>(prefix: &'a [u8], start_after: Option<&'a [u8]>) -> &'a [u8] {
+    match start_after {
+        Some(s) if s > prefix => s,
+        _ => prefix,
+    }
+}
+
+/// `merge_scan` merges `base` pairs with `overlay` entries and returns at most `limit` pairs
+/// `base` must yield pairs in ascending key order within the scan range
+/// `overlay` contains pending changes in the scan range, where `None` indicates the key was removed
+#[cfg(feature = "std")]
+pub(crate) fn merge_scan(
+    base: impl Iterator<Item = KV>,
+    overlay: alloc::collections::BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+    limit: usize,
+) -> Vec<KV> {
+    let mut base = base.peekable();
+    let mut overlay = overlay.into_iter().peekable();
+    let mut res = Vec::new();
+    while res.len() < limit {
+        let take_overlay = match (base.peek(), overlay.peek()) {
+            (None, None) => break,
+            (Some(_), None) => false,
+            (None, Some(_)) => true,
+            (Some((bk, _)), Some((ok, _))) => {
+                if bk == ok {
+                    // the overlay entry shadows the base entry
+                    base.next();
+                    true
+                } else {
+                    ok < bk
+                }
+            }
+        };
+        if take_overlay {
+            if let (k, Some(v)) = overlay.next().unwrap() {
+                res.push((k, v));
+            }
+        } else {
+            res.push(base.next().unwrap());
+        }
+    }
+    res
 }
 
 impl KVStore for Box<dyn KVStore> {
@@ -22,6 +83,9 @@ impl KVStore for Box<dyn KVStore> {
     fn remove(&mut self, key: &[u8]) {
         self.as_mut().remove(key)
     }
+    fn scan_prefix(&self, prefix: &[u8], start_after: Option<&[u8]>, limit: usize) -> Vec<KV> {
+        self.as_ref().scan_prefix(prefix, start_after, limit)
+    }
 }
 
 impl<T: KVStore> KVStore for Rc<RefCell<T>> {
@@ -34,6 +98,9 @@ impl<T: KVStore> KVStore for Rc<RefCell<T>> {
     fn remove(&mut self, key: &[u8]) {
         self.borrow_mut().remove(key)
     }
+    fn scan_prefix(&self, prefix: &[u8], start_after: Option<&[u8]>, limit: usize) -> Vec<KV> {
+        self.borrow().scan_prefix(prefix, start_after, limit)
+    }
 }
 
 #[derive(
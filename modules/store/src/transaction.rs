@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use crate::{KVStore, Result, TxId};
+use crate::{KVStore, Result, TxId, KV};
 
 /// `UpdateKey` is a hint to the store to control concurrent transactions
 pub type UpdateKey = String;
@@ -60,4 +60,15 @@ pub trait TxAccessor {
     fn tx_remove(&mut self, tx_id: TxId, key: &[u8]) -> Result<()> {
         self.run_in_mut_tx(tx_id, |tx| tx.remove(key))
     }
+
+    /// `tx_scan_prefix` returns key-value pairs whose keys start with `prefix` in a specified transaction
+    fn tx_scan_prefix(
+        &self,
+        tx_id: TxId,
+        prefix: &[u8],
+        start_after: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<Vec<KV>> {
+        self.run_in_tx(tx_id, |tx| tx.scan_prefix(prefix, start_after, limit))
+    }
 }
@@ -1,8 +1,13 @@
 use crate::errors::Error;
-use crate::header::Header;
+use crate::header::{BisectionHeader, Header, TENDERMINT_BISECTION_HEADER_TYPE_URL};
+use crate::misbehaviour::Misbehaviour;
 use crate::prelude::*;
-use crate::state::{canonicalize_state, gen_state_id, ClientState, ConsensusState};
+use crate::state::{
+    allow_update, canonicalize_state, gen_state_id, is_matching_client_state, upgrade_client_state,
+    upgrade_consensus_state, with_latest_height, ClientState, ConsensusState, UpgradedClientState,
+};
 use core::str::FromStr;
+use core::time::Duration;
 use crypto::Keccak256;
 use ibc::clients::ics07_tendermint::client_state::{
     ClientState as TendermintClientState, TENDERMINT_CLIENT_STATE_TYPE_URL,
@@ -24,17 +29,25 @@ use ibc::core::ics23_commitment::commitment::{
 };
 use ibc::core::ics23_commitment::merkle::{apply_prefix, MerkleProof};
 use ibc::core::ics24_host::Path;
-use lcp_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
+use lcp_proto::ibc::core::commitment::v1::{MerklePath, MerkleProof as RawMerkleProof};
 use light_client::commitments::{
-    CommitmentContext, CommitmentPrefix, StateCommitment, TrustingPeriodContext,
-    UpdateClientCommitment,
+    CommitmentContext, CommitmentPrefix, MisbehaviourCommitment, PrevState,
+    RecoverClientCommitment, StateCommitment, TrustingPeriodContext, UpdateClientCommitment,
 };
-use light_client::types::{Any, ClientId, Height, Time};
+use light_client::types::{Any, ClientId, ClientStatus, Height, Time};
 use light_client::{
     ibc::IBCContext, CreateClientResult, Error as LightClientError, HostClientReader, LightClient,
-    LightClientRegistry, StateVerificationResult, UpdateClientResult,
+    LightClientRegistry, MisbehaviourResult, RecoverClientResult, StateVerificationResult,
+    UpdateClientResult, UpgradeClientResult,
 };
 use log::*;
+use prost::Message;
+use tendermint::block::Height as TmHeight;
+use tendermint::chain::Id as TmChainId;
+use tendermint::Time as TmTime;
+use tendermint_light_client_verifier::options::Options;
+use tendermint_light_client_verifier::types::{TrustedBlockState, UntrustedBlockState};
+use tendermint_light_client_verifier::{ProdVerifier, Verdict, Verifier};
 
 #[derive(Default)]
 pub struct TendermintLightClient;
@@ -53,6 +66,52 @@ impl LightClient for TendermintLightClient {
         Ok(client_state.latest_height().into())
     }
 
+    fn status(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: &ClientId,
+    ) -> Result<ClientStatus, LightClientError> {
+        let client_state: ClientState = ctx.client_state(client_id)?.try_into()?;
+        if client_state.is_frozen() {
+            return Ok(ClientStatus::Frozen);
+        }
+        let latest_consensus_state: ConsensusState = ctx
+            .consensus_state(client_id, &client_state.latest_height().into())?
+            .try_into()?;
+        // if the host timestamp is before the consensus state's timestamp, the client is not expired yet
+        let expired = match ctx
+            .host_timestamp()
+            .duration_since(latest_consensus_state.timestamp)
+        {
+            Ok(elapsed) => client_state.expired(elapsed),
+            Err(_) => false,
+        };
+        if expired {
+            Ok(ClientStatus::Expired)
+        } else {
+            Ok(ClientStatus::Active)
+        }
+    }
+
+    fn consensus_state_timestamp(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Time, LightClientError> {
+        let consensus_state: ConsensusState = ctx.consensus_state(client_id, height)?.try_into()?;
+        Ok(consensus_state.timestamp.into())
+    }
+
+    fn trusting_period(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: &ClientId,
+    ) -> Result<Option<Duration>, LightClientError> {
+        let client_state: ClientState = ctx.client_state(client_id)?.try_into()?;
+        Ok(Some(client_state.trusting_period))
+    }
+
     fn create_client(
         &self,
         _: &dyn HostClientReader,
@@ -92,48 +151,14 @@ impl LightClient for TendermintLightClient {
         client_id: ClientId,
         any_header: Any,
     ) -> Result<UpdateClientResult, LightClientError> {
+        if any_header.type_url == TENDERMINT_BISECTION_HEADER_TYPE_URL {
+            return Self::update_client_with_bisection(ctx, client_id, any_header.try_into()?);
+        }
         let header = Header::try_from(any_header.clone())?;
 
         // Read client state from the host chain store.
         let client_state: ClientState = ctx.client_state(&client_id)?.try_into()?;
-
-        if client_state.is_frozen() {
-            return Err(Error::ics02(ICS02Error::ClientFrozen {
-                client_id: client_id.into(),
-            })
-            .into());
-        }
-
-        // Read consensus state from the host chain store.
-        let latest_consensus_state: ConsensusState = ctx
-            .consensus_state(&client_id, &client_state.latest_height().into())
-            .map_err(|_| {
-                Error::ics02(ICS02Error::ConsensusStateNotFound {
-                    client_id: client_id.clone().into(),
-                    height: client_state.latest_height(),
-                })
-            })?
-            .try_into()?;
-
-        debug!("latest consensus state: {:?}", latest_consensus_state);
-
-        let now = ctx.host_timestamp();
-        let duration = now
-            .duration_since(latest_consensus_state.timestamp().into_tm_time().unwrap())
-            .map_err(|_| {
-                Error::ics02(ICS02Error::InvalidConsensusStateTimestamp {
-                    time1: latest_consensus_state.timestamp(),
-                    time2: now.into(),
-                })
-            })?;
-
-        if client_state.expired(duration) {
-            return Err(Error::ics02(ICS02Error::HeaderNotWithinTrustPeriod {
-                latest_time: latest_consensus_state.timestamp(),
-                update_time: header.timestamp(),
-            })
-            .into());
-        }
+        Self::validate_updatable(ctx, &client_id, &client_state, &header)?;
 
         let height = header.height().into();
         let header_timestamp: Time = header.timestamp().into();
@@ -210,6 +235,309 @@ impl LightClient for TendermintLightClient {
         })
     }
 
+    fn submit_misbehaviour(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: ClientId,
+        any_misbehaviour: Any,
+    ) -> Result<MisbehaviourResult, LightClientError> {
+        let misbehaviour = Misbehaviour::try_from(any_misbehaviour.clone())?;
+
+        // Read client state from the host chain store.
+        let client_state: ClientState = ctx.client_state(&client_id)?.try_into()?;
+
+        if client_state.is_frozen() {
+            return Err(Error::ics02(ICS02Error::ClientFrozen {
+                client_id: client_id.into(),
+            })
+            .into());
+        }
+
+        // Check the misbehaviour using ibc-rs's logic: both headers must be verified against the trusted states
+        // and they must conflict with each other. This returns the client state which is frozen.
+        let new_client_state = client_state
+            .check_misbehaviour_and_update_state(
+                &IBCContext::<TendermintClientState, TendermintConsensusState>::new(ctx),
+                client_id.clone().into(),
+                any_misbehaviour.into(),
+            )
+            .map_err(|e| {
+                Error::ics02(ICS02Error::MisbehaviourHandlingFailure {
+                    reason: e.to_string(),
+                })
+            })?;
+        let new_client_state = ClientState(
+            downcast_client_state::<TendermintClientState>(new_client_state.as_ref())
+                .ok_or_else(|| {
+                    Error::ics02(ICS02Error::ClientArgsTypeMismatch {
+                        client_type: new_client_state.client_type(),
+                    })
+                })?
+                .clone(),
+        );
+        let frozen_height = new_client_state.frozen_height().ok_or_else(|| {
+            Error::ics02(ICS02Error::MisbehaviourHandlingFailure {
+                reason: "client state must be frozen after the misbehaviour".to_string(),
+            })
+        })?;
+
+        let lc_opts = client_state
+            .as_light_client_options()
+            .map_err(Error::ics02)?;
+        let mut prev_states = Vec::new();
+        let mut context = CommitmentContext::Empty;
+        for header in [misbehaviour.header1(), misbehaviour.header2()] {
+            let trusted_consensus_state: ConsensusState = ctx
+                .consensus_state(&client_id, &header.trusted_height.into())
+                .map_err(|_| {
+                    Error::ics02(ICS02Error::ConsensusStateNotFound {
+                        client_id: client_id.clone().into(),
+                        height: header.trusted_height,
+                    })
+                })?
+                .try_into()?;
+            let trusted_state_timestamp: Time = trusted_consensus_state.timestamp().into();
+            let header_timestamp: Time = header.timestamp().into();
+            prev_states.push(PrevState::new(
+                header.trusted_height.into(),
+                gen_state_id(canonicalize_state(&client_state), trusted_consensus_state)?,
+            ));
+            context = context.aggregate(
+                TrustingPeriodContext::new(
+                    lc_opts.trusting_period,
+                    lc_opts.clock_drift,
+                    header_timestamp,
+                    trusted_state_timestamp,
+                )
+                .into(),
+            );
+        }
+
+        Ok(MisbehaviourResult {
+            new_any_client_state: new_client_state.into(),
+            commitment: MisbehaviourCommitment {
+                client_id,
+                frozen_height: frozen_height.into(),
+                prev_states,
+                context,
+            }
+            .into(),
+        })
+    }
+
+    fn recover_client(
+        &self,
+        ctx: &dyn HostClientReader,
+        subject_client_id: ClientId,
+        substitute_client_id: ClientId,
+    ) -> Result<RecoverClientResult, LightClientError> {
+        // This follows ibc-go's MsgRecoverClient: the subject client must be expired or frozen,
+        // and the substitute client must be active and match the subject client except for the fields allowed to differ.
+        let subject_status = self.status(ctx, &subject_client_id)?;
+        if subject_status == ClientStatus::Active {
+            return Err(Error::invalid_recover_client(format!(
+                "subject client must be expired or frozen: client_id={} status={}",
+                subject_client_id, subject_status
+            ))
+            .into());
+        }
+        let subject_client_state: ClientState = ctx.client_state(&subject_client_id)?.try_into()?;
+        // ICS-07: a frozen client can be recovered only if `allow_update.after_misbehaviour` is true,
+        // and an expired client only if `allow_update.after_expiry` is true
+        let allow_update = allow_update(&subject_client_state);
+        let allowed = match subject_status {
+            ClientStatus::Frozen => allow_update.after_misbehaviour,
+            ClientStatus::Expired => allow_update.after_expiry,
+            ClientStatus::Active => unreachable!(),
+        };
+        if !allowed {
+            return Err(Error::invalid_recover_client(format!(
+                "subject client is not allowed to be recovered: client_id={} status={} allow_update_after_expiry={} allow_update_after_misbehaviour={}",
+                subject_client_id, subject_status, allow_update.after_expiry, allow_update.after_misbehaviour
+            ))
+            .into());
+        }
+
+        let substitute_status = self.status(ctx, &substitute_client_id)?;
+        if substitute_status != ClientStatus::Active {
+            return Err(Error::invalid_recover_client(format!(
+                "substitute client must be active: client_id={} status={}",
+                substitute_client_id, substitute_status
+            ))
+            .into());
+        }
+
+        let substitute_client_state: ClientState =
+            ctx.client_state(&substitute_client_id)?.try_into()?;
+
+        let subject_height: Height = subject_client_state.latest_height().into();
+        let substitute_height: Height = substitute_client_state.latest_height().into();
+        if substitute_height <= subject_height {
+            return Err(Error::invalid_recover_client(format!(
+                "substitute client height must be greater than subject client height: substitute_height={} subject_height={}",
+                substitute_height, subject_height
+            ))
+            .into());
+        }
+        if !is_matching_client_state(&subject_client_state, &substitute_client_state) {
+            return Err(Error::invalid_recover_client(
+                "subject client state does not match substitute client state".to_string(),
+            )
+            .into());
+        }
+
+        let subject_consensus_state: ConsensusState = ctx
+            .consensus_state(&subject_client_id, &subject_height)?
+            .try_into()?;
+        let substitute_consensus_state: ConsensusState = ctx
+            .consensus_state(&substitute_client_id, &substitute_height)?
+            .try_into()?;
+
+        // the substitute client is active, so its client state is not frozen
+        // and the other fields are the same as the subject's except for latest_height, trusting_period and chain_id
+        let new_client_state = substitute_client_state;
+        let prev_state_id = gen_state_id(
+            canonicalize_state(&subject_client_state),
+            subject_consensus_state,
+        )?;
+        let new_state_id = gen_state_id(
+            canonicalize_state(&new_client_state),
+            substitute_consensus_state.clone(),
+        )?;
+        let timestamp: Time = substitute_consensus_state.timestamp.into();
+        let new_any_client_state: Any = new_client_state.into();
+
+        Ok(RecoverClientResult {
+            new_any_client_state: new_any_client_state.clone(),
+            new_any_consensus_state: substitute_consensus_state.into(),
+            height: substitute_height,
+            // the recovery is not verified by a header, so it is committed as a distinct type
+            // that the LCP client accepts only with the approval of an authority
+            commitment: RecoverClientCommitment {
+                prev_state_id,
+                new_state_id,
+                new_state: new_any_client_state,
+                prev_height: subject_height,
+                new_height: substitute_height,
+                timestamp,
+            }
+            .into(),
+        })
+    }
+
+    fn upgrade_client(
+        &self,
+        ctx: &dyn HostClientReader,
+        client_id: ClientId,
+        upgraded_any_client_state: Any,
+        upgraded_any_consensus_state: Any,
+        proof_upgrade_client: Vec<u8>,
+        proof_upgrade_consensus_state: Vec<u8>,
+    ) -> Result<UpgradeClientResult, LightClientError> {
+        // This follows ibc-go's VerifyUpgradeAndUpdateState: the upgraded states must be committed
+        // under the upgrade path at the latest height of the client, and the client must be active.
+        let status = self.status(ctx, &client_id)?;
+        if status != ClientStatus::Active {
+            return Err(Error::invalid_upgrade_client(format!(
+                "client must be active: client_id={} status={}",
+                client_id, status
+            ))
+            .into());
+        }
+
+        let client_state: ClientState = ctx.client_state(&client_id)?.try_into()?;
+        if client_state.upgrade_path.is_empty() {
+            return Err(Error::invalid_upgrade_client(format!(
+                "upgrade path is not set: client_id={}",
+                client_id
+            ))
+            .into());
+        }
+
+        let upgraded_client_state =
+            UpgradedClientState::try_from(upgraded_any_client_state.clone())?;
+        let upgraded_consensus_state =
+            ConsensusState::try_from(upgraded_any_consensus_state.clone())?;
+
+        let latest_height = client_state.latest_height();
+        let upgraded_height = upgraded_client_state.latest_height;
+        if upgraded_height <= latest_height {
+            return Err(Error::invalid_upgrade_client(format!(
+                "upgraded client height must be greater than current client height: upgraded_height={} latest_height={}",
+                upgraded_height, latest_height
+            ))
+            .into());
+        }
+
+        let consensus_state: ConsensusState = ctx
+            .consensus_state(&client_id, &latest_height.into())?
+            .try_into()?;
+
+        verify_upgraded_state(
+            &client_state,
+            proof_upgrade_client,
+            consensus_state.root(),
+            upgrade_merkle_path(
+                &client_state.upgrade_path,
+                latest_height.revision_height(),
+                UPGRADED_CLIENT_KEY,
+            )?,
+            upgraded_any_client_state.encode_to_vec(),
+        )
+        .map_err(|e| {
+            Error::invalid_upgrade_client(format!(
+                "failed to verify the upgraded client state: client_id={} error={}",
+                client_id, e
+            ))
+        })?;
+        verify_upgraded_state(
+            &client_state,
+            proof_upgrade_consensus_state,
+            consensus_state.root(),
+            upgrade_merkle_path(
+                &client_state.upgrade_path,
+                latest_height.revision_height(),
+                UPGRADED_CONSENSUS_STATE_KEY,
+            )?,
+            upgraded_any_consensus_state.encode_to_vec(),
+        )
+        .map_err(|e| {
+            Error::invalid_upgrade_client(format!(
+                "failed to verify the upgraded consensus state: client_id={} error={}",
+                client_id, e
+            ))
+        })?;
+
+        let new_client_state = upgrade_client_state(&client_state, &upgraded_client_state)?;
+        let new_consensus_state = upgrade_consensus_state(&upgraded_consensus_state);
+
+        let prev_height: Height = latest_height.into();
+        let new_height: Height = upgraded_height.into();
+        let prev_state_id = gen_state_id(canonicalize_state(&client_state), consensus_state)?;
+        let new_state_id = gen_state_id(
+            canonicalize_state(&new_client_state),
+            new_consensus_state.clone(),
+        )?;
+        let timestamp: Time = new_consensus_state.timestamp.into();
+
+        Ok(UpgradeClientResult {
+            new_any_client_state: new_client_state.clone().into(),
+            new_any_consensus_state: new_consensus_state.into(),
+            height: new_height,
+            commitment: UpdateClientCommitment {
+                prev_state_id: Some(prev_state_id),
+                new_state_id,
+                // the new state is included because the upgrade may change the chain-specified fields such as the chain-id
+                new_state: Some(new_client_state.into()),
+                prev_height: Some(prev_height),
+                new_height,
+                timestamp,
+                context: CommitmentContext::Empty,
+            }
+            .into(),
+        })
+    }
+
     fn verify_membership(
         &self,
         ctx: &dyn HostClientReader,
@@ -298,6 +626,159 @@ impl LightClient for TendermintLightClient {
 }
 
 impl TendermintLightClient {
+    /// validate_updatable checks that the client is neither frozen nor expired,
+    /// and returns the latest consensus state of the client
+    fn validate_updatable(
+        ctx: &dyn HostClientReader,
+        client_id: &ClientId,
+        client_state: &ClientState,
+        header: &Header,
+    ) -> Result<ConsensusState, LightClientError> {
+        if client_state.is_frozen() {
+            return Err(Error::ics02(ICS02Error::ClientFrozen {
+                client_id: client_id.clone().into(),
+            })
+            .into());
+        }
+
+        // Read consensus state from the host chain store.
+        let latest_consensus_state: ConsensusState = ctx
+            .consensus_state(client_id, &client_state.latest_height().into())
+            .map_err(|_| {
+                Error::ics02(ICS02Error::ConsensusStateNotFound {
+                    client_id: client_id.clone().into(),
+                    height: client_state.latest_height(),
+                })
+            })?
+            .try_into()?;
+
+        debug!("latest consensus state: {:?}", latest_consensus_state);
+
+        let now = ctx.host_timestamp();
+        let duration = now
+            .duration_since(latest_consensus_state.timestamp)
+            .map_err(|_| {
+                Error::ics02(ICS02Error::InvalidConsensusStateTimestamp {
+                    time1: latest_consensus_state.timestamp(),
+                    time2: now.into(),
+                })
+            })?;
+
+        if client_state.expired(duration) {
+            return Err(Error::ics02(ICS02Error::HeaderNotWithinTrustPeriod {
+                latest_time: latest_consensus_state.timestamp(),
+                update_time: header.timestamp(),
+            })
+            .into());
+        }
+        Ok(latest_consensus_state)
+    }
+
+    /// update_client_with_bisection verifies the intermediate headers and the target header in order
+    /// with the skipping verification, and updates the client to the height of the target header.
+    ///
+    /// Only the consensus state of the target header is stored, and the commitment has
+    /// the consensus state that the first header is trusted by as the previous state.
+    fn update_client_with_bisection(
+        ctx: &dyn HostClientReader,
+        client_id: ClientId,
+        bisection_header: BisectionHeader,
+    ) -> Result<UpdateClientResult, LightClientError> {
+        let header = &bisection_header.header;
+
+        // Read client state from the host chain store.
+        let client_state: ClientState = ctx.client_state(&client_id)?.try_into()?;
+        let latest_consensus_state =
+            Self::validate_updatable(ctx, &client_id, &client_state, header)?;
+
+        if header.height() <= client_state.latest_height() {
+            return Err(Error::invalid_bisection_header(format!(
+                "the height of the header must be greater than the latest height: latest_height={} height={}",
+                client_state.latest_height(),
+                header.height()
+            ))
+            .into());
+        }
+        if header.signed_header.header.time <= latest_consensus_state.timestamp {
+            return Err(Error::invalid_bisection_header(format!(
+                "the timestamp of the header must be after the latest consensus state: latest_timestamp={} timestamp={}",
+                latest_consensus_state.timestamp(),
+                header.timestamp()
+            ))
+            .into());
+        }
+
+        let first_trusted_height = bisection_header
+            .intermediate_headers
+            .first()
+            .unwrap_or(header)
+            .trusted_height;
+        let trusted_consensus_state: ConsensusState = ctx
+            .consensus_state(&client_id, &first_trusted_height.into())
+            .map_err(|_| {
+                Error::ics02(ICS02Error::ConsensusStateNotFound {
+                    client_id: client_id.clone().into(),
+                    height: first_trusted_height,
+                })
+            })?
+            .try_into()?;
+
+        let lc_opts = client_state
+            .as_light_client_options()
+            .map_err(Error::ics02)?;
+        let now = *ctx.host_timestamp();
+        let mut trusted_height = first_trusted_height;
+        let mut trusted_state = trusted_consensus_state.clone();
+        for (i, h) in bisection_header.headers().enumerate() {
+            if h.trusted_height != trusted_height {
+                return Err(Error::invalid_bisection_header(format!(
+                    "header[{}] must be trusted by the preceding header: expected_trusted_height={} actual_trusted_height={}",
+                    i, trusted_height, h.trusted_height
+                ))
+                .into());
+            }
+            verify_header(&client_state, &trusted_state, h, &lc_opts, now)?;
+            trusted_height = h.height();
+            trusted_state = ConsensusState(TendermintConsensusState::from(h.0.clone()));
+        }
+
+        let height: Height = header.height().into();
+        let header_timestamp: Time = header.timestamp().into();
+        let new_client_state = with_latest_height(&client_state, header.height())?;
+        // the consensus state of the target header is the last verified one
+        let new_consensus_state = trusted_state;
+
+        let trusted_state_timestamp: Time = trusted_consensus_state.timestamp().into();
+        let prev_state_id =
+            gen_state_id(canonicalize_state(&client_state), trusted_consensus_state)?;
+        let new_state_id = gen_state_id(
+            canonicalize_state(&new_client_state),
+            new_consensus_state.clone(),
+        )?;
+        Ok(UpdateClientResult {
+            new_any_client_state: new_client_state.into(),
+            new_any_consensus_state: new_consensus_state.into(),
+            height,
+            commitment: UpdateClientCommitment {
+                prev_state_id: Some(prev_state_id),
+                new_state_id,
+                new_state: None,
+                prev_height: Some(first_trusted_height.into()),
+                new_height: height,
+                timestamp: header_timestamp,
+                context: TrustingPeriodContext::new(
+                    lc_opts.trusting_period,
+                    lc_opts.clock_drift,
+                    header_timestamp,
+                    trusted_state_timestamp,
+                )
+                .into(),
+            }
+            .into(),
+            prove: true,
+        })
+    }
+
     fn validate_args(
         ctx: &dyn HostClientReader,
         client_id: ClientId,
@@ -367,6 +848,104 @@ fn verify_membership(
         .map_err(ICS02Error::Ics23Verification)
 }
 
+/// the key suffixes of the upgraded states committed by the upgrade module of the counterparty chain
+const UPGRADED_CLIENT_KEY: &str = "upgradedClient";
+const UPGRADED_CONSENSUS_STATE_KEY: &str = "upgradedConsState";
+
+// upgrade_merkle_path returns the merkle path of the upgraded state: all keys of the upgrade path except the last one,
+// followed by `{last key}/{upgrade height}/{upgraded state key}`
+fn upgrade_merkle_path(
+    upgrade_path: &[String],
+    upgrade_height: u64,
+    key: &str,
+) -> Result<MerklePath, Error> {
+    let (last_key, keys) = upgrade_path
+        .split_last()
+        .ok_or_else(|| Error::invalid_upgrade_client("upgrade path is empty".into()))?;
+    let mut key_path = keys.to_vec();
+    key_path.push(format!("{}/{}/{}", last_key, upgrade_height, key));
+    Ok(MerklePath { key_path })
+}
+
+fn verify_upgraded_state(
+    client_state: &ClientState,
+    proof: Vec<u8>,
+    root: &CommitmentRoot,
+    merkle_path: MerklePath,
+    value: Vec<u8>,
+) -> Result<(), Error> {
+    let proof: IBCCommitmentProofBytes = proof.try_into().map_err(Error::ics23)?;
+    let merkle_proof: MerkleProof = RawMerkleProof::try_from(proof)
+        .map_err(|e| Error::ics02(ICS02Error::InvalidCommitmentProof(e)))?
+        .into();
+
+    merkle_proof
+        .verify_membership(
+            &client_state.proof_specs,
+            root.clone().into(),
+            merkle_path,
+            value,
+            0,
+        )
+        .map_err(|e| Error::ics02(ICS02Error::Ics23Verification(e)))
+}
+
+/// verify_header verifies the header against the trusted consensus state with the skipping verification
+fn verify_header(
+    client_state: &ClientState,
+    trusted_consensus_state: &ConsensusState,
+    header: &Header,
+    options: &Options,
+    now: TmTime,
+) -> Result<(), Error> {
+    if header.height().revision_number() != client_state.chain_id.version() {
+        return Err(Error::invalid_bisection_header(format!(
+            "unexpected revision number: expected={} actual={}",
+            client_state.chain_id.version(),
+            header.height().revision_number()
+        )));
+    }
+    if header.height() <= header.trusted_height {
+        return Err(Error::invalid_bisection_header(format!(
+            "the height of the header must be greater than the trusted height: trusted_height={} height={}",
+            header.trusted_height,
+            header.height()
+        )));
+    }
+    if header.trusted_validator_set.hash() != trusted_consensus_state.next_validators_hash {
+        return Err(Error::invalid_bisection_header(format!(
+            "the trusted validator set does not match the trusted consensus state: height={}",
+            header.height()
+        )));
+    }
+
+    let chain_id: TmChainId = client_state.chain_id.clone().into();
+    let trusted_state = TrustedBlockState {
+        chain_id: &chain_id,
+        header_time: trusted_consensus_state.timestamp,
+        height: TmHeight::try_from(header.trusted_height.revision_height()).map_err(|_| {
+            Error::invalid_bisection_header(format!(
+                "invalid trusted height: trusted_height={}",
+                header.trusted_height
+            ))
+        })?,
+        next_validators: &header.trusted_validator_set,
+        next_validators_hash: trusted_consensus_state.next_validators_hash,
+    };
+    let untrusted_state = UntrustedBlockState {
+        signed_header: &header.signed_header,
+        validators: &header.validator_set,
+        // the next validators of the untrusted header are verified by the succeeding header
+        next_validators: None,
+    };
+    match ProdVerifier::default().verify(untrusted_state, trusted_state, options, now) {
+        Verdict::Success => Ok(()),
+        verdict => Err(Error::ics02(ICS02Error::HeaderVerificationFailure {
+            reason: format!("{:?}", verdict),
+        })),
+    }
+}
+
 fn verify_non_membership(
     client_state: &ClientState,
     prefix: &IBCCommitmentPrefix,
@@ -383,3 +962,706 @@ fn verify_non_membership(
         .verify_non_membership(&client_state.proof_specs, root.clone().into(), merkle_path)
         .map_err(ICS02Error::Ics23Verification)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use context::Context;
+    use crypto::NopSigner;
+    use ibc::clients::ics07_tendermint::client_state::AllowUpdate;
+    use ibc::clients::ics07_tendermint::header::Header as TendermintHeader;
+    use ibc::clients::ics07_tendermint::misbehaviour::Misbehaviour as TendermintMisbehaviour;
+    use ibc::core::ics02_client::trust_threshold::TrustThreshold;
+    use ibc::core::ics23_commitment::specs::ProofSpecs;
+    use ibc::core::ics24_host::identifier::ChainId;
+    use ics23::commitment_proof::Proof;
+    use ics23::{
+        calculate_existence_root, CommitmentProof, ExistenceProof, HashOp, HostFunctionsManager,
+        InnerOp, LeafOp, LengthOp,
+    };
+    use lcp_proto::google::protobuf::Duration as RawDuration;
+    use lcp_proto::ibc::core::client::v1::Height as RawHeight;
+    use lcp_proto::ibc::lightclients::tendermint::v1::ClientState as RawTmClientState;
+    use lcp_proto::ics23::{CommitmentProof as RawCommitmentProof, ProofSpec as RawProofSpec};
+    use light_client::{ClientKeeper, MapLightClientRegistry};
+    use store::memory::MemStore;
+    use tendermint::validator::Set as ValidatorSet;
+    use tendermint_testgen::light_block::TmLightBlock;
+    use tendermint_testgen::{
+        Generator, Header as TestgenHeader, LightBlock as TestgenLightBlock,
+        Validator as TestgenValidator,
+    };
+
+    const TRUSTING_PERIOD_SECS: u64 = 100;
+    const GENESIS_SECS: i64 = 1_000;
+    const CHAIN_ID: &str = "test-0";
+
+    #[test]
+    fn test_recover_client_allow_update() {
+        for (frozen, after_expiry, after_misbehaviour) in [
+            (false, false, false),
+            (false, false, true),
+            (false, true, false),
+            (false, true, true),
+            (true, false, false),
+            (true, false, true),
+            (true, true, false),
+            (true, true, true),
+        ] {
+            let allow_update = AllowUpdate {
+                after_expiry,
+                after_misbehaviour,
+            };
+            let mut ctx = Context::new(
+                MapLightClientRegistry::new(),
+                MemStore::default(),
+                &NopSigner,
+            );
+            // the frozen subject is within the trusting period, and the other one is expired
+            let subject_timestamp = if frozen {
+                GENESIS_SECS + 50
+            } else {
+                GENESIS_SECS
+            };
+            let subject_client_id = put_client(
+                &mut ctx,
+                0,
+                client_state(1, allow_update.clone(), frozen),
+                subject_timestamp,
+            );
+            let substitute_client_id = put_client(
+                &mut ctx,
+                1,
+                client_state(2, allow_update, false),
+                GENESIS_SECS + 100,
+            );
+            ctx.set_timestamp(timestamp(GENESIS_SECS + 120));
+
+            let lc = TendermintLightClient;
+            let expected_status = if frozen {
+                ClientStatus::Frozen
+            } else {
+                ClientStatus::Expired
+            };
+            assert_eq!(
+                lc.status(&ctx, &subject_client_id).unwrap(),
+                expected_status
+            );
+
+            let res = lc.recover_client(&ctx, subject_client_id, substitute_client_id);
+            let allowed = if frozen {
+                after_misbehaviour
+            } else {
+                after_expiry
+            };
+            if allowed {
+                let res = res.unwrap();
+                assert_eq!(res.height, Height::new(0, 2));
+                // the commitment carries the new client state and cannot be used as an update
+                let commitment: RecoverClientCommitment = res.commitment.try_into().unwrap();
+                assert_eq!(commitment.new_state, res.new_any_client_state);
+                assert_eq!(commitment.prev_height, Height::new(0, 1));
+                assert_eq!(commitment.new_height, Height::new(0, 2));
+                let new_client_state: ClientState = res.new_any_client_state.try_into().unwrap();
+                assert!(!new_client_state.is_frozen());
+            } else {
+                assert!(
+                    res.is_err(),
+                    "frozen={} after_expiry={} after_misbehaviour={} res={:?}",
+                    frozen,
+                    after_expiry,
+                    after_misbehaviour,
+                    res
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_recover_client_active_subject() {
+        let allow_update = AllowUpdate {
+            after_expiry: true,
+            after_misbehaviour: true,
+        };
+        let mut ctx = Context::new(
+            MapLightClientRegistry::new(),
+            MemStore::default(),
+            &NopSigner,
+        );
+        let subject_client_id = put_client(
+            &mut ctx,
+            0,
+            client_state(1, allow_update.clone(), false),
+            GENESIS_SECS + 50,
+        );
+        let substitute_client_id = put_client(
+            &mut ctx,
+            1,
+            client_state(2, allow_update, false),
+            GENESIS_SECS + 100,
+        );
+        ctx.set_timestamp(timestamp(GENESIS_SECS + 120));
+
+        let res =
+            TendermintLightClient.recover_client(&ctx, subject_client_id, substitute_client_id);
+        assert!(res.is_err(), "res={:?}", res);
+    }
+
+    #[test]
+    fn test_frozen_client_cannot_verify_state() {
+        let allow_update = AllowUpdate {
+            after_expiry: true,
+            after_misbehaviour: true,
+        };
+        let mut ctx = Context::new(
+            MapLightClientRegistry::new(),
+            MemStore::default(),
+            &NopSigner,
+        );
+        let client_id = put_client(
+            &mut ctx,
+            0,
+            client_state(1, allow_update, true),
+            GENESIS_SECS + 50,
+        );
+        ctx.set_timestamp(timestamp(GENESIS_SECS + 60));
+
+        let lc = TendermintLightClient;
+        assert_eq!(lc.status(&ctx, &client_id).unwrap(), ClientStatus::Frozen);
+        let res = lc.verify_membership(
+            &ctx,
+            client_id,
+            b"ibc".to_vec(),
+            "clients/07-tendermint-0/clientState".to_string(),
+            b"value".to_vec(),
+            Height::new(0, 1),
+            vec![],
+        );
+        assert!(res.is_err(), "res={:?}", res);
+    }
+
+    #[test]
+    fn test_submit_misbehaviour() {
+        let validators = validators(&["1", "2"]);
+        let genesis = light_block(1, GENESIS_SECS, &validators, &validators);
+        let mut ctx = Context::new(
+            MapLightClientRegistry::new(),
+            MemStore::default(),
+            &NopSigner,
+        );
+        let client_id = put_client_with_block(&mut ctx, 0, &genesis);
+        ctx.set_timestamp(timestamp(GENESIS_SECS + 20));
+
+        // two different blocks at the same height signed by the trusted validators
+        let header_1 = header(
+            &light_block(2, GENESIS_SECS + 10, &validators, &validators),
+            1,
+            &genesis.next_validators,
+        );
+        let header_2 = header(
+            &light_block(2, GENESIS_SECS + 11, &validators, &validators),
+            1,
+            &genesis.next_validators,
+        );
+        let build_misbehaviour = |header_1: &Header, header_2: &Header| -> Any {
+            Misbehaviour::from(
+                TendermintMisbehaviour::new(
+                    client_id.clone().into(),
+                    header_1.0.clone(),
+                    header_2.0.clone(),
+                )
+                .unwrap(),
+            )
+            .into()
+        };
+
+        let lc = TendermintLightClient;
+
+        // the headers must conflict with each other
+        let res = lc.submit_misbehaviour(
+            &ctx,
+            client_id.clone(),
+            build_misbehaviour(&header_1, &header_1),
+        );
+        assert!(res.is_err(), "res={:?}", res);
+
+        let res = lc
+            .submit_misbehaviour(
+                &ctx,
+                client_id.clone(),
+                build_misbehaviour(&header_1, &header_2),
+            )
+            .unwrap();
+        let new_client_state = ClientState::try_from(res.new_any_client_state.clone()).unwrap();
+        assert!(new_client_state.is_frozen());
+        let commitment = MisbehaviourCommitment::try_from(res.commitment).unwrap();
+        assert_eq!(commitment.client_id, client_id);
+        assert_eq!(
+            commitment
+                .prev_states
+                .iter()
+                .map(|s| s.height)
+                .collect::<Vec<_>>(),
+            vec![Height::new(0, 1), Height::new(0, 1)]
+        );
+
+        // the frozen client refuses another misbehaviour
+        ctx.store_any_client_state(client_id.clone(), res.new_any_client_state)
+            .unwrap();
+        assert_eq!(lc.status(&ctx, &client_id).unwrap(), ClientStatus::Frozen);
+        let res = lc.submit_misbehaviour(&ctx, client_id, build_misbehaviour(&header_1, &header_2));
+        assert!(res.is_err(), "res={:?}", res);
+    }
+
+    #[test]
+    fn test_upgrade_client() {
+        let mut ctx = Context::new(
+            MapLightClientRegistry::new(),
+            MemStore::default(),
+            &NopSigner,
+        );
+        let client_id = put_client(&mut ctx, 0, upgradable_client_state(1), GENESIS_SECS);
+        ctx.set_timestamp(timestamp(GENESIS_SECS + 10));
+
+        // the counterparty chain zeroes out the client-specified fields of the upgraded client state
+        let upgraded_client_state = upgraded_client_state("test-1", 1, TRUSTING_PERIOD_SECS * 3);
+        let upgraded_consensus_state: Any = ConsensusState(TendermintConsensusState::new(
+            CommitmentRoot::from_bytes(b"sentinel_root"),
+            *timestamp(GENESIS_SECS + 5),
+            tendermint::Hash::None,
+        ))
+        .into();
+        let (root, proof_client, proof_consensus_state) =
+            upgrade_proofs(1, &upgraded_client_state, &upgraded_consensus_state);
+        put_root(&mut ctx, &client_id, 1, GENESIS_SECS, root);
+
+        let lc = TendermintLightClient;
+        let res = lc
+            .upgrade_client(
+                &ctx,
+                client_id.clone(),
+                upgraded_client_state.clone(),
+                upgraded_consensus_state.clone(),
+                proof_client.clone(),
+                proof_consensus_state.clone(),
+            )
+            .unwrap();
+        assert_eq!(res.height, Height::new(1, 1));
+        // the chain-specified fields come from the upgraded client state and the others are kept
+        let new_client_state: ClientState = res.new_any_client_state.clone().try_into().unwrap();
+        assert_eq!(
+            new_client_state.chain_id,
+            ChainId::new("test".to_string(), 1)
+        );
+        assert_eq!(
+            new_client_state.unbonding_period,
+            Duration::from_secs(TRUSTING_PERIOD_SECS * 3)
+        );
+        assert_eq!(
+            new_client_state.trusting_period,
+            Duration::from_secs(TRUSTING_PERIOD_SECS)
+        );
+        assert_eq!(new_client_state.trust_level, TrustThreshold::ONE_THIRD);
+        let commitment: UpdateClientCommitment = res.commitment.try_into().unwrap();
+        assert_eq!(commitment.new_state, Some(res.new_any_client_state));
+        assert_eq!(commitment.prev_height, Some(Height::new(0, 1)));
+        assert_eq!(commitment.new_height, Height::new(1, 1));
+
+        // the proofs must be verified against the committed states
+        let res = lc.upgrade_client(
+            &ctx,
+            client_id.clone(),
+            upgraded_client_state.clone(),
+            upgraded_consensus_state.clone(),
+            proof_consensus_state.clone(),
+            proof_client.clone(),
+        );
+        assert!(res.is_err(), "res={:?}", res);
+        let res = lc.upgrade_client(
+            &ctx,
+            client_id.clone(),
+            upgraded_client_state("test-1", 1, TRUSTING_PERIOD_SECS * 4),
+            upgraded_consensus_state.clone(),
+            proof_client.clone(),
+            proof_consensus_state.clone(),
+        );
+        assert!(res.is_err(), "res={:?}", res);
+        let res = lc.upgrade_client(
+            &ctx,
+            client_id,
+            upgraded_client_state,
+            upgraded_consensus_state,
+            b"invalid".to_vec(),
+            proof_consensus_state,
+        );
+        assert!(res.is_err(), "res={:?}", res);
+    }
+
+    #[test]
+    fn test_upgrade_client_height() {
+        let mut ctx = Context::new(
+            MapLightClientRegistry::new(),
+            MemStore::default(),
+            &NopSigner,
+        );
+        let client_id = put_client(&mut ctx, 0, upgradable_client_state(2), GENESIS_SECS);
+        ctx.set_timestamp(timestamp(GENESIS_SECS + 10));
+
+        // the upgraded height must be greater than the latest height even if the states are committed
+        let upgraded_client_state = upgraded_client_state("test-0", 2, TRUSTING_PERIOD_SECS * 2);
+        let upgraded_consensus_state: Any = ConsensusState(TendermintConsensusState::new(
+            CommitmentRoot::from_bytes(b"sentinel_root"),
+            *timestamp(GENESIS_SECS + 5),
+            tendermint::Hash::None,
+        ))
+        .into();
+        let (root, proof_client, proof_consensus_state) =
+            upgrade_proofs(2, &upgraded_client_state, &upgraded_consensus_state);
+        put_root(&mut ctx, &client_id, 2, GENESIS_SECS, root);
+
+        let res = TendermintLightClient.upgrade_client(
+            &ctx,
+            client_id,
+            upgraded_client_state,
+            upgraded_consensus_state,
+            proof_client,
+            proof_consensus_state,
+        );
+        assert!(res.is_err(), "res={:?}", res);
+    }
+
+    #[test]
+    fn test_update_client_with_bisection() {
+        let validators = validators(&["1", "2"]);
+        let genesis = light_block(1, GENESIS_SECS, &validators, &validators);
+        let mut ctx = Context::new(
+            MapLightClientRegistry::new(),
+            MemStore::default(),
+            &NopSigner,
+        );
+        let client_id = put_client_with_block(&mut ctx, 0, &genesis);
+        ctx.set_timestamp(timestamp(GENESIS_SECS + 50));
+
+        let block_3 = light_block(3, GENESIS_SECS + 10, &validators, &validators);
+        let block_5 = light_block(5, GENESIS_SECS + 20, &validators, &validators);
+        let intermediate_header = header(&block_3, 1, &genesis.next_validators);
+        let target_header = header(&block_5, 3, &block_3.next_validators);
+
+        let lc = TendermintLightClient;
+
+        // every header must be trusted by the preceding one
+        let res = lc.update_client(
+            &ctx,
+            client_id.clone(),
+            BisectionHeader {
+                header: header(&block_5, 2, &block_3.next_validators),
+                intermediate_headers: vec![intermediate_header.clone()],
+            }
+            .into(),
+        );
+        assert!(res.is_err(), "res={:?}", res);
+
+        // the trusted validator set must match the next validators hash of the trusted consensus state
+        let other_validators = light_block(
+            1,
+            GENESIS_SECS,
+            &validators(&["3", "4"]),
+            &validators(&["3", "4"]),
+        )
+        .next_validators;
+        let res = lc.update_client(
+            &ctx,
+            client_id.clone(),
+            BisectionHeader {
+                header: target_header.clone(),
+                intermediate_headers: vec![header(&block_3, 1, &other_validators)],
+            }
+            .into(),
+        );
+        assert!(res.is_err(), "res={:?}", res);
+
+        // the client skips to the target header across the intermediate header
+        let res = lc
+            .update_client(
+                &ctx,
+                client_id.clone(),
+                BisectionHeader {
+                    header: target_header,
+                    intermediate_headers: vec![intermediate_header],
+                }
+                .into(),
+            )
+            .unwrap();
+        assert_eq!(res.height, Height::new(0, 5));
+        let new_client_state: ClientState = res.new_any_client_state.clone().try_into().unwrap();
+        assert_eq!(
+            new_client_state.latest_height(),
+            ibc::Height::new(0, 5).unwrap()
+        );
+        let new_consensus_state: ConsensusState =
+            res.new_any_consensus_state.clone().try_into().unwrap();
+        assert_eq!(
+            new_consensus_state.root(),
+            &CommitmentRoot::from_bytes(block_5.signed_header.header.app_hash.as_ref())
+        );
+        let commitment: UpdateClientCommitment = res.commitment.try_into().unwrap();
+        assert_eq!(commitment.prev_height, Some(Height::new(0, 1)));
+        assert_eq!(commitment.new_height, Height::new(0, 5));
+        ctx.store_any_client_state(client_id.clone(), res.new_any_client_state)
+            .unwrap();
+        ctx.store_any_consensus_state(client_id.clone(), res.height, res.new_any_consensus_state)
+            .unwrap();
+
+        // the target height must be greater than the latest height
+        let res = lc.update_client(
+            &ctx,
+            client_id,
+            BisectionHeader {
+                header: header(&block_3, 1, &genesis.next_validators),
+                intermediate_headers: vec![],
+            }
+            .into(),
+        );
+        assert!(res.is_err(), "res={:?}", res);
+    }
+
+    fn validators(ids: &[&str]) -> Vec<TestgenValidator> {
+        ids.iter()
+            .map(|id| TestgenValidator::new(id).voting_power(50))
+            .collect()
+    }
+
+    fn light_block(
+        height: u64,
+        timestamp_secs: i64,
+        validators: &[TestgenValidator],
+        next_validators: &[TestgenValidator],
+    ) -> TmLightBlock {
+        TestgenLightBlock::new_default_with_header(
+            TestgenHeader::new(validators)
+                .height(height)
+                .chain_id(CHAIN_ID)
+                .next_validators(next_validators)
+                .time(TmTime::from_unix_timestamp(timestamp_secs, 0).unwrap()),
+        )
+        .generate()
+        .unwrap()
+    }
+
+    fn header(
+        block: &TmLightBlock,
+        trusted_height: u64,
+        trusted_validator_set: &ValidatorSet,
+    ) -> Header {
+        Header::from(TendermintHeader {
+            signed_header: block.signed_header.clone(),
+            validator_set: block.validators.clone(),
+            trusted_height: ibc::Height::new(0, trusted_height).unwrap(),
+            trusted_validator_set: trusted_validator_set.clone(),
+        })
+    }
+
+    /// put_client_with_block stores a client whose latest consensus state is derived from the given block
+    fn put_client_with_block<C: ClientKeeper>(
+        ctx: &mut C,
+        counter: u64,
+        block: &TmLightBlock,
+    ) -> ClientId {
+        let allow_update = AllowUpdate {
+            after_expiry: false,
+            after_misbehaviour: false,
+        };
+        let client_state = client_state(
+            block.signed_header.header.height.value(),
+            allow_update,
+            false,
+        );
+        let client_id = ClientId::new(client_type().as_str(), counter).unwrap();
+        let height: Height = client_state.latest_height().into();
+        let consensus_state = ConsensusState(TendermintConsensusState::from(
+            block.signed_header.header.clone(),
+        ));
+        ctx.store_client_type(client_id.clone(), client_type().as_str().to_string())
+            .unwrap();
+        ctx.store_any_client_state(client_id.clone(), client_state.into())
+            .unwrap();
+        ctx.store_any_consensus_state(client_id.clone(), height, consensus_state.into())
+            .unwrap();
+        client_id
+    }
+
+    fn client_state(latest_height: u64, allow_update: AllowUpdate, frozen: bool) -> ClientState {
+        #[allow(deprecated)]
+        let client_state = TendermintClientState::new(
+            ChainId::new("test".to_string(), 0),
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(TRUSTING_PERIOD_SECS),
+            Duration::from_secs(TRUSTING_PERIOD_SECS * 2),
+            Duration::from_secs(10),
+            ibc::Height::new(0, latest_height).unwrap(),
+            ProofSpecs::default(),
+            vec![],
+            allow_update,
+            frozen.then(|| ibc::Height::new(0, 1).unwrap()),
+        )
+        .unwrap();
+        ClientState(client_state)
+    }
+
+    fn put_client<C: ClientKeeper>(
+        ctx: &mut C,
+        counter: u64,
+        client_state: ClientState,
+        timestamp_secs: i64,
+    ) -> ClientId {
+        let client_id = ClientId::new(client_type().as_str(), counter).unwrap();
+        let height: Height = client_state.latest_height().into();
+        let consensus_state = ConsensusState(TendermintConsensusState::new(
+            CommitmentRoot::from_bytes(b"root"),
+            *timestamp(timestamp_secs),
+            tendermint::Hash::None,
+        ));
+        ctx.store_client_type(client_id.clone(), client_type().as_str().to_string())
+            .unwrap();
+        ctx.store_any_client_state(client_id.clone(), client_state.into())
+            .unwrap();
+        ctx.store_any_consensus_state(client_id.clone(), height, consensus_state.into())
+            .unwrap();
+        client_id
+    }
+
+    /// upgradable_client_state returns a client state whose proofs consist of two tendermint merkle proofs:
+    /// one for the upgrade store and the other for the multistore
+    fn upgradable_client_state(latest_height: u64) -> ClientState {
+        let allow_update = AllowUpdate {
+            after_expiry: false,
+            after_misbehaviour: false,
+        };
+        let mut client_state = client_state(latest_height, allow_update, false);
+        let spec =
+            RawProofSpec::decode(ics23::tendermint_spec().encode_to_vec().as_slice()).unwrap();
+        client_state.0.proof_specs = vec![spec.clone(), spec].into();
+        client_state.0.upgrade_path = vec!["upgrade".to_string(), "upgradedIBCState".to_string()];
+        client_state
+    }
+
+    /// upgraded_client_state returns the upgraded client state committed by the counterparty chain,
+    /// whose client-specified fields are zeroed out
+    fn upgraded_client_state(
+        chain_id: &str,
+        latest_height: u64,
+        unbonding_period_secs: u64,
+    ) -> Any {
+        let chain_id = ChainId::from_string(chain_id);
+        let raw = RawTmClientState {
+            chain_id: chain_id.to_string(),
+            unbonding_period: Some(RawDuration {
+                seconds: unbonding_period_secs as i64,
+                nanos: 0,
+            }),
+            latest_height: Some(RawHeight {
+                revision_number: chain_id.version(),
+                revision_height: latest_height,
+            }),
+            proof_specs: upgradable_client_state(1).proof_specs.clone().into(),
+            upgrade_path: vec!["upgrade".to_string(), "upgradedIBCState".to_string()],
+            ..Default::default()
+        };
+        Any::new(
+            TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
+            raw.encode_to_vec(),
+        )
+    }
+
+    /// upgrade_proofs returns the root and the proofs of the upgraded states committed under `upgradedIBCState/{height}`
+    /// of the upgrade store, which is the only store of the multistore
+    fn upgrade_proofs(
+        upgrade_height: u64,
+        upgraded_client_state: &Any,
+        upgraded_consensus_state: &Any,
+    ) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+        let leaf = |key: &str, value: &Any| ExistenceProof {
+            key: format!("upgradedIBCState/{}/{}", upgrade_height, key).into_bytes(),
+            value: value.encode_to_vec(),
+            leaf: Some(LeafOp {
+                hash: HashOp::Sha256.into(),
+                prehash_key: HashOp::NoHash.into(),
+                prehash_value: HashOp::Sha256.into(),
+                length: LengthOp::VarProto.into(),
+                prefix: vec![0],
+            }),
+            path: vec![],
+        };
+        let root = |proof: &ExistenceProof| {
+            calculate_existence_root::<HostFunctionsManager>(proof).unwrap()
+        };
+
+        // the upgraded client state is the left child, and the upgraded consensus state is the right one
+        let client_leaf = leaf(UPGRADED_CLIENT_KEY, upgraded_client_state);
+        let consensus_leaf = leaf(UPGRADED_CONSENSUS_STATE_KEY, upgraded_consensus_state);
+        let client_proof = ExistenceProof {
+            path: vec![InnerOp {
+                hash: HashOp::Sha256.into(),
+                prefix: vec![1],
+                suffix: root(&consensus_leaf),
+            }],
+            ..client_leaf.clone()
+        };
+        let consensus_proof = ExistenceProof {
+            path: vec![InnerOp {
+                hash: HashOp::Sha256.into(),
+                prefix: [vec![1], root(&client_leaf)].concat(),
+                suffix: vec![],
+            }],
+            ..consensus_leaf
+        };
+        let store_proof = ExistenceProof {
+            key: b"upgrade".to_vec(),
+            value: root(&client_proof),
+            ..leaf(UPGRADED_CLIENT_KEY, upgraded_client_state)
+        };
+
+        let merkle_proof = |proof: ExistenceProof| {
+            let proofs = [proof, store_proof.clone()]
+                .into_iter()
+                .map(|proof| {
+                    let proof = CommitmentProof {
+                        proof: Some(Proof::Exist(proof)),
+                    };
+                    RawCommitmentProof::decode(proof.encode_to_vec().as_slice()).unwrap()
+                })
+                .collect();
+            RawMerkleProof { proofs }.encode_to_vec()
+        };
+        (
+            root(&store_proof),
+            merkle_proof(client_proof),
+            merkle_proof(consensus_proof),
+        )
+    }
+
+    /// put_root replaces the root of the consensus state at the given height
+    fn put_root<C: ClientKeeper>(
+        ctx: &mut C,
+        client_id: &ClientId,
+        height: u64,
+        timestamp_secs: i64,
+        root: Vec<u8>,
+    ) {
+        let consensus_state = ConsensusState(TendermintConsensusState::new(
+            CommitmentRoot::from_bytes(&root),
+            *timestamp(timestamp_secs),
+            tendermint::Hash::None,
+        ));
+        ctx.store_any_consensus_state(
+            client_id.clone(),
+            Height::new(0, height),
+            consensus_state.into(),
+        )
+        .unwrap();
+    }
+
+    fn timestamp(secs: i64) -> Time {
+        TmTime::from_unix_timestamp(secs, 0).unwrap().into()
+    }
+}
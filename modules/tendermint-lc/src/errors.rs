@@ -12,6 +12,27 @@ define_error! {
             format_args!("unexpected client_type: type_url={}", e.type_url)
         },
 
+        InvalidRecoverClient {
+            descr: String
+        }
+        |e| {
+            format_args!("invalid recover client: descr={}", e.descr)
+        },
+
+        InvalidUpgradeClient {
+            descr: String
+        }
+        |e| {
+            format_args!("invalid upgrade client: descr={}", e.descr)
+        },
+
+        InvalidBisectionHeader {
+            descr: String
+        }
+        |e| {
+            format_args!("invalid bisection header: descr={}", e.descr)
+        },
+
         Ics02
         [TraceError<ibc::core::ics02_client::error::ClientError>]
         |_| { "ICS02 client error" },
@@ -24,13 +45,21 @@ define_error! {
         [TraceError<ibc::core::ics04_channel::error::ChannelError>]
         |_| { "ICS04 channel error" },
 
+        Ics07
+        [TraceError<ibc::clients::ics07_tendermint::error::Error>]
+        |_| { "ICS07 tendermint error" },
+
         Ics23
         [TraceError<ibc::core::ics23_commitment::error::CommitmentError>]
         |_| { "ICS23 commitment error" },
 
         Commitment
         [light_client::commitments::Error]
-        |_| { "Commitment error" }
+        |_| { "Commitment error" },
+
+        ProtoDecode
+        [TraceError<prost::DecodeError>]
+        |_| { "Proto decode error" }
     }
 }
 
@@ -1,11 +1,17 @@
 use core::ops::Deref;
 
 use crate::errors::Error;
+use crate::prelude::*;
 use ibc::clients::ics07_tendermint::header::{
     Header as TendermintHeader, TENDERMINT_HEADER_TYPE_URL,
 };
 use lcp_proto::google::protobuf::Any as ProtoAny;
+use lcp_proto::lcp::lightclients::tendermint::v1::BisectionHeader as RawBisectionHeader;
 use light_client::types::Any;
+use prost::Message;
+
+pub const TENDERMINT_BISECTION_HEADER_TYPE_URL: &str =
+    "/lcp.lightclients.tendermint.v1.BisectionHeader";
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Header(pub(crate) TendermintHeader);
@@ -47,3 +53,60 @@ impl TryFrom<Any> for Header {
         }
     }
 }
+
+/// BisectionHeader is a header to update the client to a non-adjacent height
+/// through the intermediate headers of the skipping verification
+#[derive(Clone, Debug)]
+pub struct BisectionHeader {
+    pub header: Header,
+    pub intermediate_headers: Vec<Header>,
+}
+
+impl BisectionHeader {
+    /// headers returns the headers in the order of verification, ending with the target header
+    pub fn headers(&self) -> impl Iterator<Item = &Header> {
+        self.intermediate_headers
+            .iter()
+            .chain(core::iter::once(&self.header))
+    }
+}
+
+impl From<BisectionHeader> for Any {
+    fn from(value: BisectionHeader) -> Self {
+        let raw = RawBisectionHeader {
+            header: Some(ProtoAny::from(value.header.0)),
+            intermediate_headers: value
+                .intermediate_headers
+                .into_iter()
+                .map(|h| ProtoAny::from(h.0))
+                .collect(),
+        };
+        Any::new(
+            TENDERMINT_BISECTION_HEADER_TYPE_URL.to_string(),
+            raw.encode_to_vec(),
+        )
+    }
+}
+
+impl TryFrom<Any> for BisectionHeader {
+    type Error = Error;
+
+    fn try_from(value: Any) -> Result<Self, Self::Error> {
+        let any: ProtoAny = value.into();
+        if any.type_url != TENDERMINT_BISECTION_HEADER_TYPE_URL {
+            return Err(Error::unexpected_client_type(any.type_url));
+        }
+        let raw = RawBisectionHeader::decode(any.value.as_slice()).map_err(Error::proto_decode)?;
+        let header = raw
+            .header
+            .ok_or_else(|| Error::invalid_bisection_header("header must be non-nil".into()))?;
+        Ok(Self {
+            header: Header::try_from(Any::from(header))?,
+            intermediate_headers: raw
+                .intermediate_headers
+                .into_iter()
+                .map(|h| Header::try_from(Any::from(h)))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
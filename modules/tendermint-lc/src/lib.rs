@@ -1,4 +1,4 @@
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 extern crate alloc;
 
 mod prelude {
@@ -24,4 +24,5 @@ pub use client::{register_implementations, TendermintLightClient};
 pub mod client;
 pub mod errors;
 pub mod header;
+pub mod misbehaviour;
 pub mod state;
@@ -0,0 +1,51 @@
+use core::ops::Deref;
+
+use crate::errors::Error;
+use ibc::clients::ics07_tendermint::misbehaviour::{
+    Misbehaviour as TendermintMisbehaviour, TENDERMINT_MISBEHAVIOUR_TYPE_URL,
+};
+use lcp_proto::google::protobuf::Any as ProtoAny;
+use light_client::types::Any;
+
+#[derive(Clone, Debug)]
+pub struct Misbehaviour(pub(crate) TendermintMisbehaviour);
+
+impl Deref for Misbehaviour {
+    type Target = TendermintMisbehaviour;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Misbehaviour> for TendermintMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        value.0
+    }
+}
+
+impl From<TendermintMisbehaviour> for Misbehaviour {
+    fn from(value: TendermintMisbehaviour) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(value: Misbehaviour) -> Self {
+        ProtoAny::from(value.0).into()
+    }
+}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(value: Any) -> Result<Self, Self::Error> {
+        let any: ProtoAny = value.into();
+        if any.type_url == TENDERMINT_MISBEHAVIOUR_TYPE_URL {
+            Ok(Self(
+                TendermintMisbehaviour::try_from(any).map_err(Error::ics02)?,
+            ))
+        } else {
+            Err(Error::unexpected_client_type(any.type_url))
+        }
+    }
+}
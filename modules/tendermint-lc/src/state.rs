@@ -1,5 +1,7 @@
 use crate::errors::Error;
+use crate::prelude::*;
 use core::ops::Deref;
+use core::time::Duration;
 use ibc::clients::ics07_tendermint::{
     client_state::{
         AllowUpdate, ClientState as TendermintClientState, TENDERMINT_CLIENT_STATE_TYPE_URL,
@@ -8,10 +10,15 @@ use ibc::clients::ics07_tendermint::{
         ConsensusState as TendermintConsensusState, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
     },
 };
+use ibc::core::ics02_client::client_state::ClientState as Ics02ClientState;
+use ibc::core::ics23_commitment::commitment::CommitmentRoot;
+use ibc::core::ics23_commitment::specs::ProofSpecs;
+use ibc::core::ics24_host::identifier::ChainId;
 use lcp_proto::google::protobuf::Any as ProtoAny;
 use lcp_proto::ibc::lightclients::tendermint::v1::ClientState as RawTmClientState;
 use light_client::commitments::{gen_state_id_from_any, StateID};
 use light_client::types::{Any, Height};
+use prost::Message;
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct ClientState(pub(crate) TendermintClientState);
@@ -77,10 +84,18 @@ impl From<ConsensusState> for Any {
     }
 }
 
+// allow_update returns the flags whether the client can be recovered from the expired or frozen status
+pub fn allow_update(client_state: &ClientState) -> AllowUpdate {
+    let raw_state = RawTmClientState::from(client_state.0.clone());
+    AllowUpdate {
+        after_expiry: raw_state.allow_update_after_expiry,
+        after_misbehaviour: raw_state.allow_update_after_misbehaviour,
+    }
+}
+
 // canonicalize_state canonicalizes some fields of specified client state
 // target fields: latest_height, frozen_height
 pub fn canonicalize_state(client_state: &ClientState) -> ClientState {
-    let raw_state: RawTmClientState = client_state.0.clone().try_into().unwrap();
     let opt = client_state.as_light_client_options().unwrap();
     #[allow(deprecated)]
     let tm = TendermintClientState::new(
@@ -94,16 +109,144 @@ pub fn canonicalize_state(client_state: &ClientState) -> ClientState {
             .unwrap(),
         client_state.proof_specs.clone(),
         client_state.upgrade_path.clone(),
-        AllowUpdate {
-            after_expiry: raw_state.allow_update_after_expiry,
-            after_misbehaviour: raw_state.allow_update_after_misbehaviour,
-        },
+        allow_update(client_state),
         None,
     )
     .unwrap();
     ClientState(tm)
 }
 
+// is_matching_client_state returns true if the client states are identical except for the fields
+// that the client recovery can change: latest_height, frozen_height, trusting_period and chain_id
+pub fn is_matching_client_state(subject: &ClientState, substitute: &ClientState) -> bool {
+    let zero_custom_fields = |client_state: &ClientState| {
+        let mut raw_state = RawTmClientState::from(client_state.0.clone());
+        raw_state.latest_height = None;
+        raw_state.frozen_height = None;
+        raw_state.trusting_period = None;
+        raw_state.chain_id = Default::default();
+        raw_state
+    };
+    zero_custom_fields(subject) == zero_custom_fields(substitute)
+}
+
+// with_latest_height returns a new client state which is the same as the given unfrozen client state except for the latest height
+pub fn with_latest_height(
+    client_state: &ClientState,
+    latest_height: ibc::Height,
+) -> Result<ClientState, Error> {
+    let opt = client_state
+        .as_light_client_options()
+        .map_err(Error::ics02)?;
+    #[allow(deprecated)]
+    let tm = TendermintClientState::new(
+        client_state.chain_id.clone(),
+        client_state.trust_level,
+        client_state.trusting_period,
+        client_state.unbonding_period,
+        opt.clock_drift,
+        latest_height,
+        client_state.proof_specs.clone(),
+        client_state.upgrade_path.clone(),
+        allow_update(client_state),
+        None,
+    )
+    .map_err(Error::ics07)?;
+    Ok(ClientState(tm))
+}
+
+/// SENTINEL_ROOT is the commitment root of the consensus state created by the client upgrade
+///
+/// The upgraded consensus state cannot be used for the state verification until the client is updated with a new header.
+pub const SENTINEL_ROOT: &[u8] = b"sentinel_root";
+
+/// UpgradedClientState is the chain-specified fields of the client state committed by the upgrade module of the counterparty chain
+///
+/// The counterparty chain zeroes out the client-specified fields such as `trust_level` and `trusting_period`,
+/// and `ClientState` rejects such a state, so only the chain-specified fields are decoded.
+#[derive(Clone, Debug)]
+pub struct UpgradedClientState {
+    pub chain_id: ChainId,
+    pub unbonding_period: Duration,
+    pub latest_height: ibc::Height,
+    pub proof_specs: ProofSpecs,
+    pub upgrade_path: Vec<String>,
+}
+
+impl TryFrom<Any> for UpgradedClientState {
+    type Error = Error;
+
+    fn try_from(value: Any) -> Result<Self, Self::Error> {
+        let any: ProtoAny = value.into();
+        if any.type_url != TENDERMINT_CLIENT_STATE_TYPE_URL {
+            return Err(Error::unexpected_client_type(any.type_url));
+        }
+        let raw = RawTmClientState::decode(any.value.as_slice()).map_err(Error::proto_decode)?;
+        let unbonding_period = match raw.unbonding_period {
+            Some(d) if d.seconds >= 0 && d.nanos >= 0 => {
+                Duration::new(d.seconds as u64, d.nanos as u32)
+            }
+            _ => {
+                return Err(Error::invalid_upgrade_client(
+                    "invalid unbonding period of the upgraded client state".into(),
+                ))
+            }
+        };
+        let latest_height = raw.latest_height.ok_or_else(|| {
+            Error::invalid_upgrade_client(
+                "missing latest height of the upgraded client state".into(),
+            )
+        })?;
+        Ok(Self {
+            chain_id: ChainId::from_string(&raw.chain_id),
+            unbonding_period,
+            latest_height: ibc::Height::new(
+                latest_height.revision_number,
+                latest_height.revision_height,
+            )
+            .map_err(Error::ics02)?,
+            proof_specs: raw.proof_specs.into(),
+            upgrade_path: raw.upgrade_path,
+        })
+    }
+}
+
+// upgrade_client_state returns a new client state which is composed of the chain-specified fields of the upgraded client state
+// and the client-specified fields of the current client state
+pub fn upgrade_client_state(
+    client_state: &ClientState,
+    upgraded_client_state: &UpgradedClientState,
+) -> Result<ClientState, Error> {
+    let opt = client_state
+        .as_light_client_options()
+        .map_err(Error::ics02)?;
+    #[allow(deprecated)]
+    let tm = TendermintClientState::new(
+        upgraded_client_state.chain_id.clone(),
+        client_state.trust_level,
+        client_state.trusting_period,
+        upgraded_client_state.unbonding_period,
+        opt.clock_drift,
+        upgraded_client_state.latest_height,
+        upgraded_client_state.proof_specs.clone(),
+        upgraded_client_state.upgrade_path.clone(),
+        allow_update(client_state),
+        None,
+    )
+    .map_err(Error::ics07)?;
+    Ok(ClientState(tm))
+}
+
+// upgrade_consensus_state returns a new consensus state which has the timestamp and next validators hash of the upgraded consensus state
+// and the sentinel root
+pub fn upgrade_consensus_state(upgraded_consensus_state: &ConsensusState) -> ConsensusState {
+    ConsensusState(TendermintConsensusState::new(
+        CommitmentRoot::from_bytes(SENTINEL_ROOT),
+        upgraded_consensus_state.timestamp,
+        upgraded_consensus_state.next_validators_hash,
+    ))
+}
+
 pub fn gen_state_id(
     client_state: ClientState,
     consensus_state: ConsensusState,
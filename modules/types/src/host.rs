@@ -67,6 +67,28 @@ impl From<ibc::core::ics24_host::identifier::ClientId> for ClientId {
     }
 }
 
+/// ClientStatus is a status of Enclave Light Client(ELC)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ClientStatus {
+    /// the client can be updated and used for the state verification
+    Active,
+    /// the latest consensus state of the client is outside the trusting period
+    Expired,
+    /// the client is frozen due to a misbehaviour
+    Frozen,
+}
+
+/// This implementation provides a `to_string` method.
+impl Display for ClientStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Active => write!(f, "Active"),
+            Self::Expired => write!(f, "Expired"),
+            Self::Frozen => write!(f, "Frozen"),
+        }
+    }
+}
+
 /// Default validator function for Client identifiers.
 ///
 /// A valid identifier must be between 9-64 characters and only contain lowercase
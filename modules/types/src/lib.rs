@@ -4,9 +4,10 @@ extern crate alloc;
 pub use any::Any;
 pub use errors::{TimeError, TypeError};
 pub use height::Height;
-pub use host::ClientId;
+pub use host::{ClientId, ClientStatus};
 /// re-export
 pub use lcp_proto as proto;
+pub use pagination::{Pagination, MAX_PAGE_LIMIT};
 pub use sgx::Mrenclave;
 pub use time::{nanos_to_duration, Time, MAX_UNIX_TIMESTAMP_NANOS};
 
@@ -14,6 +15,7 @@ mod any;
 mod errors;
 mod height;
 mod host;
+mod pagination;
 mod sgx;
 mod time;
 
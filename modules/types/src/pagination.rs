@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of items returned in a page
+///
+/// A page is returned through the output buffer of the ecall, so its size must be bounded.
+pub const MAX_PAGE_LIMIT: u64 = 100;
+
+/// Pagination is a parameter to select a page of the query results in ascending order of the key
+///
+/// If the number of the returned items equals to the page size, the next page can be queried
+/// with the key of the last item as `start_after`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pagination<K> {
+    /// if Some(k), the page starts from the first item whose key is strictly greater than `k`
+    pub start_after: Option<K>,
+    /// the maximum number of items to return; 0 or a value greater than `MAX_PAGE_LIMIT` means `MAX_PAGE_LIMIT`
+    pub limit: u64,
+}
+
+impl<K> Default for Pagination<K> {
+    fn default() -> Self {
+        Self {
+            start_after: None,
+            limit: 0,
+        }
+    }
+}
+
+impl<K> Pagination<K> {
+    pub fn new(start_after: Option<K>, limit: u64) -> Self {
+        Self { start_after, limit }
+    }
+
+    /// page_size returns the number of items to return, which is bounded by `MAX_PAGE_LIMIT`
+    pub fn page_size(&self) -> usize {
+        if self.limit == 0 || self.limit > MAX_PAGE_LIMIT {
+            MAX_PAGE_LIMIT as usize
+        } else {
+            self.limit as usize
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_size() {
+        assert_eq!(
+            Pagination::<u64>::default().page_size(),
+            MAX_PAGE_LIMIT as usize
+        );
+        assert_eq!(Pagination::new(Some(1u64), 2).page_size(), 2);
+        assert_eq!(
+            Pagination::new(Some(1u64), MAX_PAGE_LIMIT).page_size(),
+            MAX_PAGE_LIMIT as usize
+        );
+        assert_eq!(
+            Pagination::<u64>::new(None, MAX_PAGE_LIMIT + 1).page_size(),
+            MAX_PAGE_LIMIT as usize
+        );
+    }
+}
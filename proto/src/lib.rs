@@ -44,6 +44,18 @@ pub mod ibc {
 }
 
 pub mod lcp {
+    pub mod lightclients {
+        pub mod solomachine {
+            pub mod v1 {
+                include_proto!("lcp.lightclients.solomachine.v1.rs");
+            }
+        }
+        pub mod tendermint {
+            pub mod v1 {
+                include_proto!("lcp.lightclients.tendermint.v1.rs");
+            }
+        }
+    }
     pub mod service {
         pub mod enclave {
             pub mod v1 {
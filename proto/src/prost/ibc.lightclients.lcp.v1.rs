@@ -18,6 +18,39 @@ pub struct RegisterEnclaveKeyMessage {
     #[prost(bytes = "vec", tag = "3")]
     pub signing_cert: ::prost::alloc::vec::Vec<u8>,
 }
+/// MisbehaviourMessage is a proof that two conflicting commitments were signed by enclave keys
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MisbehaviourMessage {
+    #[prost(message, optional, tag = "1")]
+    pub update_1: ::core::option::Option<UpdateClientMessage>,
+    #[prost(message, optional, tag = "2")]
+    pub update_2: ::core::option::Option<UpdateClientMessage>,
+}
+/// ELCMisbehaviourMessage is a misbehaviour commitment signed by an enclave key
+/// It attests that the ELC detected a misbehaviour against the states stored in the client
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ElcMisbehaviourMessage {
+    #[prost(bytes = "vec", tag = "1")]
+    pub commitment: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+/// RevokeEnclaveKeyMessage is a message to revoke the enclave key before it expires
+/// It must be signed by an active enclave key or an authority key of the client
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevokeEnclaveKeyMessage {
+    #[prost(bytes = "vec", tag = "1")]
+    pub key: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClientState {
@@ -35,6 +68,19 @@ pub struct ClientState {
     /// e.g. INTEL-SA-XXXXX
     #[prost(string, repeated, tag = "5")]
     pub allowed_advisory_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// whether the client is frozen due to misbehaviour
+    #[prost(bool, tag = "6")]
+    pub frozen: bool,
+    /// addresses of the keys that are allowed to revoke enclave keys (RevokeEnclaveKeyMessage),
+    /// upgrade the client (upgrade_client) and approve the recovery of the client (recover_client)
+    #[prost(bytes = "vec", repeated, tag = "7")]
+    pub authorities: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// number of upgrades applied to the client, which is included in the upgrade sign bytes
+    #[prost(uint64, tag = "8")]
+    pub upgrade_sequence: u64,
+    /// number of enclave keys revoked in the client, which is included in the revocation sign bytes
+    #[prost(uint64, tag = "9")]
+    pub revocation_sequence: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -0,0 +1,111 @@
+/// ClientState defines a solo machine client whose states are signed by
+/// a threshold of a fixed set of secp256k1 keys, in the style of ICS-06.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientState {
+    /// latest sequence of the client state
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+    /// addresses of the keys that sign the states of the solo machine
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// the number of signatures required to sign the states
+    #[prost(uint32, tag = "3")]
+    pub threshold: u32,
+    /// diversifier allows the same keys to be reused across different solo machines
+    #[prost(string, tag = "4")]
+    pub diversifier: ::prost::alloc::string::String,
+    /// whether the client is frozen due to misbehaviour
+    #[prost(bool, tag = "5")]
+    pub is_frozen: bool,
+}
+/// ConsensusState defines the state of the solo machine at the sequence.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConsensusState {
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+    /// unix timestamp in nanoseconds
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+}
+/// Header defines an update of the signers of the solo machine, which is
+/// signed by the current signers at the latest sequence.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Header {
+    /// unix timestamp in nanoseconds
+    #[prost(uint64, tag = "1")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub signatures: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub new_signers: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, tag = "4")]
+    pub new_threshold: u32,
+    #[prost(string, tag = "5")]
+    pub new_diversifier: ::prost::alloc::string::String,
+}
+/// Misbehaviour defines two conflicting data signed at the same sequence.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Misbehaviour {
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+    #[prost(message, optional, tag = "2")]
+    pub signature_one: ::core::option::Option<SignatureAndData>,
+    #[prost(message, optional, tag = "3")]
+    pub signature_two: ::core::option::Option<SignatureAndData>,
+}
+/// SignatureAndData contains the signatures and the data that they sign.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignatureAndData {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub signatures: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub path: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    /// unix timestamp in nanoseconds
+    #[prost(uint64, tag = "4")]
+    pub timestamp: u64,
+}
+/// TimestampedSignatureData is the proof of the membership and the
+/// non-membership verification.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimestampedSignatureData {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub signatures: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// unix timestamp in nanoseconds
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+}
+/// SignBytes defines the bytes that the signers sign.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignBytes {
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+    /// unix timestamp in nanoseconds
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+    #[prost(string, tag = "3")]
+    pub diversifier: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "4")]
+    pub path: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+/// HeaderData is the data of the SignBytes for the header.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HeaderData {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub new_signers: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, tag = "2")]
+    pub new_threshold: u32,
+    #[prost(string, tag = "3")]
+    pub new_diversifier: ::prost::alloc::string::String,
+}
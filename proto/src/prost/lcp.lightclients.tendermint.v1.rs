@@ -0,0 +1,19 @@
+/// BisectionHeader defines a header of the tendermint ELC that updates the client
+/// from a trusted height to a non-adjacent height through the intermediate headers
+/// computed by the skipping verification.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BisectionHeader {
+    /// the target header; its trusted height must be the height of the last intermediate header
+    #[prost(message, optional, tag = "1")]
+    pub header: ::core::option::Option<
+        super::super::super::super::google::protobuf::Any,
+    >,
+    /// intermediate headers in ascending order of height
+    /// the first one must be trusted by a consensus state of the client,
+    /// and each of the others must be trusted by the preceding one
+    #[prost(message, repeated, tag = "2")]
+    pub intermediate_headers: ::prost::alloc::vec::Vec<
+        super::super::super::super::google::protobuf::Any,
+    >,
+}
@@ -4,6 +4,11 @@
 pub struct QueryClientRequest {
     #[prost(string, tag = "1")]
     pub client_id: ::prost::alloc::string::String,
+    /// height of the consensus state to return; if nil, the latest height is used
+    #[prost(message, optional, tag = "2")]
+    pub height: ::core::option::Option<
+        super::super::super::super::ibc::core::client::v1::Height,
+    >,
 }
 #[derive(::serde::Serialize, ::serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -21,6 +26,110 @@ pub struct QueryClientResponse {
         super::super::super::super::google::protobuf::Any,
     >,
 }
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryConsensusStateRequest {
+    #[prost(string, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub height: ::core::option::Option<
+        super::super::super::super::ibc::core::client::v1::Height,
+    >,
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryConsensusStateResponse {
+    /// consensus state associated with the client that corresponds to the given height
+    #[prost(message, optional, tag = "1")]
+    pub consensus_state: ::core::option::Option<
+        super::super::super::super::google::protobuf::Any,
+    >,
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryConsensusStateHeightsRequest {
+    #[prost(string, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+    /// if set, the heights start from the first one strictly greater than this height
+    #[prost(message, optional, tag = "2")]
+    pub start_after: ::core::option::Option<
+        super::super::super::super::ibc::core::client::v1::Height,
+    >,
+    /// the maximum number of heights to return; 0 or a value greater than 100 means 100
+    #[prost(uint64, tag = "3")]
+    pub limit: u64,
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryConsensusStateHeightsResponse {
+    /// heights of the consensus states that the client holds in ascending order
+    #[prost(message, repeated, tag = "1")]
+    pub heights: ::prost::alloc::vec::Vec<
+        super::super::super::super::ibc::core::client::v1::Height,
+    >,
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryClientsRequest {
+    /// if non-empty, the clients start from the one next to this client id
+    #[prost(string, tag = "1")]
+    pub start_after: ::prost::alloc::string::String,
+    /// the maximum number of clients to return; 0 or a value greater than 100 means 100
+    #[prost(uint64, tag = "2")]
+    pub limit: u64,
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryClientsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub clients: ::prost::alloc::vec::Vec<IdentifiedClient>,
+}
+/// IdentifiedClient is a summary of a client with its identifier
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IdentifiedClient {
+    #[prost(string, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub client_type: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub latest_height: ::core::option::Option<
+        super::super::super::super::ibc::core::client::v1::Height,
+    >,
+    /// status of the client: "Active", "Expired" or "Frozen"
+    #[prost(string, tag = "4")]
+    pub status: ::prost::alloc::string::String,
+    /// error message if the client cannot be read; the fields other than
+    /// client_id are unset if this is not empty
+    #[prost(string, tag = "5")]
+    pub error: ::prost::alloc::string::String,
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryClientStatusRequest {
+    #[prost(string, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryClientStatusResponse {
+    /// status of the client: "Active", "Expired" or "Frozen"
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    /// unix timestamp in seconds when the latest consensus state of the client
+    /// expires; 0 means the client never expires
+    #[prost(uint64, tag = "2")]
+    pub expires_at: u64,
+}
 /// Generated client implementations.
 #[cfg(feature = "client")]
 pub mod query_client {
@@ -111,6 +220,85 @@ pub mod query_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        pub async fn consensus_state(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryConsensusStateRequest>,
+        ) -> Result<tonic::Response<super::QueryConsensusStateResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/lcp.service.elc.v1.Query/ConsensusState",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn consensus_state_heights(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryConsensusStateHeightsRequest>,
+        ) -> Result<
+            tonic::Response<super::QueryConsensusStateHeightsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/lcp.service.elc.v1.Query/ConsensusStateHeights",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn clients(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryClientsRequest>,
+        ) -> Result<tonic::Response<super::QueryClientsResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/lcp.service.elc.v1.Query/Clients",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn client_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryClientStatusRequest>,
+        ) -> Result<tonic::Response<super::QueryClientStatusResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/lcp.service.elc.v1.Query/ClientStatus",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -125,6 +313,25 @@ pub mod query_server {
             &self,
             request: tonic::Request<super::QueryClientRequest>,
         ) -> Result<tonic::Response<super::QueryClientResponse>, tonic::Status>;
+        async fn consensus_state(
+            &self,
+            request: tonic::Request<super::QueryConsensusStateRequest>,
+        ) -> Result<tonic::Response<super::QueryConsensusStateResponse>, tonic::Status>;
+        async fn consensus_state_heights(
+            &self,
+            request: tonic::Request<super::QueryConsensusStateHeightsRequest>,
+        ) -> Result<
+            tonic::Response<super::QueryConsensusStateHeightsResponse>,
+            tonic::Status,
+        >;
+        async fn clients(
+            &self,
+            request: tonic::Request<super::QueryClientsRequest>,
+        ) -> Result<tonic::Response<super::QueryClientsResponse>, tonic::Status>;
+        async fn client_status(
+            &self,
+            request: tonic::Request<super::QueryClientStatusRequest>,
+        ) -> Result<tonic::Response<super::QueryClientStatusResponse>, tonic::Status>;
     }
     /// Query defines the ELC Query service.
     #[derive(Debug)]
@@ -222,6 +429,167 @@ pub mod query_server {
                     };
                     Box::pin(fut)
                 }
+                "/lcp.service.elc.v1.Query/ConsensusState" => {
+                    #[allow(non_camel_case_types)]
+                    struct ConsensusStateSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryConsensusStateRequest>
+                    for ConsensusStateSvc<T> {
+                        type Response = super::QueryConsensusStateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryConsensusStateRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).consensus_state(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ConsensusStateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/lcp.service.elc.v1.Query/ConsensusStateHeights" => {
+                    #[allow(non_camel_case_types)]
+                    struct ConsensusStateHeightsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryConsensusStateHeightsRequest,
+                    > for ConsensusStateHeightsSvc<T> {
+                        type Response = super::QueryConsensusStateHeightsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryConsensusStateHeightsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).consensus_state_heights(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ConsensusStateHeightsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/lcp.service.elc.v1.Query/Clients" => {
+                    #[allow(non_camel_case_types)]
+                    struct ClientsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryClientsRequest>
+                    for ClientsSvc<T> {
+                        type Response = super::QueryClientsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryClientsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).clients(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ClientsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/lcp.service.elc.v1.Query/ClientStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct ClientStatusSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryClientStatusRequest>
+                    for ClientStatusSvc<T> {
+                        type Response = super::QueryClientStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryClientStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).client_status(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ClientStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -328,10 +696,173 @@ pub struct MsgUpdateClientResponse {
     #[prost(bytes = "vec", tag = "3")]
     pub signature: ::prost::alloc::vec::Vec<u8>,
 }
+/// MsgUpdateClientBatch defines a message to update a IBC client state using
+/// the given headers in sequence.
 #[derive(::serde::Serialize, ::serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct MsgVerifyMembership {
+pub struct MsgUpdateClientBatch {
+    /// client unique identifier
+    #[prost(string, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+    /// headers to update the light client, applied in order
+    #[prost(message, repeated, tag = "2")]
+    pub headers: ::prost::alloc::vec::Vec<
+        super::super::super::super::google::protobuf::Any,
+    >,
+    /// request to include the latest state in a commitment
+    #[prost(bool, tag = "3")]
+    pub include_state: bool,
+    /// enclave key for signing
+    #[prost(bytes = "vec", tag = "4")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+}
+/// MsgUpdateClientBatchResponse defines the Msg/UpdateClientBatch response type.
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgUpdateClientBatchResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub commitment: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+/// MsgSubmitMisbehaviour defines a message to submit a misbehaviour evidence
+/// and freeze the IBC client.
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgSubmitMisbehaviour {
+    /// client unique identifier
+    #[prost(string, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+    /// misbehaviour used for freezing the light client
+    #[prost(message, optional, tag = "2")]
+    pub misbehaviour: ::core::option::Option<
+        super::super::super::super::google::protobuf::Any,
+    >,
+    /// enclave key for signing
+    #[prost(bytes = "vec", tag = "3")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+}
+/// MsgSubmitMisbehaviourResponse defines the Msg/SubmitMisbehaviour response type.
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgSubmitMisbehaviourResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub commitment: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+/// MsgPruneConsensusStates defines a message to delete the expired consensus
+/// states of the client.
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgPruneConsensusStates {
+    /// client unique identifier
+    #[prost(string, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+    /// consensus states older than this period in seconds are deleted;
+    /// 0 means the trusting period of the client
+    #[prost(uint64, tag = "2")]
+    pub retention_period: u64,
+    /// the maximum number of consensus states to delete; 0 means no limit
+    #[prost(uint64, tag = "3")]
+    pub limit: u64,
+}
+/// MsgPruneConsensusStatesResponse defines the Msg/PruneConsensusStates response type.
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgPruneConsensusStatesResponse {
+    /// heights of the deleted consensus states
+    #[prost(message, repeated, tag = "1")]
+    pub pruned_heights: ::prost::alloc::vec::Vec<
+        super::super::super::super::ibc::core::client::v1::Height,
+    >,
+}
+/// MsgRecoverClient defines a message to replace the state of the expired or
+/// frozen subject client with the state of the active substitute client.
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgRecoverClient {
+    /// the client identifier of the client to be recovered
+    #[prost(string, tag = "1")]
+    pub subject_client_id: ::prost::alloc::string::String,
+    /// the client identifier of the client whose state replaces the subject client's
+    #[prost(string, tag = "2")]
+    pub substitute_client_id: ::prost::alloc::string::String,
+    /// enclave key for signing
+    #[prost(bytes = "vec", tag = "3")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+}
+/// MsgRecoverClientResponse defines the Msg/RecoverClient response type.
+/// The commitment is a RecoverClientCommitment, which the LCP client accepts
+/// only with the approval of one of its authorities.
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgRecoverClientResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub commitment: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+/// MsgUpgradeClient defines a message to upgrade the client with the upgraded
+/// client state and consensus state committed by the counterparty chain.
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgUpgradeClient {
+    /// client unique identifier
+    #[prost(string, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+    /// upgraded client state
+    #[prost(message, optional, tag = "2")]
+    pub client_state: ::core::option::Option<
+        super::super::super::super::google::protobuf::Any,
+    >,
+    /// upgraded consensus state, only contains enough information to serve as a
+    /// basis of trust in update logic
+    #[prost(message, optional, tag = "3")]
+    pub consensus_state: ::core::option::Option<
+        super::super::super::super::google::protobuf::Any,
+    >,
+    /// proof that old chain committed to new client
+    #[prost(bytes = "vec", tag = "4")]
+    pub proof_upgrade_client: ::prost::alloc::vec::Vec<u8>,
+    /// proof that old chain committed to new consensus state
+    #[prost(bytes = "vec", tag = "5")]
+    pub proof_upgrade_consensus_state: ::prost::alloc::vec::Vec<u8>,
+    /// enclave key for signing
+    #[prost(bytes = "vec", tag = "6")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+}
+/// MsgUpgradeClientResponse defines the Msg/UpgradeClient response type.
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgUpgradeClientResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub commitment: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgVerifyMembership {
     #[prost(string, tag = "1")]
     pub client_id: ::prost::alloc::string::String,
     #[prost(bytes = "vec", tag = "2")]
@@ -392,6 +923,48 @@ pub struct MsgVerifyNonMembershipResponse {
     #[prost(bytes = "vec", tag = "3")]
     pub signature: ::prost::alloc::vec::Vec<u8>,
 }
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgVerifyStateBatch {
+    #[prost(string, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub proof_height: ::core::option::Option<
+        super::super::super::super::ibc::core::client::v1::Height,
+    >,
+    #[prost(message, repeated, tag = "3")]
+    pub entries: ::prost::alloc::vec::Vec<VerifyStateEntry>,
+    /// enclave key for signing
+    #[prost(bytes = "vec", tag = "4")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+}
+/// VerifyStateEntry is a state to be verified in MsgVerifyStateBatch.
+/// An empty value indicates the non-existence of the state at the path.
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VerifyStateEntry {
+    #[prost(bytes = "vec", tag = "1")]
+    pub prefix: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "2")]
+    pub path: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub proof: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgVerifyStateBatchResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub commitment: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
 /// Generated client implementations.
 #[cfg(feature = "client")]
 pub mod msg_client {
@@ -503,6 +1076,115 @@ pub mod msg_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        /// UpdateClientBatch defines a rpc handler method for MsgUpdateClientBatch.
+        pub async fn update_client_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgUpdateClientBatch>,
+        ) -> Result<
+            tonic::Response<super::MsgUpdateClientBatchResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/lcp.service.elc.v1.Msg/UpdateClientBatch",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// SubmitMisbehaviour defines a rpc handler method for MsgSubmitMisbehaviour.
+        pub async fn submit_misbehaviour(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgSubmitMisbehaviour>,
+        ) -> Result<
+            tonic::Response<super::MsgSubmitMisbehaviourResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/lcp.service.elc.v1.Msg/SubmitMisbehaviour",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// PruneConsensusStates defines a rpc handler method for MsgPruneConsensusStates.
+        pub async fn prune_consensus_states(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgPruneConsensusStates>,
+        ) -> Result<
+            tonic::Response<super::MsgPruneConsensusStatesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/lcp.service.elc.v1.Msg/PruneConsensusStates",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// RecoverClient defines a rpc handler method for MsgRecoverClient.
+        pub async fn recover_client(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgRecoverClient>,
+        ) -> Result<tonic::Response<super::MsgRecoverClientResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/lcp.service.elc.v1.Msg/RecoverClient",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// UpgradeClient defines a rpc handler method for MsgUpgradeClient.
+        pub async fn upgrade_client(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgUpgradeClient>,
+        ) -> Result<tonic::Response<super::MsgUpgradeClientResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/lcp.service.elc.v1.Msg/UpgradeClient",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
         /// VerifyMembership defines a rpc handler method for MsgVerifyMembership
         pub async fn verify_membership(
             &mut self,
@@ -546,6 +1228,26 @@ pub mod msg_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        /// VerifyStateBatch defines a rpc handler method for MsgVerifyStateBatch
+        pub async fn verify_state_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgVerifyStateBatch>,
+        ) -> Result<tonic::Response<super::MsgVerifyStateBatchResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/lcp.service.elc.v1.Msg/VerifyStateBatch",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -566,6 +1268,37 @@ pub mod msg_server {
             &self,
             request: tonic::Request<super::MsgUpdateClient>,
         ) -> Result<tonic::Response<super::MsgUpdateClientResponse>, tonic::Status>;
+        /// UpdateClientBatch defines a rpc handler method for MsgUpdateClientBatch.
+        async fn update_client_batch(
+            &self,
+            request: tonic::Request<super::MsgUpdateClientBatch>,
+        ) -> Result<tonic::Response<super::MsgUpdateClientBatchResponse>, tonic::Status>;
+        /// SubmitMisbehaviour defines a rpc handler method for MsgSubmitMisbehaviour.
+        async fn submit_misbehaviour(
+            &self,
+            request: tonic::Request<super::MsgSubmitMisbehaviour>,
+        ) -> Result<
+            tonic::Response<super::MsgSubmitMisbehaviourResponse>,
+            tonic::Status,
+        >;
+        /// PruneConsensusStates defines a rpc handler method for MsgPruneConsensusStates.
+        async fn prune_consensus_states(
+            &self,
+            request: tonic::Request<super::MsgPruneConsensusStates>,
+        ) -> Result<
+            tonic::Response<super::MsgPruneConsensusStatesResponse>,
+            tonic::Status,
+        >;
+        /// RecoverClient defines a rpc handler method for MsgRecoverClient.
+        async fn recover_client(
+            &self,
+            request: tonic::Request<super::MsgRecoverClient>,
+        ) -> Result<tonic::Response<super::MsgRecoverClientResponse>, tonic::Status>;
+        /// UpgradeClient defines a rpc handler method for MsgUpgradeClient.
+        async fn upgrade_client(
+            &self,
+            request: tonic::Request<super::MsgUpgradeClient>,
+        ) -> Result<tonic::Response<super::MsgUpgradeClientResponse>, tonic::Status>;
         /// VerifyMembership defines a rpc handler method for MsgVerifyMembership
         async fn verify_membership(
             &self,
@@ -579,6 +1312,11 @@ pub mod msg_server {
             tonic::Response<super::MsgVerifyNonMembershipResponse>,
             tonic::Status,
         >;
+        /// VerifyStateBatch defines a rpc handler method for MsgVerifyStateBatch
+        async fn verify_state_batch(
+            &self,
+            request: tonic::Request<super::MsgVerifyStateBatch>,
+        ) -> Result<tonic::Response<super::MsgVerifyStateBatchResponse>, tonic::Status>;
     }
     /// Msg defines the ELC Msg service.
     #[derive(Debug)]
@@ -716,6 +1454,200 @@ pub mod msg_server {
                     };
                     Box::pin(fut)
                 }
+                "/lcp.service.elc.v1.Msg/UpdateClientBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateClientBatchSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgUpdateClientBatch>
+                    for UpdateClientBatchSvc<T> {
+                        type Response = super::MsgUpdateClientBatchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgUpdateClientBatch>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).update_client_batch(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateClientBatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/lcp.service.elc.v1.Msg/SubmitMisbehaviour" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubmitMisbehaviourSvc<T: Msg>(pub Arc<T>);
+                    impl<
+                        T: Msg,
+                    > tonic::server::UnaryService<super::MsgSubmitMisbehaviour>
+                    for SubmitMisbehaviourSvc<T> {
+                        type Response = super::MsgSubmitMisbehaviourResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgSubmitMisbehaviour>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).submit_misbehaviour(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SubmitMisbehaviourSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/lcp.service.elc.v1.Msg/PruneConsensusStates" => {
+                    #[allow(non_camel_case_types)]
+                    struct PruneConsensusStatesSvc<T: Msg>(pub Arc<T>);
+                    impl<
+                        T: Msg,
+                    > tonic::server::UnaryService<super::MsgPruneConsensusStates>
+                    for PruneConsensusStatesSvc<T> {
+                        type Response = super::MsgPruneConsensusStatesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgPruneConsensusStates>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).prune_consensus_states(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PruneConsensusStatesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/lcp.service.elc.v1.Msg/RecoverClient" => {
+                    #[allow(non_camel_case_types)]
+                    struct RecoverClientSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgRecoverClient>
+                    for RecoverClientSvc<T> {
+                        type Response = super::MsgRecoverClientResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgRecoverClient>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).recover_client(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RecoverClientSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/lcp.service.elc.v1.Msg/UpgradeClient" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpgradeClientSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgUpgradeClient>
+                    for UpgradeClientSvc<T> {
+                        type Response = super::MsgUpgradeClientResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgUpgradeClient>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).upgrade_client(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpgradeClientSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/lcp.service.elc.v1.Msg/VerifyMembership" => {
                     #[allow(non_camel_case_types)]
                     struct VerifyMembershipSvc<T: Msg>(pub Arc<T>);
@@ -794,6 +1726,44 @@ pub mod msg_server {
                     };
                     Box::pin(fut)
                 }
+                "/lcp.service.elc.v1.Msg/VerifyStateBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct VerifyStateBatchSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgVerifyStateBatch>
+                    for VerifyStateBatchSvc<T> {
+                        type Response = super::MsgVerifyStateBatchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgVerifyStateBatch>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).verify_state_batch(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = VerifyStateBatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
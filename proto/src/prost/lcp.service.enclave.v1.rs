@@ -43,6 +43,28 @@ pub struct QueryEnclaveKeyResponse {
     #[prost(message, optional, tag = "1")]
     pub key: ::core::option::Option<EnclaveKeyInfo>,
 }
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryLightClientsRequest {}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryLightClientsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub light_clients: ::prost::alloc::vec::Vec<LightClientInfo>,
+}
+/// LightClientInfo is an ELC implementation that the enclave supports
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LightClientInfo {
+    #[prost(string, tag = "1")]
+    pub client_type: ::prost::alloc::string::String,
+    /// type URLs of the client state that the ELC accepts
+    #[prost(string, repeated, tag = "2")]
+    pub client_state_type_urls: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
 /// Generated client implementations.
 #[cfg(feature = "client")]
 pub mod query_client {
@@ -154,6 +176,25 @@ pub mod query_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        pub async fn light_clients(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryLightClientsRequest>,
+        ) -> Result<tonic::Response<super::QueryLightClientsResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/lcp.service.enclave.v1.Query/LightClients",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -175,6 +216,10 @@ pub mod query_server {
             &self,
             request: tonic::Request<super::QueryEnclaveKeyRequest>,
         ) -> Result<tonic::Response<super::QueryEnclaveKeyResponse>, tonic::Status>;
+        async fn light_clients(
+            &self,
+            request: tonic::Request<super::QueryLightClientsRequest>,
+        ) -> Result<tonic::Response<super::QueryLightClientsResponse>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct QueryServer<T: Query> {
@@ -316,6 +361,46 @@ pub mod query_server {
                     };
                     Box::pin(fut)
                 }
+                "/lcp.service.enclave.v1.Query/LightClients" => {
+                    #[allow(non_camel_case_types)]
+                    struct LightClientsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryLightClientsRequest>
+                    for LightClientsSvc<T> {
+                        type Response = super::QueryLightClientsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryLightClientsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).light_clients(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = LightClientsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -12,8 +12,9 @@ mod tests {
     use anyhow::{anyhow, bail};
     use commitments::UpdateClientCommitment;
     use ecall_commands::{
-        CommitmentProofPair, GenerateEnclaveKeyInput, InitClientInput, UpdateClientInput,
-        VerifyMembershipInput,
+        CommitmentProofPair, GenerateEnclaveKeyInput, InitClientInput, PruneConsensusStatesInput,
+        QueryClientStatusInput, QueryClientsInput, QueryConsensusStateHeightsInput,
+        QueryConsensusStateInput, UpdateClientBatchInput, UpdateClientInput, VerifyMembershipInput,
     };
     use enclave_api::{Enclave, EnclaveCommandAPI};
     use host_environment::Environment;
@@ -31,10 +32,11 @@ mod tests {
     };
     use keymanager::EnclaveKeyManager;
     use lcp_proto::protobuf::Protobuf;
-    use lcp_types::Time;
+    use lcp_types::{ClientStatus, Pagination, Time};
     use log::*;
     use std::str::FromStr;
     use std::sync::{Arc, RwLock};
+    use std::time::Duration;
     use store::{host::HostStore, memory::MemStore};
     use tempfile::TempDir;
     use tokio::runtime::Runtime as TokioRuntime;
@@ -181,9 +183,60 @@ mod tests {
 
         let commitment: UpdateClientCommitment = res.0.commitment().unwrap().try_into()?;
         let height = commitment.new_height;
+        let state_id = commitment.new_state_id;
 
         info!("current height is {}", height);
 
+        let res = enclave.query_consensus_state_heights(QueryConsensusStateHeightsInput {
+            client_id: client_id.clone(),
+            pagination: Default::default(),
+        })?;
+        assert_eq!(res.heights, vec![initial_height.into(), height]);
+
+        let res = enclave.query_clients(QueryClientsInput {
+            pagination: Default::default(),
+            current_timestamp: Time::now(),
+        })?;
+        assert_eq!(res.clients.len(), 1);
+        assert_eq!(res.clients[0].client_id, client_id);
+        let summary = res.clients[0].summary.as_ref().unwrap();
+        assert_eq!(summary.latest_height, height);
+        assert_eq!(summary.status, ClientStatus::Active);
+
+        let res = enclave.query_client_status(QueryClientStatusInput {
+            client_id: client_id.clone(),
+            current_timestamp: Time::now(),
+        })?;
+        assert_eq!(res.status, ClientStatus::Active);
+        assert!(res.expires_at.unwrap() > Time::now());
+
+        // the latest consensus state must be kept even if it is older than the retention period
+        let res = enclave.prune_consensus_states(PruneConsensusStatesInput {
+            client_id: client_id.clone(),
+            retention_period: Some(Duration::from_secs(0)),
+            limit: u64::MAX,
+            current_timestamp: Time::now(),
+        })?;
+        assert_eq!(res.pruned_heights, vec![initial_height.into()]);
+        let res = enclave.query_consensus_state_heights(QueryConsensusStateHeightsInput {
+            client_id: client_id.clone(),
+            pagination: Default::default(),
+        })?;
+        assert_eq!(res.heights, vec![height]);
+
+        let _ = enclave.query_consensus_state(QueryConsensusStateInput {
+            client_id: client_id.clone(),
+            height,
+        })?;
+        let res = enclave.query_consensus_state(QueryConsensusStateInput {
+            client_id: client_id.clone(),
+            height: initial_height.into(),
+        });
+        assert!(matches!(
+            res.as_ref().map_err(|e| e.detail()),
+            Err(enclave_api::ErrorDetail::ConsensusStateNotFound(_))
+        ));
+
         let (port_id, channel_id) = (
             PortId::from_str("transfer")?,
             ChannelId::from_str("channel-0")?,
@@ -197,7 +250,7 @@ mod tests {
         info!("expected channel is {:?}", res.0);
 
         let _ = enclave.verify_membership(VerifyMembershipInput {
-            client_id,
+            client_id: client_id.clone(),
             prefix: "ibc".into(),
             path: Path::ChannelEnd(ChannelEndPath(port_id, channel_id)).to_string(),
             value: res.0.encode_vec()?,
@@ -208,6 +261,77 @@ mod tests {
             signer,
         })?;
 
+        let height_1: ibc::Height = height.try_into().map_err(|e| anyhow!("{:?}", e))?;
+        let (height_2, height_3) = (height_1.increment(), height_1.increment().increment());
+        let header_1_2 = rly.create_header(height_1, height_2)?;
+        let header_2_3 = rly.create_header(height_2, height_3)?;
+
+        // every header must be based on the state produced by the preceding header
+        let res = enclave.update_client_batch(UpdateClientBatchInput {
+            client_id: client_id.clone(),
+            any_headers: vec![header_1_2.clone(), rly.create_header(height_1, height_3)?],
+            include_state: false,
+            current_timestamp: Time::now(),
+            signer,
+        });
+        assert!(res.is_err(), "res={:?}", res);
+
+        // a failing header in the middle of the batch aborts the whole batch
+        let res = enclave.update_client_batch(UpdateClientBatchInput {
+            client_id: client_id.clone(),
+            any_headers: vec![
+                header_1_2.clone(),
+                lcp_types::Any::new(header_2_3.type_url.clone(), vec![]),
+                header_2_3.clone(),
+            ],
+            include_state: false,
+            current_timestamp: Time::now(),
+            signer,
+        });
+        assert!(res.is_err(), "res={:?}", res);
+
+        // no state is written by the failed batches
+        let res = enclave.query_consensus_state_heights(QueryConsensusStateHeightsInput {
+            client_id: client_id.clone(),
+            pagination: Default::default(),
+        })?;
+        assert_eq!(res.heights, vec![height]);
+
+        let res = enclave.update_client_batch(UpdateClientBatchInput {
+            client_id: client_id.clone(),
+            any_headers: vec![header_1_2, header_2_3],
+            include_state: false,
+            current_timestamp: Time::now(),
+            signer,
+        })?;
+        assert!(res.0.is_proven());
+        // the aggregated commitment has the previous state of the first step and the new state of the last step
+        let commitment: UpdateClientCommitment = res.0.commitment().unwrap().try_into()?;
+        assert_eq!(commitment.prev_height, Some(height));
+        assert_eq!(commitment.prev_state_id, Some(state_id));
+        assert_eq!(commitment.new_height, height_3.into());
+        assert!(matches!(
+            commitment.context,
+            commitments::CommitmentContext::TrustingPeriod(_)
+        ));
+        let res = enclave.query_consensus_state_heights(QueryConsensusStateHeightsInput {
+            client_id: client_id.clone(),
+            pagination: Default::default(),
+        })?;
+        assert_eq!(res.heights, vec![height, height_2.into(), height_3.into()]);
+
+        // the heights can be queried page by page
+        let res = enclave.query_consensus_state_heights(QueryConsensusStateHeightsInput {
+            client_id: client_id.clone(),
+            pagination: Pagination::new(None, 2),
+        })?;
+        assert_eq!(res.heights, vec![height, height_2.into()]);
+        let res = enclave.query_consensus_state_heights(QueryConsensusStateHeightsInput {
+            client_id,
+            pagination: Pagination::new(Some(height_2.into()), 2),
+        })?;
+        assert_eq!(res.heights, vec![height_3.into()]);
+
         Ok(())
     }
 